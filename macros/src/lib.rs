@@ -0,0 +1,239 @@
+//! Derive macros for [bevy_cronjob](https://docs.rs/bevy_cronjob).
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use std::str::FromStr;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, DeriveInput, ItemFn, LitInt, LitStr, Path, Token};
+
+/// Implements `bevy_cronjob::CronJob` from a `#[cron(...)]` attribute, see the trait for the
+/// accepted keys.
+#[proc_macro_derive(CronJob, attributes(cron))]
+pub fn derive_cron_job(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match cron_job(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn cron_job(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let mut expression = None;
+    let mut label = None;
+    let mut tags = vec![];
+    let mut timezone = None;
+    let mut alignment = None;
+    let mut month_end = None;
+    let mut history = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cron"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            match key.as_str() {
+                "expression" => expression = Some(meta.value()?.parse::<LitStr>()?),
+                "label" => label = Some(meta.value()?.parse::<LitStr>()?),
+                "tag" => tags.push(meta.value()?.parse::<LitStr>()?),
+                "timezone" => timezone = Some(meta.value()?.parse::<LitStr>()?),
+                "history" => history = Some(meta.value()?.parse::<LitInt>()?),
+                "alignment" => {
+                    let value = meta.value()?.parse::<LitStr>()?;
+                    alignment = Some(match value.value().as_str() {
+                        "actual_fire" => quote!(ActualFire),
+                        "schedule" => quote!(Schedule),
+                        _ => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "expected \"actual_fire\" or \"schedule\"",
+                            ))
+                        }
+                    });
+                }
+                "month_end" => {
+                    let value = meta.value()?.parse::<LitStr>()?;
+                    month_end = Some(match value.value().as_str() {
+                        "skip" => quote!(Skip),
+                        "clamp" => quote!(Clamp),
+                        _ => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "expected \"skip\" or \"clamp\"",
+                            ))
+                        }
+                    });
+                }
+                _ => return Err(meta.error("unknown cron job attribute")),
+            }
+            Ok(())
+        })?;
+    }
+    let Some(expression) = expression else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "missing #[cron(expression = \"...\")]",
+        ));
+    };
+
+    validate(&expression)?;
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let label_const = match &label {
+        Some(label) => quote!(::core::option::Option::Some(#label)),
+        None => quote!(::core::option::Option::None),
+    };
+    let label = label.map(|label| quote!(.with_label(#label)));
+    let timezone = timezone.map(|timezone| {
+        quote! {
+            .with_timezone(
+                <::bevy_cronjob::CronTimezone as ::core::str::FromStr>::from_str(#timezone)
+                    .expect("invalid cron job timezone"),
+            )
+        }
+    });
+    let alignment = alignment
+        .map(|alignment| quote!(.with_alignment(::bevy_cronjob::ScheduleAlignment::#alignment)));
+    let month_end = month_end
+        .map(|policy| quote!(.with_month_end_policy(::bevy_cronjob::MonthEndPolicy::#policy)));
+    let history = history.map(|history| quote!(.with_history(#history)));
+    Ok(quote! {
+        impl #impl_generics ::bevy_cronjob::CronJob for #ident #ty_generics #where_clause {
+            const EXPRESSION: &'static str = #expression;
+            const LABEL: ::core::option::Option<&'static str> = #label_const;
+
+            fn timer() -> ::bevy_cronjob::ScheduleTimer {
+                ::bevy_cronjob::ScheduleTimer::new(Self::EXPRESSION)
+                    #label
+                    #(.with_tag(#tags))*
+                    #timezone
+                    #alignment
+                    #month_end
+                    #history
+            }
+        }
+    })
+}
+
+/// Arguments of `#[cron("...", schedule = Update)]`
+struct CronArgs {
+    expression: LitStr,
+    schedule: Option<Path>,
+}
+
+impl Parse for CronArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expression = input.parse()?;
+        let mut schedule = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key = input.parse::<syn::Ident>()?;
+            if key != "schedule" {
+                return Err(syn::Error::new(key.span(), "unknown cron argument"));
+            }
+            input.parse::<Token![=]>()?;
+            schedule = Some(input.parse()?);
+        }
+        Ok(Self {
+            expression,
+            schedule,
+        })
+    }
+}
+
+/// Runs the system whenever the expression passes, see `bevy_cronjob::cron`.
+#[proc_macro_attribute]
+pub fn cron(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as CronArgs);
+    let item = parse_macro_input!(item as ItemFn);
+    match cron_system(args, item) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn cron_system(args: CronArgs, item: ItemFn) -> syn::Result<TokenStream2> {
+    validate(&args.expression)?;
+    if !item.sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item.sig.generics,
+            "#[cron] systems cannot be generic",
+        ));
+    }
+    let vis = &item.vis;
+    let name = &item.sig.ident;
+    let expression = &args.expression;
+    let schedule = match args.schedule {
+        Some(schedule) => quote!(#schedule),
+        None => quote!(::bevy_cronjob::__private::Update),
+    };
+    let doc = format!(
+        "Registration of [`{name}`](fn@{name}), gated on `{}`",
+        expression.value()
+    );
+    let module = format_ident!("{name}");
+    Ok(quote! {
+        #item
+
+        #[doc = #doc]
+        #vis mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+            use ::bevy_cronjob::__private::IntoSystemConfigs as _;
+
+            /// the expression gating the system
+            pub const EXPRESSION: &str = #expression;
+
+            /// Add the system to `app`, run whenever [`EXPRESSION`] passes
+            pub fn plugin(app: &mut ::bevy_cronjob::__private::App) {
+                app.add_systems(
+                    #schedule,
+                    super::#name.run_if(::bevy_cronjob::schedule_passed(EXPRESSION)),
+                );
+            }
+        }
+    })
+}
+
+/// Reject expressions that are neither cron nor english at compile time.
+///
+/// Weekdays of the month, `TUE#2`, `FRIL` or `"2nd tuesday of the month at 19:00"`, are read by
+/// the parser `CronExpr::parse` uses, and checked as the plain cron expression it builds.
+fn validate(expression: &LitStr) -> syn::Result<()> {
+    let value = expression.value();
+    let invalid = |reason: String| {
+        syn::Error::new(
+            expression.span(),
+            format!("invalid cron expression: {reason}"),
+        )
+    };
+    if let Some(month_weekday) = bevy_cronjob_parser::parse_month_weekday(&value) {
+        let month_weekday = month_weekday.map_err(|reason| invalid(reason.to_string()))?;
+        return cron::Schedule::from_str(&month_weekday.to_cron())
+            .map(|_| ())
+            .map_err(|err| invalid(err.to_string()));
+    }
+    if cron::Schedule::from_str(&value).is_ok() {
+        return Ok(());
+    }
+    let english = english_to_cron::str_cron_syntax(&value)
+        .map_err(|err| err.to_string())
+        .and_then(|cron| {
+            cron::Schedule::from_str(&cron)
+                .map(|_| ())
+                .map_err(|err| err.to_string())
+        });
+    match english {
+        Ok(()) if value.chars().any(|c| c.is_ascii_alphabetic()) => Ok(()),
+        Ok(()) => Err(syn::Error::new(
+            expression.span(),
+            "invalid cron expression",
+        )),
+        Err(err) => Err(invalid(err)),
+    }
+}
@@ -0,0 +1,211 @@
+//! Expression grammar shared by [bevy_cronjob](https://docs.rs/bevy_cronjob) and its macros, so
+//! expressions checked at compile time are read exactly as they are at runtime.
+
+use chrono::Weekday;
+
+/// Week of the month used by `CronExpr::monthly_on_weekday`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonthWeek {
+    /// days 1-7
+    First,
+    /// days 8-14
+    Second,
+    /// days 15-21
+    Third,
+    /// days 22-28
+    Fourth,
+    /// the last seven days of the month
+    Last,
+}
+
+impl MonthWeek {
+    /// The day-of-month field covering the week
+    pub fn days(self) -> &'static str {
+        match self {
+            MonthWeek::First => "1-7",
+            MonthWeek::Second => "8-14",
+            MonthWeek::Third => "15-21",
+            MonthWeek::Fourth => "22-28",
+            MonthWeek::Last => "22-31",
+        }
+    }
+
+    /// The `nth` week of the month, as written in `TUE#2`
+    pub fn nth(nth: u32) -> Option<Self> {
+        match nth {
+            1 => Some(MonthWeek::First),
+            2 => Some(MonthWeek::Second),
+            3 => Some(MonthWeek::Third),
+            4 => Some(MonthWeek::Fourth),
+            _ => None,
+        }
+    }
+}
+
+/// A weekday of a given week of the month, read by [`parse_month_weekday`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonthWeekday {
+    /// `TUE#2` or `FRIL` in the day-of-week field of a cron expression
+    Cron {
+        /// the seven fields of the expression, day of week still written as `TUE#2` or `FRIL`
+        fields: Vec<String>,
+        /// the week of the month
+        week: MonthWeek,
+        /// the weekday
+        weekday: Weekday,
+    },
+    /// a phrase such as `"2nd tuesday of the month at 19:00"`
+    Phrase {
+        /// the week of the month
+        week: MonthWeek,
+        /// the weekday
+        weekday: Weekday,
+        /// hour of the day, not checked to be in range
+        hour: u32,
+        /// minute of the hour, not checked to be in range
+        minute: u32,
+    },
+}
+
+impl MonthWeekday {
+    /// The week of the month
+    pub fn week(&self) -> MonthWeek {
+        match self {
+            MonthWeekday::Cron { week, .. } | MonthWeekday::Phrase { week, .. } => *week,
+        }
+    }
+
+    /// The plain cron expression of the weekday within [`MonthWeekday::week`], e.g.
+    /// `0 0 19 8-14 * TUE *`; the last week also matches weekdays of the fourth one, which the
+    /// caller filters out
+    pub fn to_cron(&self) -> String {
+        let (mut fields, week, weekday) = match self {
+            MonthWeekday::Cron {
+                fields,
+                week,
+                weekday,
+            } => (fields.clone(), *week, *weekday),
+            MonthWeekday::Phrase {
+                week,
+                weekday,
+                hour,
+                minute,
+            } => (
+                [
+                    "0",
+                    &minute.to_string(),
+                    &hour.to_string(),
+                    "*",
+                    "*",
+                    "*",
+                    "*",
+                ]
+                .map(str::to_string)
+                .to_vec(),
+                *week,
+                *weekday,
+            ),
+        };
+        fields[3] = week.days().to_string();
+        fields[5] = weekday_name(weekday).to_string();
+        fields.join(" ")
+    }
+}
+
+/// The name of `day` in cron syntax, e.g. `MON`
+pub fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MON",
+        Weekday::Tue => "TUE",
+        Weekday::Wed => "WED",
+        Weekday::Thu => "THU",
+        Weekday::Fri => "FRI",
+        Weekday::Sat => "SAT",
+        Weekday::Sun => "SUN",
+    }
+}
+
+/// Parse expressions naming a weekday of a given week of the month, in cron (`TUE#2`, `FRIL`) or
+/// english (`"last friday of the month at 6 pm"`) form. `None` when the expression is neither,
+/// an error with the reason when it is one of them but malformed.
+pub fn parse_month_weekday(expression: &str) -> Option<Result<MonthWeekday, &'static str>> {
+    let fields = expression.split_whitespace().collect::<Vec<_>>();
+    if matches!(fields.len(), 6 | 7) && fields[5].contains(['#', 'L', 'l']) {
+        let day_of_week = fields[5];
+        let (day, week) = match day_of_week.split_once('#') {
+            Some((day, nth)) => (day, nth.parse().ok().and_then(MonthWeek::nth)),
+            None => (
+                day_of_week.strip_suffix(['L', 'l']).unwrap_or(day_of_week),
+                Some(MonthWeek::Last),
+            ),
+        };
+        let weekday = day.parse::<Weekday>().ok()?;
+        let Some(week) = week else {
+            return Some(Err("the week of the month must be 1-4"));
+        };
+        let mut fields = fields.into_iter().map(str::to_string).collect::<Vec<_>>();
+        if fields.len() == 6 {
+            fields.push("*".to_string());
+        }
+        return Some(Ok(MonthWeekday::Cron {
+            fields,
+            week,
+            weekday,
+        }));
+    }
+
+    let lower = expression.to_ascii_lowercase();
+    let words = lower
+        .split_whitespace()
+        .skip_while(|word| matches!(*word, "every" | "on" | "the"))
+        .collect::<Vec<_>>();
+    let week = match *words.first()? {
+        "first" | "1st" => MonthWeek::First,
+        "second" | "2nd" => MonthWeek::Second,
+        "third" | "3rd" => MonthWeek::Third,
+        "fourth" | "4th" => MonthWeek::Fourth,
+        "last" => MonthWeek::Last,
+        _ => return None,
+    };
+    let weekday = words.get(1)?.parse::<Weekday>().ok()?;
+    let rest = match &words[2..] {
+        ["of", "the" | "every" | "each", "month", rest @ ..] | ["of", "month", rest @ ..] => rest,
+        _ => return None,
+    };
+    let (hour, minute) = match rest {
+        [] => (0, 0),
+        ["at", time @ ..] => match parse_time(time) {
+            Some(time) => time,
+            None => return Some(Err("unsupported time of day")),
+        },
+        _ => return Some(Err("expected `at <time>` after the month")),
+    };
+    Some(Ok(MonthWeekday::Phrase {
+        week,
+        weekday,
+        hour,
+        minute,
+    }))
+}
+
+/// Parse `19:00`, `7 pm`, `7pm` or `7:30 pm` into hour and minute
+fn parse_time(words: &[&str]) -> Option<(u32, u32)> {
+    let joined = words.concat();
+    let (clock, offset) = if let Some(clock) = joined.strip_suffix("am") {
+        (clock, Some(0))
+    } else if let Some(clock) = joined.strip_suffix("pm") {
+        (clock, Some(12))
+    } else {
+        (joined.as_str(), None)
+    };
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+    let hour = match offset {
+        Some(offset) if (1..=12).contains(&hour) => hour % 12 + offset,
+        Some(_) => return None,
+        None => hour,
+    };
+    Some((hour, minute))
+}
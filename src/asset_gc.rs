@@ -0,0 +1,162 @@
+use crate::{ScheduleArrived, ScheduleTimer};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::{BoxedSystem, SystemId};
+use bevy_utils::tracing::debug;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Triggers [`AssetGcRequested`] on a schedule, so long sessions can drop assets they no longer
+/// use, and measures how long the cleanup took in [`AssetGcStats`].
+///
+/// The measured time covers the observers of [`AssetGcRequested`] and the system given to
+/// [`AssetGcPlugin::with_system`]. With the `dev` feature, it is published by
+/// `CronDiagnosticsPlugin` as `cron/asset_gc_time`.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(Resource, Default)]
+/// struct LevelCache(Vec<String>);
+///
+/// fn clear_cache(mut cache: ResMut<LevelCache>) {
+///     cache.0.clear();
+/// }
+///
+/// App::new()
+///     .init_resource::<LevelCache>()
+///     .add_plugins((
+///         CronJobPlugin,
+///         AssetGcPlugin::new("every 10 minutes").with_system(clear_cache),
+///     ));
+/// ```
+pub struct AssetGcPlugin {
+    /// when cleanups are requested
+    pub schedule: String,
+    system: Mutex<Option<BoxedSystem>>,
+}
+
+impl Default for AssetGcPlugin {
+    fn default() -> Self {
+        Self::new("every 10 minutes")
+    }
+}
+
+impl AssetGcPlugin {
+    /// Request cleanups on the cron or english `schedule`
+    pub fn new(schedule: impl Into<String>) -> Self {
+        Self {
+            schedule: schedule.into(),
+            system: Mutex::new(None),
+        }
+    }
+
+    /// Run `system` for every cleanup request
+    pub fn with_system<M>(self, system: impl IntoSystem<(), (), M>) -> Self {
+        *self.system.lock().unwrap() = Some(Box::new(IntoSystem::into_system(system)));
+        self
+    }
+}
+
+impl Plugin for AssetGcPlugin {
+    fn build(&self, app: &mut App) {
+        let timer = ScheduleTimer::new(self.schedule.as_str()).with_label("asset_gc");
+        let system = self
+            .system
+            .lock()
+            .unwrap()
+            .take()
+            .map(|system| app.world_mut().register_boxed_system(system));
+        app.init_resource::<AssetGcStats>()
+            .insert_resource(AssetGcSystem(system))
+            .add_observer(request_asset_gc);
+        app.world_mut().spawn((timer, AssetGcTimer));
+    }
+}
+
+/// Marks the timer spawned by [`AssetGcPlugin`]
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct AssetGcTimer;
+
+/// Triggered globally, without a target, when [`AssetGcPlugin`] requests a cleanup
+#[derive(Event, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AssetGcRequested;
+
+/// How long the cleanups requested by [`AssetGcPlugin`] took
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetGcStats {
+    /// cleanups run so far
+    pub runs: u64,
+    /// duration of the latest cleanup
+    pub last_duration: Duration,
+    /// duration of all cleanups together
+    pub total_duration: Duration,
+}
+
+impl AssetGcStats {
+    /// Average duration of a cleanup
+    pub fn average_duration(&self) -> Duration {
+        match self.runs {
+            0 => Duration::ZERO,
+            runs => self.total_duration / runs as u32,
+        }
+    }
+}
+
+#[derive(Resource)]
+struct AssetGcSystem(Option<SystemId>);
+
+fn request_asset_gc(
+    trigger: Trigger<ScheduleArrived>,
+    timers: Query<(), With<AssetGcTimer>>,
+    mut commands: Commands,
+) {
+    if !timers.contains(trigger.entity()) {
+        return;
+    }
+    commands.queue(|world: &mut World| {
+        let start = Instant::now();
+        world.trigger(AssetGcRequested);
+        if let Some(system) = world.resource::<AssetGcSystem>().0 {
+            if let Err(error) = world.run_system(system) {
+                debug!(%error, "asset cleanup system failed");
+            }
+        }
+        let elapsed = start.elapsed();
+        let mut stats = world.resource_mut::<AssetGcStats>();
+        stats.runs += 1;
+        stats.last_duration = elapsed;
+        stats.total_duration += elapsed;
+        debug!(?elapsed, "asset cleanup finished");
+    });
+}
+
+#[test]
+fn test_asset_gc() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    #[derive(Resource, Default)]
+    struct Cleanups(u32);
+
+    let mut app = cron_app();
+    app.add_plugins(AssetGcPlugin::new("every minute").with_system(
+        |mut cleanups: ResMut<Cleanups>| {
+            cleanups.0 += 1;
+            std::thread::sleep(Duration::from_millis(5));
+        },
+    ))
+    .init_resource::<Cleanups>()
+    .record::<AssetGcRequested>();
+    app.update();
+    for _ in 0..2 {
+        app.advance_cron_time(Duration::from_secs(60));
+    }
+    assert_eq!(app.world().resource::<Cleanups>().0, 2);
+    assert_eq!(app.triggered::<AssetGcRequested>().len(), 2);
+    let stats = app.world().resource::<AssetGcStats>();
+    assert_eq!(stats.runs, 2);
+    assert!(stats.last_duration >= Duration::from_millis(5));
+    assert!(stats.average_duration() >= Duration::from_millis(5));
+}
@@ -0,0 +1,159 @@
+use crate::{ScheduleArrived, ScheduleTimer};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::BoxedSystem;
+use bevy_utils::tracing::debug;
+use std::sync::Mutex;
+
+/// Requests a save on a schedule by triggering [`SaveRequested`], skipping fires while the
+/// previous save is still running.
+///
+/// A save counts as running from the request until [`Autosave::finish`] is called. A save system
+/// given to [`AutosavePlugin::with_system`] runs in the frame of the request and finishes the save
+/// on its own.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn save(_: Trigger<SaveRequested>, mut autosave: ResMut<Autosave>) {
+///     // write the save file, possibly on a task, then
+///     autosave.finish();
+/// }
+///
+/// App::new()
+///     .add_plugins((CronJobPlugin, AutosavePlugin::new("every 5 minutes")))
+///     .add_observer(save);
+/// ```
+pub struct AutosavePlugin {
+    /// when saves are requested
+    pub schedule: String,
+    system: Mutex<Option<BoxedSystem>>,
+}
+
+impl Default for AutosavePlugin {
+    fn default() -> Self {
+        Self::new("every 5 minutes")
+    }
+}
+
+impl AutosavePlugin {
+    /// Request saves on the cron or english `schedule`
+    pub fn new(schedule: impl Into<String>) -> Self {
+        Self {
+            schedule: schedule.into(),
+            system: Mutex::new(None),
+        }
+    }
+
+    /// Run `system` for every save request
+    pub fn with_system<M>(self, system: impl IntoSystem<(), (), M>) -> Self {
+        *self.system.lock().unwrap() = Some(Box::new(IntoSystem::into_system(system)));
+        self
+    }
+}
+
+impl Plugin for AutosavePlugin {
+    fn build(&self, app: &mut App) {
+        let timer = ScheduleTimer::new(self.schedule.as_str()).with_label("autosave");
+        app.init_resource::<Autosave>()
+            .add_observer(request_autosave);
+        app.world_mut().spawn((timer, AutosaveTimer));
+        if let Some(system) = self.system.lock().unwrap().take() {
+            let system = app.world_mut().register_boxed_system(system);
+            app.add_observer(move |_: Trigger<SaveRequested>, mut commands: Commands| {
+                commands.run_system(system);
+                commands.queue(|world: &mut World| world.resource_mut::<Autosave>().finish());
+            });
+        }
+    }
+}
+
+/// Marks the timer spawned by [`AutosavePlugin`]
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct AutosaveTimer;
+
+/// State of the saves requested by [`AutosavePlugin`]
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Autosave {
+    running: bool,
+    /// saves requested so far
+    pub requested: u64,
+    /// fires skipped because the previous save was still running
+    pub skipped: u64,
+}
+
+impl Autosave {
+    /// Mark the running save as finished, so the next fire requests a save again
+    pub fn finish(&mut self) {
+        self.running = false;
+    }
+
+    /// Whether a requested save has not finished yet
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+}
+
+/// Triggered globally, without a target, when [`AutosavePlugin`] requests a save
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaveRequested {
+    /// number of the request, starting at one
+    pub number: u64,
+}
+
+fn request_autosave(
+    trigger: Trigger<ScheduleArrived>,
+    timers: Query<(), With<AutosaveTimer>>,
+    mut autosave: ResMut<Autosave>,
+    mut commands: Commands,
+) {
+    if !timers.contains(trigger.entity()) {
+        return;
+    }
+    if autosave.running {
+        autosave.skipped += 1;
+        debug!(
+            skipped = autosave.skipped,
+            "autosave skipped, the previous save is still running"
+        );
+        return;
+    }
+    autosave.running = true;
+    autosave.requested += 1;
+    commands.trigger(SaveRequested {
+        number: autosave.requested,
+    });
+}
+
+#[test]
+fn test_autosave() {
+    use crate::testing::cron_app;
+    use crate::CronAppExt;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Saves(u32);
+
+    let mut app = cron_app();
+    app.add_plugins(
+        AutosavePlugin::new("every minute").with_system(|mut saves: ResMut<Saves>| saves.0 += 1),
+    )
+    .init_resource::<Saves>();
+    app.update();
+    let minute = Duration::from_secs(60);
+    app.advance_cron_time(minute).advance_cron_time(minute);
+    assert_eq!(app.world().resource::<Saves>().0, 2);
+    assert!(!app.world().resource::<Autosave>().is_running());
+
+    // without a save system, the save runs until it is finished
+    let mut app = cron_app();
+    app.add_plugins(AutosavePlugin::new("every minute"));
+    app.update();
+    app.advance_cron_time(minute).advance_cron_time(minute);
+    let autosave = app.world().resource::<Autosave>();
+    assert_eq!((autosave.requested, autosave.skipped), (1, 1));
+    app.world_mut().resource_mut::<Autosave>().finish();
+    app.advance_cron_time(minute);
+    assert_eq!(app.world().resource::<Autosave>().requested, 2);
+}
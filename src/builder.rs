@@ -0,0 +1,157 @@
+use crate::{
+    CatchUpPolicy, CronError, CronTimezone, FinishAction, IntoCronExpression, ScheduleAlignment,
+    ScheduleTimer, TimerSchedule,
+};
+use std::time::Duration;
+
+/// Configures a [`ScheduleTimer`] step by step, see [`ScheduleTimer::builder`].
+///
+/// The first error, e.g. an invalid expression or an unknown timezone name, is kept and returned
+/// by [`build`](Self::build), so options can be chained without checking each of them.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ScheduleTimerBuilder {
+    timer: Result<ScheduleTimer, CronError>,
+}
+
+impl ScheduleTimer {
+    /// Start configuring a timer on `expression`
+    ///
+    /// ```rust
+    /// use bevy_cronjob::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let timer = ScheduleTimer::builder("every 5 minutes")
+    ///     .timezone_named("+02:00")
+    ///     .max_runs(3)
+    ///     .jitter(Duration::from_secs(2))
+    ///     .catch_up(CatchUpPolicy::Skip)
+    ///     .build()?;
+    /// assert_eq!(timer.max_runs, Some(3));
+    /// # Ok::<(), CronError>(())
+    /// ```
+    pub fn builder(expression: impl IntoCronExpression) -> ScheduleTimerBuilder {
+        ScheduleTimerBuilder {
+            timer: expression
+                .into_cron_expr()
+                .map(|expr| Self::from_schedule(TimerSchedule::Cron(expr))),
+        }
+    }
+}
+
+impl From<ScheduleTimer> for ScheduleTimerBuilder {
+    fn from(timer: ScheduleTimer) -> Self {
+        Self { timer: Ok(timer) }
+    }
+}
+
+impl ScheduleTimerBuilder {
+    fn map(self, f: impl FnOnce(ScheduleTimer) -> ScheduleTimer) -> Self {
+        Self {
+            timer: self.timer.map(f),
+        }
+    }
+
+    /// See [`ScheduleTimer::with_label`]
+    pub fn label(self, label: impl Into<String>) -> Self {
+        self.map(|timer| timer.with_label(label))
+    }
+
+    /// See [`ScheduleTimer::with_tag`]
+    pub fn tag(self, tag: impl Into<String>) -> Self {
+        self.map(|timer| timer.with_tag(tag))
+    }
+
+    /// See [`ScheduleTimer::with_timezone`]
+    pub fn timezone(self, timezone: impl Into<CronTimezone>) -> Self {
+        self.map(|timer| timer.with_timezone(timezone))
+    }
+
+    /// Evaluate the expression in the timezone called `name`, e.g. `"UTC"` or `"+02:00"`,
+    /// failing the build when it is unknown
+    pub fn timezone_named(self, name: &str) -> Self {
+        Self {
+            timer: self
+                .timer
+                .and_then(|timer| Ok(timer.with_timezone(name.parse::<CronTimezone>()?))),
+        }
+    }
+
+    /// See [`ScheduleTimer::with_max_runs`]
+    pub fn max_runs(self, max_runs: u32) -> Self {
+        self.map(|timer| timer.with_max_runs(max_runs))
+    }
+
+    /// See [`ScheduleTimer::with_finish_action`]
+    pub fn on_finish(self, action: FinishAction) -> Self {
+        self.map(|timer| timer.with_finish_action(action))
+    }
+
+    /// See [`ScheduleTimer::with_jitter`]
+    pub fn jitter(self, jitter: Duration) -> Self {
+        self.map(|timer| timer.with_jitter(jitter))
+    }
+
+    /// See [`ScheduleTimer::with_catch_up`]
+    pub fn catch_up(self, policy: CatchUpPolicy) -> Self {
+        self.map(|timer| timer.with_catch_up(policy))
+    }
+
+    /// See [`ScheduleTimer::with_alignment`]
+    pub fn alignment(self, alignment: ScheduleAlignment) -> Self {
+        self.map(|timer| timer.with_alignment(alignment))
+    }
+
+    /// See [`ScheduleTimer::with_seed`]
+    pub fn seed(self, seed: u64) -> Self {
+        self.map(|timer| timer.with_seed(seed))
+    }
+
+    /// Start paused, see [`ScheduleTimer::pause`]
+    pub fn paused(self) -> Self {
+        self.map(|mut timer| {
+            timer.pause();
+            timer
+        })
+    }
+
+    /// The configured timer, or the first error met while configuring it
+    pub fn build(self) -> Result<ScheduleTimer, CronError> {
+        self.timer
+    }
+}
+
+#[test]
+fn test_timer_builder() {
+    let timer = ScheduleTimer::builder("every 5 minutes")
+        .label("harvest")
+        .timezone_named("+02:00")
+        .max_runs(3)
+        .jitter(Duration::from_secs(2))
+        .catch_up(CatchUpPolicy::Skip)
+        .paused()
+        .build()
+        .unwrap();
+    assert_eq!(timer.label(), Some("harvest"));
+    assert_eq!(timer.timezone, Some("+02:00".parse().unwrap()));
+    assert_eq!(timer.max_runs, Some(3));
+    assert_eq!(timer.jitter, Some(Duration::from_secs(2)));
+    assert_eq!(timer.catch_up, Some(CatchUpPolicy::Skip));
+    assert!(timer.is_paused());
+    assert_eq!(timer.expression(), Some("every 5 minutes"));
+
+    // the first error is kept
+    assert!(matches!(
+        ScheduleTimer::builder("every blue moon")
+            .timezone_named("Nowhere")
+            .build(),
+        Err(CronError::InvalidExpression { .. })
+    ));
+    assert!(matches!(
+        ScheduleTimer::builder("every hour")
+            .timezone_named("Nowhere")
+            .max_runs(1)
+            .build(),
+        Err(CronError::UnknownTimezone { .. })
+    ));
+}
@@ -0,0 +1,650 @@
+use crate::{AnyScheduleArrived, CronClock, ScheduleArrived};
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::debug;
+use std::time::Duration;
+
+/// The rules of an in-world calendar, so games can schedule on their own weeks, months and years
+/// with [`GameTimer`]s.
+///
+/// Only the month lengths are required, leap rules go into [`days_in_month`]. Years, months and
+/// days are numbered from 1, weekdays from 0, and day 1 of year 1 is weekday 0.
+///
+/// [`days_in_month`]: CalendarSystem::days_in_month
+pub trait CalendarSystem: Send + Sync + 'static {
+    /// number of days in a week
+    fn days_per_week(&self) -> u32 {
+        7
+    }
+
+    /// number of months in a year
+    fn months_per_year(&self) -> u32;
+
+    /// number of days in `month` of `year`, apply leap rules here
+    fn days_in_month(&self, year: u32, month: u32) -> u32;
+
+    /// number of hours in a day
+    fn hours_per_day(&self) -> u32 {
+        24
+    }
+
+    /// number of minutes in an hour
+    fn minutes_per_hour(&self) -> u32 {
+        60
+    }
+
+    /// number of seconds in a minute
+    fn seconds_per_minute(&self) -> u32 {
+        60
+    }
+
+    /// number of days in `year`
+    fn days_in_year(&self, year: u32) -> u32 {
+        (1..=self.months_per_year())
+            .map(|month| self.days_in_month(year, month))
+            .sum()
+    }
+
+    /// number of seconds in a day
+    fn seconds_per_day(&self) -> u64 {
+        self.hours_per_day() as u64
+            * self.minutes_per_hour() as u64
+            * self.seconds_per_minute() as u64
+    }
+
+    /// display name of a day of the week, from 0
+    fn weekday_name(&self, weekday: u32) -> String {
+        format!("Day {}", weekday + 1)
+    }
+
+    /// display name of a month, from 1
+    fn month_name(&self, month: u32) -> String {
+        format!("Month {month}")
+    }
+
+    /// the day of the week named `name`, ignoring case
+    fn weekday_by_name(&self, name: &str) -> Option<u32> {
+        (0..self.days_per_week())
+            .find(|weekday| self.weekday_name(*weekday).eq_ignore_ascii_case(name))
+    }
+
+    /// the month named `name`, ignoring case
+    fn month_by_name(&self, name: &str) -> Option<u32> {
+        (1..=self.months_per_year())
+            .find(|month| self.month_name(*month).eq_ignore_ascii_case(name))
+    }
+}
+
+/// A calendar without leap years, e.g. twelve months of thirty days with ten-day weeks
+///
+/// ```rust
+/// use bevy_cronjob::{CalendarSystem, SimpleCalendar};
+///
+/// let calendar = SimpleCalendar::new(12, 30)
+///     .with_weekday_names(["Sunday", "Moonday", "Starday"])
+///     .with_month_names(["Frostmoon", "Thawmoon"]);
+/// assert_eq!(calendar.days_in_year(1), 360);
+/// assert_eq!(calendar.days_per_week(), 3);
+/// assert_eq!(calendar.weekday_by_name("moonday"), Some(1));
+/// assert_eq!(calendar.month_name(2), "Thawmoon");
+/// assert_eq!(calendar.month_name(3), "Month 3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SimpleCalendar {
+    /// number of months in a year
+    pub months_per_year: u32,
+    /// number of days in every month
+    pub days_per_month: u32,
+    /// number of days in a week
+    pub days_per_week: u32,
+    /// number of hours in a day
+    pub hours_per_day: u32,
+    /// names of the days of the week
+    pub weekday_names: Vec<String>,
+    /// names of the months
+    pub month_names: Vec<String>,
+}
+
+impl SimpleCalendar {
+    /// `months_per_year` months of `days_per_month` days, with seven-day weeks and 24 hour days
+    pub fn new(months_per_year: u32, days_per_month: u32) -> Self {
+        Self {
+            months_per_year: months_per_year.max(1),
+            days_per_month: days_per_month.max(1),
+            days_per_week: 7,
+            hours_per_day: 24,
+            weekday_names: Vec::new(),
+            month_names: Vec::new(),
+        }
+    }
+
+    /// Change the length of a week
+    pub fn with_days_per_week(mut self, days: u32) -> Self {
+        self.days_per_week = days.max(1);
+        self
+    }
+
+    /// Change the length of a day
+    pub fn with_hours_per_day(mut self, hours: u32) -> Self {
+        self.hours_per_day = hours.max(1);
+        self
+    }
+
+    /// Name the days of the week, the week gets one day per name
+    pub fn with_weekday_names(
+        mut self,
+        names: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.weekday_names = names.into_iter().map(Into::into).collect();
+        if !self.weekday_names.is_empty() {
+            self.days_per_week = self.weekday_names.len() as u32;
+        }
+        self
+    }
+
+    /// Name the months, months without a name keep the default name
+    pub fn with_month_names(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.month_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl CalendarSystem for SimpleCalendar {
+    fn days_per_week(&self) -> u32 {
+        self.days_per_week
+    }
+
+    fn months_per_year(&self) -> u32 {
+        self.months_per_year
+    }
+
+    fn days_in_month(&self, _year: u32, _month: u32) -> u32 {
+        self.days_per_month
+    }
+
+    fn hours_per_day(&self) -> u32 {
+        self.hours_per_day
+    }
+
+    fn weekday_name(&self, weekday: u32) -> String {
+        match self.weekday_names.get(weekday as usize) {
+            Some(name) => name.clone(),
+            None => format!("Day {}", weekday + 1),
+        }
+    }
+
+    fn month_name(&self, month: u32) -> String {
+        match month
+            .checked_sub(1)
+            .and_then(|index| self.month_names.get(index as usize))
+        {
+            Some(name) => name.clone(),
+            None => format!("Month {month}"),
+        }
+    }
+}
+
+/// A date and time of a [`CalendarSystem`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GameDate {
+    /// year, from 1
+    pub year: u32,
+    /// month of the year, from 1
+    pub month: u32,
+    /// day of the month, from 1
+    pub day: u32,
+    /// day of the week, from 0
+    pub weekday: u32,
+    /// second of the day
+    pub second_of_day: u64,
+}
+
+impl GameDate {
+    /// The date `seconds` after the start of year 1
+    pub fn from_seconds(calendar: &dyn CalendarSystem, seconds: u64) -> Self {
+        let seconds_per_day = calendar.seconds_per_day().max(1);
+        let mut days = seconds / seconds_per_day;
+        let weekday = (days % calendar.days_per_week().max(1) as u64) as u32;
+        let mut year = 1;
+        while days >= calendar.days_in_year(year) as u64 {
+            days -= calendar.days_in_year(year) as u64;
+            year += 1;
+        }
+        let mut month = 1;
+        while days >= calendar.days_in_month(year, month) as u64 {
+            days -= calendar.days_in_month(year, month) as u64;
+            month += 1;
+        }
+        Self {
+            year,
+            month,
+            day: days as u32 + 1,
+            weekday,
+            second_of_day: seconds % seconds_per_day,
+        }
+    }
+
+    /// Seconds from the start of year 1 to this date
+    pub fn to_seconds(&self, calendar: &dyn CalendarSystem) -> u64 {
+        let days = (1..self.year)
+            .map(|year| calendar.days_in_year(year) as u64)
+            .sum::<u64>()
+            + (1..self.month)
+                .map(|month| calendar.days_in_month(self.year, month) as u64)
+                .sum::<u64>()
+            + self.day.saturating_sub(1) as u64;
+        days * calendar.seconds_per_day() + self.second_of_day
+    }
+
+    /// hour of the day
+    pub fn hour(&self, calendar: &dyn CalendarSystem) -> u32 {
+        (self.second_of_day / seconds_per_hour(calendar)) as u32
+    }
+
+    /// minute of the hour
+    pub fn minute(&self, calendar: &dyn CalendarSystem) -> u32 {
+        (self.second_of_day % seconds_per_hour(calendar) / calendar.seconds_per_minute() as u64)
+            as u32
+    }
+
+    /// Format the date with strftime-like specifiers, using the names of `calendar`:
+    ///
+    /// | specifier | value                      |
+    /// |-----------|----------------------------|
+    /// | `%Y`      | year                       |
+    /// | `%m`      | month number, zero-padded  |
+    /// | `%B`      | month name                 |
+    /// | `%d`      | day of month, zero-padded  |
+    /// | `%e`      | day of month               |
+    /// | `%A`      | weekday name               |
+    /// | `%H`      | hour, zero-padded          |
+    /// | `%M`      | minute, zero-padded        |
+    /// | `%S`      | second, zero-padded        |
+    /// | `%%`      | a literal `%`              |
+    ///
+    /// ```rust
+    /// use bevy_cronjob::{GameDate, SimpleCalendar};
+    ///
+    /// let calendar = SimpleCalendar::new(12, 30)
+    ///     .with_weekday_names(["Sunday", "Moonday", "Starday"])
+    ///     .with_month_names(["Frostmoon"]);
+    /// let date = GameDate::from_seconds(&calendar, 24 * 3600 + 6 * 3600);
+    /// assert_eq!(date.format(&calendar, "%A, %e %B %Y %H:%M"), "Moonday, 2 Frostmoon 1 06:00");
+    /// ```
+    pub fn format(&self, calendar: &dyn CalendarSystem, pattern: &str) -> String {
+        let mut out = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('Y') => out.push_str(&self.year.to_string()),
+                Some('m') => out.push_str(&format!("{:02}", self.month)),
+                Some('B') => out.push_str(&calendar.month_name(self.month)),
+                Some('d') => out.push_str(&format!("{:02}", self.day)),
+                Some('e') => out.push_str(&self.day.to_string()),
+                Some('A') => out.push_str(&calendar.weekday_name(self.weekday)),
+                Some('H') => out.push_str(&format!("{:02}", self.hour(calendar))),
+                Some('M') => out.push_str(&format!("{:02}", self.minute(calendar))),
+                Some('S') => out.push_str(&format!(
+                    "{:02}",
+                    self.second_of_day % calendar.seconds_per_minute().max(1) as u64
+                )),
+                Some('%') => out.push('%'),
+                Some(other) => {
+                    out.push('%');
+                    out.push(other);
+                }
+                None => out.push('%'),
+            }
+        }
+        out
+    }
+
+    /// The same time of day on the following day
+    fn next_day(mut self, calendar: &dyn CalendarSystem) -> Self {
+        self.weekday = (self.weekday + 1) % calendar.days_per_week().max(1);
+        self.day += 1;
+        if self.day > calendar.days_in_month(self.year, self.month) {
+            self.day = 1;
+            self.month += 1;
+            if self.month > calendar.months_per_year() {
+                self.month = 1;
+                self.year += 1;
+            }
+        }
+        self
+    }
+}
+
+fn seconds_per_hour(calendar: &dyn CalendarSystem) -> u64 {
+    (calendar.minutes_per_hour() as u64 * calendar.seconds_per_minute() as u64).max(1)
+}
+
+/// The in-world time, counted in seconds from the start of year 1 of its [`CalendarSystem`].
+///
+/// The clock only moves when advanced, typically from a system scaling real time:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn advance_game_clock(time: Res<Time>, mut clock: ResMut<GameClock>) {
+///     // one in-world minute per real second
+///     clock.advance(time.delta() * 60);
+/// }
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(GameClock::new(SimpleCalendar::new(12, 30).with_days_per_week(10)))
+///     .add_systems(Update, advance_game_clock);
+/// ```
+#[derive(Resource)]
+pub struct GameClock {
+    calendar: Box<dyn CalendarSystem>,
+    elapsed: Duration,
+}
+
+impl GameClock {
+    /// A clock at the start of year 1 of `calendar`
+    pub fn new(calendar: impl CalendarSystem) -> Self {
+        Self {
+            calendar: Box::new(calendar),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Start the clock at `date`
+    pub fn starting_at(mut self, date: GameDate) -> Self {
+        self.elapsed = Duration::from_secs(date.to_seconds(self.calendar()));
+        self
+    }
+
+    /// The rules of the calendar
+    pub fn calendar(&self) -> &dyn CalendarSystem {
+        self.calendar.as_ref()
+    }
+
+    /// Move the clock forward
+    pub fn advance(&mut self, by: Duration) {
+        self.elapsed += by;
+    }
+
+    /// Whole seconds from the start of year 1
+    pub fn seconds(&self) -> u64 {
+        self.elapsed.as_secs()
+    }
+
+    /// The current date
+    pub fn date(&self) -> GameDate {
+        GameDate::from_seconds(self.calendar(), self.seconds())
+    }
+
+    /// Format a time of this clock, e.g. the next fire of a [`GameTimer`], see
+    /// [`GameDate::format`]
+    pub fn format(&self, seconds: u64, pattern: &str) -> String {
+        GameDate::from_seconds(self.calendar(), seconds).format(self.calendar(), pattern)
+    }
+}
+
+/// When a [`GameTimer`] fires, in terms of a [`CalendarSystem`]
+///
+/// Every field left `None` matches any value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GameSchedule {
+    /// month of the year, from 1
+    pub month: Option<u32>,
+    /// day of the month, from 1
+    pub day: Option<u32>,
+    /// day of the week, from 0
+    pub weekday: Option<u32>,
+    /// hour of the day
+    pub hour: u32,
+    /// minute of the hour
+    pub minute: u32,
+}
+
+impl GameSchedule {
+    /// Every day at `hour:minute`
+    pub fn daily(hour: u32, minute: u32) -> Self {
+        Self {
+            hour,
+            minute,
+            ..Default::default()
+        }
+    }
+
+    /// Every week on `weekday` at `hour:minute`
+    pub fn weekly(weekday: u32, hour: u32, minute: u32) -> Self {
+        Self {
+            weekday: Some(weekday),
+            ..Self::daily(hour, minute)
+        }
+    }
+
+    /// Every month on `day` at `hour:minute`
+    pub fn monthly(day: u32, hour: u32, minute: u32) -> Self {
+        Self {
+            day: Some(day),
+            ..Self::daily(hour, minute)
+        }
+    }
+
+    /// Every year on `day` of `month` at `hour:minute`
+    pub fn yearly(month: u32, day: u32, hour: u32, minute: u32) -> Self {
+        Self {
+            month: Some(month),
+            ..Self::monthly(day, hour, minute)
+        }
+    }
+
+    /// Every week on the weekday named `weekday` at `hour:minute`, `None` when `calendar` has no
+    /// such weekday
+    pub fn weekly_on(
+        calendar: &dyn CalendarSystem,
+        weekday: &str,
+        hour: u32,
+        minute: u32,
+    ) -> Option<Self> {
+        Some(Self::weekly(
+            calendar.weekday_by_name(weekday)?,
+            hour,
+            minute,
+        ))
+    }
+
+    /// Every year on `day` of the month named `month` at `hour:minute`, `None` when `calendar`
+    /// has no such month
+    pub fn yearly_on(
+        calendar: &dyn CalendarSystem,
+        month: &str,
+        day: u32,
+        hour: u32,
+        minute: u32,
+    ) -> Option<Self> {
+        Some(Self::yearly(
+            calendar.month_by_name(month)?,
+            day,
+            hour,
+            minute,
+        ))
+    }
+
+    /// The next occurrence strictly after `after`, in seconds from the start of year 1.
+    /// Searches up to a hundred years ahead.
+    pub fn next_after(&self, calendar: &dyn CalendarSystem, after: u64) -> Option<u64> {
+        let time_of_day = self.hour as u64 * seconds_per_hour(calendar)
+            + self.minute as u64 * calendar.seconds_per_minute() as u64;
+        if time_of_day >= calendar.seconds_per_day() {
+            return None;
+        }
+        let mut date = GameDate::from_seconds(calendar, after);
+        let day_start = after - date.second_of_day;
+        date.second_of_day = 0;
+        let horizon = 100 * calendar.days_in_year(date.year).max(1) as u64;
+        for day in 0..horizon {
+            if self.matches(&date) {
+                let at = day_start + day * calendar.seconds_per_day() + time_of_day;
+                if at > after {
+                    return Some(at);
+                }
+            }
+            date = date.next_day(calendar);
+        }
+        None
+    }
+
+    fn matches(&self, date: &GameDate) -> bool {
+        self.month.is_none_or(|month| month == date.month)
+            && self.day.is_none_or(|day| day == date.day)
+            && self.weekday.is_none_or(|weekday| weekday == date.weekday)
+    }
+}
+
+/// A timer following the [`GameClock`], triggering [`ScheduleArrived`] and
+/// [`AnyScheduleArrived`] like a [`ScheduleTimer`](crate::ScheduleTimer)
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// const MOONDAY: u32 = 2;
+///
+/// fn setup(mut commands: Commands) {
+///     // every Moonday at dawn
+///     commands
+///         .spawn(GameTimer::new(GameSchedule::weekly(MOONDAY, 6, 0)).with_label("market"))
+///         .observe(|_: Trigger<ScheduleArrived>| info!("the market opens"));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct GameTimer {
+    /// when the timer fires
+    pub schedule: GameSchedule,
+    /// name identifying the job
+    pub label: Option<String>,
+    /// next planned fire, in seconds of the [`GameClock`]
+    pub next_fire: Option<u64>,
+}
+
+impl GameTimer {
+    /// A timer firing on `schedule`
+    pub fn new(schedule: GameSchedule) -> Self {
+        Self {
+            schedule,
+            label: None,
+            next_fire: None,
+        }
+    }
+
+    /// Name the job, the label is passed along with the fired events
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+pub(crate) fn check_game_timers(
+    clock: Res<GameClock>,
+    cron_clock: Res<CronClock>,
+    mut query: Query<(Entity, &mut GameTimer)>,
+    mut commands: Commands,
+) {
+    let now = clock.seconds();
+    for (entity, mut timer) in query.iter_mut() {
+        match timer.next_fire {
+            Some(next) if now >= next => {
+                timer.next_fire = timer.schedule.next_after(clock.calendar(), now);
+                debug!(label = timer.label.as_deref(), ?entity, "game timer fired");
+                // in-world occurrences have no real time of their own
+                let real_now = cron_clock.now();
+                commands.trigger(AnyScheduleArrived {
+                    entity,
+                    label: timer.label.clone(),
+                    scheduled: real_now,
+                    actual: real_now,
+                });
+                commands.trigger_targets(
+                    ScheduleArrived {
+                        label: timer.label.clone(),
+                        scheduled: real_now,
+                        actual: real_now,
+                        lateness: Duration::ZERO,
+                    },
+                    entity,
+                );
+            }
+            Some(_) => {}
+            None => timer.next_fire = timer.schedule.next_after(clock.calendar(), now),
+        }
+    }
+}
+
+#[test]
+fn test_game_calendar() {
+    let calendar = SimpleCalendar::new(3, 30).with_days_per_week(10);
+    let date = GameDate {
+        year: 2,
+        month: 3,
+        day: 17,
+        weekday: 0,
+        second_of_day: 3_600,
+    };
+    let seconds = date.to_seconds(&calendar);
+    let parsed = GameDate::from_seconds(&calendar, seconds);
+    assert_eq!((parsed.year, parsed.month, parsed.day), (2, 3, 17));
+    assert_eq!(parsed.hour(&calendar), 1);
+    // 90 + 60 + 16 days since the epoch
+    assert_eq!(parsed.weekday, 166 % 10);
+
+    let moonday_dawn = GameSchedule::weekly(2, 6, 0);
+    let next = moonday_dawn.next_after(&calendar, 0).unwrap();
+    let next_date = GameDate::from_seconds(&calendar, next);
+    assert_eq!((next_date.day, next_date.weekday), (3, 2));
+    assert_eq!(next_date.hour(&calendar), 6);
+    let after = moonday_dawn.next_after(&calendar, next).unwrap();
+    assert_eq!(after - next, 10 * calendar.seconds_per_day());
+}
+
+#[test]
+fn test_game_calendar_names() {
+    let calendar = SimpleCalendar::new(2, 10)
+        .with_weekday_names(["Sunday", "Moonday", "Starday", "Fireday", "Restday"])
+        .with_month_names(["Frostmoon", "Thawmoon"]);
+    let market = GameSchedule::weekly_on(&calendar, "MOONDAY", 6, 30).unwrap();
+    assert!(GameSchedule::weekly_on(&calendar, "Monday", 6, 0).is_none());
+    let festival = GameSchedule::yearly_on(&calendar, "thawmoon", 1, 0, 0).unwrap();
+    assert_eq!(festival.month, Some(2));
+
+    let mut clock = GameClock::new(calendar);
+    let next = market
+        .next_after(clock.calendar(), clock.seconds())
+        .unwrap();
+    assert_eq!(
+        clock.format(next, "%A %d %B, year %Y at %H:%M"),
+        "Moonday 02 Frostmoon, year 1 at 06:30"
+    );
+    clock.advance(Duration::from_secs(next));
+    assert_eq!(clock.date().format(clock.calendar(), "100%% %Q"), "100% %Q");
+}
+
+#[test]
+fn test_game_timer() {
+    use crate::testing::{cron_app, TestAppExt};
+
+    let calendar = SimpleCalendar::new(12, 30).with_days_per_week(10);
+    let day = Duration::from_secs(calendar.seconds_per_day());
+    let mut app = cron_app();
+    app.insert_resource(GameClock::new(calendar))
+        .record::<ScheduleArrived>();
+    app.world_mut()
+        .spawn(GameTimer::new(GameSchedule::weekly(2, 6, 0)));
+    for _ in 0..30 {
+        app.update();
+        app.world_mut().resource_mut::<GameClock>().advance(day);
+    }
+    app.update();
+
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 3);
+}
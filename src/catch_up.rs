@@ -0,0 +1,122 @@
+use crate::{CronTimezone, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+
+/// Most missed occurrences counted for [`CatchUpPolicy::FireOnce`]
+const MAX_COUNTED: u64 = 10_000;
+
+/// What a timer does with the occurrences it missed while the app was not updating, e.g. while
+/// suspended in the background or asleep on a laptop, see [`ScheduleTimer::with_catch_up`].
+///
+/// Missed occurrences are those that passed after the pending one, before the timer was checked
+/// again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum CatchUpPolicy {
+    /// drop the pending occurrence and the missed ones, triggering
+    /// [`ScheduleSkipped`](crate::ScheduleSkipped), and resume with the next upcoming one
+    Skip,
+    /// fire once for the pending occurrence, triggering [`ScheduleCaughtUp`] with the count of
+    /// the missed ones first
+    FireOnce,
+    /// fire the missed occurrences on consecutive checks, at most `max` of them, and resume with
+    /// the next upcoming one
+    FireAll {
+        /// most missed occurrences fired
+        max: u32,
+    },
+}
+
+/// Triggered on a timer entity with [`CatchUpPolicy::FireOnce`] right before
+/// [`ScheduleArrived`](crate::ScheduleArrived), when the fire stands for missed occurrences too
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleCaughtUp {
+    /// label of the timer
+    pub label: Option<String>,
+    /// occurrences missed after the one that fired, counted up to 10 000
+    pub missed: u64,
+}
+
+impl ScheduleTimer {
+    /// Handle occurrences missed while the app did not update according to `policy`, instead
+    /// of the [alignment](ScheduleTimer::with_alignment).
+    ///
+    /// ```rust
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// // grant the energy of every hour spent in the background, up to a day
+    /// let timer =
+    ///     ScheduleTimer::new("every hour").with_catch_up(CatchUpPolicy::FireAll { max: 24 });
+    /// ```
+    pub fn with_catch_up(mut self, policy: CatchUpPolicy) -> Self {
+        self.catch_up = Some(policy);
+        self
+    }
+
+    /// Where the search for the next occurrence starts after the pending occurrence `local`
+    /// passed at `now`, and whether `local` fires
+    pub(crate) fn catch_up_from(
+        &mut self,
+        policy: CatchUpPolicy,
+        local: DateTime<chrono::Local>,
+        now: DateTime<chrono::Local>,
+        timezone: CronTimezone,
+    ) -> (DateTime<chrono::Local>, bool) {
+        match policy {
+            CatchUpPolicy::Skip => (now, self.count_occurrences(local, now, timezone, 1) == 0),
+            CatchUpPolicy::FireOnce => {
+                self.caught_up = self.count_occurrences(local, now, timezone, MAX_COUNTED);
+                (now, true)
+            }
+            CatchUpPolicy::FireAll { max } => {
+                if self.caught_up < max as u64
+                    && self.count_occurrences(local, now, timezone, 1) > 0
+                {
+                    self.caught_up += 1;
+                    (local, true)
+                } else {
+                    self.caught_up = 0;
+                    (now, true)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_catch_up() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{CronAppExt, ScheduleArrived, ScheduleSkipped};
+    use std::time::Duration;
+
+    for (policy, arrived, skipped, missed) in [
+        (CatchUpPolicy::Skip, 1, 1, 0),
+        (CatchUpPolicy::FireOnce, 2, 0, 4),
+        (CatchUpPolicy::FireAll { max: 2 }, 4, 0, 0),
+    ] {
+        let mut app = cron_app();
+        app.record::<ScheduleArrived>()
+            .record::<ScheduleSkipped>()
+            .record::<ScheduleCaughtUp>();
+        app.world_mut()
+            .spawn(ScheduleTimer::new("every 10 seconds").with_catch_up(policy));
+        app.update();
+        // on time at 10 s
+        app.advance_cron_time(Duration::from_secs(10));
+        // stalled from 11 s to 61 s, missing 30 s to 60 s after the pending 20 s
+        app.advance_cron_time(Duration::from_secs(50));
+        app.update();
+        app.update();
+
+        let caught_up = app.triggered::<ScheduleCaughtUp>();
+        assert_eq!(
+            (
+                app.triggered::<ScheduleArrived>().len(),
+                app.triggered::<ScheduleSkipped>().len(),
+                caught_up.iter().map(|(_, event)| event.missed).sum::<u64>()
+            ),
+            (arrived, skipped, missed),
+            "{policy:?}"
+        );
+    }
+}
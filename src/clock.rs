@@ -0,0 +1,324 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Time, Virtual};
+use chrono::{DateTime, TimeZone};
+use std::time::Duration;
+
+/// The clock [`ScheduleTimer`](crate::ScheduleTimer)s are evaluated against.
+///
+/// Defaults to the wall clock. In tick mode, the current time is derived from a tick counter
+/// advanced by the simulation, so lockstep or replayed simulations fire on the same ticks on
+/// every machine:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn step(mut clock: ResMut<CronClock>) {
+///     clock.advance(1);
+/// }
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(CronClock::ticks(60))
+///     .add_systems(FixedUpdate, step);
+/// ```
+///
+/// Cron expressions are still interpreted in the local timezone, so expressions on hours or days
+/// only fire on identical ticks across machines sharing a timezone.
+///
+/// In synced mode, the system clock is corrected towards an authoritative time, so clients fire
+/// on "server midnight" even when their system clock is off:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use chrono::DateTime;
+///
+/// // called by the netcode whenever the server time is received
+/// fn on_server_time(In(server_now): In<DateTime<chrono::Local>>, mut clock: ResMut<CronClock>) {
+///     clock.sync(server_now);
+/// }
+/// ```
+///
+/// In virtual mode, the clock follows the game time of Bevy's `Time<Virtual>`, so schedules
+/// stop while the game is paused and run faster or slower with its relative speed, e.g. a shop
+/// restocking every in-game hour:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// App::new()
+///     .add_plugins((MinimalPlugins, CronJobPlugin))
+///     .insert_resource(CronClock::virtual_time());
+/// ```
+///
+/// In manual mode, the clock stands still until it is moved, so tests exercise schedules without
+/// sleeping, see [`CronAppExt::advance_cron_time`](crate::CronAppExt::advance_cron_time):
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use chrono::TimeZone;
+///
+/// let midnight = chrono::Local.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let mut app = App::new();
+/// app.add_plugins(CronJobPlugin)
+///     .insert_resource(CronClock::manual(midnight));
+/// app.advance_cron_time(std::time::Duration::from_secs(3600));
+/// assert_eq!(app.world().resource::<CronClock>().now().timestamp(), midnight.timestamp() + 3600);
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub enum CronClock {
+    /// the system clock
+    #[default]
+    Wall,
+    /// a deterministic tick counter
+    Ticks {
+        /// ticks elapsed since `epoch`
+        tick: u64,
+        /// how many ticks make one second
+        ticks_per_second: u32,
+        /// the time of tick zero
+        epoch: DateTime<chrono::Local>,
+    },
+    /// the system clock corrected by an offset, e.g. towards a server or NTP time
+    Synced {
+        /// added to the system clock
+        offset: chrono::Duration,
+    },
+    /// the virtual game time, updated from `Time<Virtual>` before the timers are checked
+    Virtual {
+        /// virtual time elapsed since `epoch`
+        elapsed: Duration,
+        /// the time at virtual time zero
+        epoch: DateTime<chrono::Local>,
+    },
+    /// a clock only moved by [`set`](Self::set) and [`fast_forward`](Self::fast_forward)
+    Manual {
+        /// the current time
+        now: DateTime<chrono::Local>,
+    },
+}
+
+impl CronClock {
+    /// A tick clock starting at tick zero on 2000-01-01 00:00:00 UTC
+    pub fn ticks(ticks_per_second: u32) -> Self {
+        Self::Ticks {
+            tick: 0,
+            ticks_per_second: ticks_per_second.max(1),
+            epoch: chrono::Utc
+                .with_ymd_and_hms(2000, 1, 1, 0, 0, 0)
+                .unwrap()
+                .with_timezone(&chrono::Local),
+        }
+    }
+
+    /// A clock following `Time<Virtual>`, reading the current system time at virtual time zero
+    pub fn virtual_time() -> Self {
+        Self::Virtual {
+            elapsed: Duration::ZERO,
+            epoch: chrono::Local::now(),
+        }
+    }
+
+    /// A clock standing still at `now` until it is moved
+    pub fn manual(now: DateTime<chrono::Local>) -> Self {
+        Self::Manual { now }
+    }
+
+    /// Set the time of tick zero of a tick clock, or of virtual time zero of a virtual clock
+    pub fn with_epoch(mut self, time: DateTime<chrono::Local>) -> Self {
+        if let Self::Ticks { epoch, .. } | Self::Virtual { epoch, .. } = &mut self {
+            *epoch = time;
+        }
+        self
+    }
+
+    /// A clock following the system clock, corrected to read `authoritative_now` at this instant
+    pub fn synced(authoritative_now: DateTime<chrono::Local>) -> Self {
+        Self::Synced {
+            offset: authoritative_now - chrono::Local::now(),
+        }
+    }
+
+    /// Correct the clock to read `authoritative_now` at this instant, switching the wall clock
+    /// to synced mode. Does nothing on tick and virtual clocks.
+    pub fn sync(&mut self, authoritative_now: DateTime<chrono::Local>) {
+        if self.is_real_time() {
+            *self = Self::synced(authoritative_now);
+        }
+    }
+
+    /// Move a manual clock to `time`, possibly backwards. Does nothing on other clocks.
+    pub fn set(&mut self, time: DateTime<chrono::Local>) {
+        if let Self::Manual { now } = self {
+            *now = time;
+        }
+    }
+
+    /// Whether the clock follows real time, i.e. is neither a tick, a virtual nor a manual clock
+    pub fn is_real_time(&self) -> bool {
+        matches!(self, Self::Wall | Self::Synced { .. })
+    }
+
+    /// Whether the clock advances with the frames, i.e. is neither a tick nor a manual clock.
+    /// Frame timers follow the frame delta on these clocks.
+    pub(crate) fn follows_frames(&self) -> bool {
+        !matches!(self, Self::Ticks { .. } | Self::Manual { .. })
+    }
+
+    /// Advance a tick clock by `ticks`, does nothing on the wall clock
+    pub fn advance(&mut self, ticks: u64) {
+        if let Self::Ticks { tick, .. } = self {
+            *tick += ticks;
+        }
+    }
+
+    /// Move the clock forward by `by`, switching the wall clock to synced mode. Tick clocks
+    /// advance by the matching number of ticks, virtual clocks move their epoch and manual clocks
+    /// their time.
+    pub fn fast_forward(&mut self, by: Duration) {
+        match self {
+            Self::Wall => {
+                *self = Self::Synced {
+                    offset: chrono::Duration::from_std(by).unwrap_or_default(),
+                }
+            }
+            Self::Synced { offset } => {
+                *offset += chrono::Duration::from_std(by).unwrap_or_default();
+            }
+            Self::Ticks {
+                tick,
+                ticks_per_second,
+                ..
+            } => {
+                *tick += (by.as_nanos() * *ticks_per_second as u128 / 1_000_000_000) as u64;
+            }
+            Self::Virtual { epoch, .. } | Self::Manual { now: epoch } => {
+                *epoch += chrono::Duration::from_std(by).unwrap_or_default();
+            }
+        }
+    }
+
+    /// The current tick of a tick clock
+    pub fn tick(&self) -> Option<u64> {
+        match self {
+            Self::Wall | Self::Synced { .. } | Self::Virtual { .. } | Self::Manual { .. } => None,
+            Self::Ticks { tick, .. } => Some(*tick),
+        }
+    }
+
+    /// The current time according to the clock
+    pub fn now(&self) -> DateTime<chrono::Local> {
+        match self {
+            Self::Wall => chrono::Local::now(),
+            Self::Synced { offset } => chrono::Local::now() + *offset,
+            Self::Ticks {
+                tick,
+                ticks_per_second,
+                epoch,
+            } => {
+                let nanos = *tick as i128 * 1_000_000_000 / *ticks_per_second as i128;
+                *epoch + chrono::Duration::nanoseconds(nanos as i64)
+            }
+            Self::Virtual { elapsed, epoch } => {
+                *epoch + chrono::Duration::from_std(*elapsed).unwrap_or_default()
+            }
+            Self::Manual { now } => *now,
+        }
+    }
+}
+
+/// Copies the elapsed `Time<Virtual>` into a virtual [`CronClock`]
+pub(crate) fn sync_virtual_clock(mut clock: ResMut<CronClock>, time: Res<Time<Virtual>>) {
+    if let CronClock::Virtual { elapsed, .. } = clock.as_mut() {
+        *elapsed = time.elapsed();
+    }
+}
+
+#[test]
+fn test_tick_clock() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{ScheduleArrived, ScheduleTimer};
+
+    let epoch = CronClock::ticks(10).now();
+    let mut app = cron_app();
+    app.insert_resource(CronClock::ticks(10))
+        .record::<ScheduleArrived>();
+    app.world_mut().spawn(ScheduleTimer::new("every 5 seconds"));
+    for _ in 0..120 {
+        app.world_mut().resource_mut::<CronClock>().advance(1);
+        app.update();
+    }
+    let fired = app
+        .triggered::<ScheduleArrived>()
+        .iter()
+        .map(|(_, fire)| (fire.actual - epoch).num_milliseconds())
+        .collect::<Vec<_>>();
+    // the epoch is on a 5 second boundary and every occurrence fires on the tick after it
+    assert_eq!(fired, [5100, 10100]);
+}
+
+#[test]
+fn test_synced_clock() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{ScheduleArrived, ScheduleTimer};
+
+    // the server is two hours ahead of the local clock
+    let server_now = chrono::Local::now() + chrono::Duration::hours(2);
+    let mut app = cron_app();
+    app.insert_resource(CronClock::synced(server_now))
+        .record::<ScheduleArrived>();
+    let mut timer = ScheduleTimer::new("every hour");
+    // due an hour from now locally, but already passed on the server
+    timer.local_schedule = Some(chrono::Local::now() + chrono::Duration::hours(1));
+    app.world_mut().spawn(timer);
+    app.update();
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 1);
+
+    let mut clock = app.world_mut().resource_mut::<CronClock>();
+    let drift = (clock.now() - server_now).num_seconds();
+    assert!(drift.abs() <= 1);
+    clock.sync(chrono::Local::now());
+    assert!((clock.now() - chrono::Local::now()).num_seconds().abs() <= 1);
+    assert!(clock.is_real_time());
+}
+
+#[test]
+fn test_virtual_clock() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{ScheduleArrived, ScheduleTimer};
+    use bevy_app::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Runs(u32);
+
+    let mut app = cron_app();
+    app.add_systems(
+        Update,
+        (|mut runs: ResMut<Runs>| runs.0 += 1).run_if(crate::schedule_passed("every hour")),
+    )
+    .init_resource::<Time<Virtual>>()
+    .insert_resource(CronClock::virtual_time().with_epoch(CronClock::ticks(1).now()))
+    .init_resource::<Runs>()
+    .record::<ScheduleArrived>();
+    app.world_mut().spawn(ScheduleTimer::new("every hour"));
+    let step = |app: &mut App, seconds: u64| {
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .advance_by(Duration::from_secs(seconds));
+        app.update();
+    };
+    step(&mut app, 1);
+    step(&mut app, 3600);
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 1);
+    assert_eq!(app.world().resource::<Runs>().0, 1);
+
+    // the clock reads the virtual time, which bevy_time holds while the game is paused
+    assert_eq!(
+        app.world().resource::<CronClock>().now(),
+        CronClock::ticks(1).now() + chrono::Duration::seconds(3601)
+    );
+    assert!(!app.world().resource::<CronClock>().is_real_time());
+}
@@ -0,0 +1,416 @@
+use crate::jitter::random_offset;
+use crate::{CronClock, CronExpr, IntoCronExpression};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::{CombinatorSystem, Combine, SystemIn};
+use chrono::DateTime;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Creates a system that returns the scheduled time of the occurrence that just passed, or
+/// `None` when the cron expression has not passed this frame.
+///
+/// See [`schedule_passed`](crate::schedule_passed) for the expression format.
+#[allow(clippy::type_complexity)]
+pub fn schedule_fired(
+    expression: impl IntoCronExpression,
+) -> impl FnMut(
+    Local<Option<DateTime<chrono::Local>>>,
+    Option<Res<CronClock>>,
+    Option<Res<CronConditionRegistry>>,
+) -> Option<DateTime<chrono::Local>> {
+    labeled_schedule_fired(None, expression)
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn labeled_schedule_fired(
+    label: Option<String>,
+    expression: impl IntoCronExpression,
+) -> impl FnMut(
+    Local<Option<DateTime<chrono::Local>>>,
+    Option<Res<CronClock>>,
+    Option<Res<CronConditionRegistry>>,
+) -> Option<DateTime<chrono::Local>> {
+    let schedule = expression
+        .into_cron_expr()
+        .expect("Failed to parse cron expression");
+    let id = NEXT_CONDITION_ID.fetch_add(1, Ordering::Relaxed);
+    // the next fire last written to the registry, which is only locked when it changes
+    let mut reported = None;
+    move |mut local_schedule: Local<Option<DateTime<chrono::Local>>>,
+          clock: Option<Res<CronClock>>,
+          registry: Option<Res<CronConditionRegistry>>| {
+        let mut fired = None;
+        let now = clock.map_or_else(chrono::Local::now, |clock| clock.now());
+        if let Some(datetime) = schedule.after(&now).next() {
+            match *local_schedule {
+                Some(local) => {
+                    if now > local {
+                        *local_schedule = Some(datetime);
+                        fired = Some(local);
+                    }
+                }
+
+                None => *local_schedule = Some(datetime),
+            }
+        }
+        if let Some(registry) = registry.filter(|_| reported != Some(*local_schedule)) {
+            registry.update(id, &label, &schedule, *local_schedule, fired);
+            reported = Some(*local_schedule);
+        }
+
+        fired
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn jittered_schedule_fired(
+    expression: impl IntoCronExpression,
+    jitter: Duration,
+) -> impl FnMut(
+    Option<Res<CronClock>>,
+    Option<Res<CronConditionRegistry>>,
+) -> Option<DateTime<chrono::Local>> {
+    let schedule = expression
+        .into_cron_expr()
+        .expect("Failed to parse cron expression");
+    let id = NEXT_CONDITION_ID.fetch_add(1, Ordering::Relaxed);
+    let mut rng = fastrand::Rng::new();
+    // the pending occurrence and its jittered fire time
+    let mut pending: Option<(DateTime<chrono::Local>, DateTime<chrono::Local>)> = None;
+    move |clock: Option<Res<CronClock>>, registry: Option<Res<CronConditionRegistry>>| {
+        let mut fired = None;
+        let now = clock.map_or_else(chrono::Local::now, |clock| clock.now());
+        let from = match pending {
+            Some((_, at)) if now <= at => None,
+            // searched after the occurrence, which may still be ahead of an early fire
+            Some((occurrence, _)) => {
+                fired = Some(occurrence);
+                Some(occurrence.max(now))
+            }
+            None => Some(now),
+        };
+        if let Some(from) = from {
+            pending = schedule.after(&from).next().and_then(|occurrence| {
+                let at = occurrence.checked_add_signed(random_offset(&mut rng, jitter))?;
+                Some((occurrence, at))
+            });
+            // the registry lists occurrences, as for unjittered conditions
+            if let Some(registry) = registry {
+                let next_fire = pending.map(|(occurrence, _)| occurrence);
+                registry.update(id, &None, &schedule, next_fire, fired);
+            }
+        }
+
+        fired
+    }
+}
+
+/// Creates a run condition that is true between an occurrence of `start` and the following
+/// occurrence of `end`, e.g. at night between `"0 0 22 * * *"` and `"0 0 6 * * *"`.
+///
+/// The window is known from the first run, without waiting for `start` to pass, and follows the
+/// [`CronClock`] when the app has one. Entities follow such windows with a
+/// [`ScheduleWindow`](crate::ScheduleWindow). Meant to gate whole system sets:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct NightSystems;
+///
+/// App::new().configure_sets(
+///     Update,
+///     NightSystems.run_if(in_cron_window("0 0 22 * * *", "0 0 6 * * *")),
+/// );
+/// ```
+pub fn in_cron_window(
+    start: impl IntoCronExpression,
+    end: impl IntoCronExpression,
+) -> impl FnMut(Option<Res<CronClock>>) -> bool {
+    let start = start
+        .into_cron_expr()
+        .expect("Failed to parse cron expression");
+    let end = end
+        .into_cron_expr()
+        .expect("Failed to parse cron expression");
+    let mut window = None;
+    move |clock: Option<Res<CronClock>>| {
+        let now = clock.map_or_else(chrono::Local::now, |clock| clock.now());
+        CronWindow::update(&mut window, &start, &end, now).open
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CronWindow {
+    pub(crate) next_start: Option<DateTime<chrono::Local>>,
+    pub(crate) next_end: Option<DateTime<chrono::Local>>,
+    pub(crate) open: bool,
+}
+
+impl CronWindow {
+    /// The window at `now`, reusing `window` until its next start or end passed
+    pub(crate) fn update(
+        window: &mut Option<Self>,
+        start: &CronExpr,
+        end: &CronExpr,
+        now: DateTime<chrono::Local>,
+    ) -> Self {
+        match *window {
+            Some(current) if current.is_current(now) => current,
+            _ => *window.insert(Self::at(start, end, now)),
+        }
+    }
+
+    /// Whether neither the next start nor the next end passed at `now`
+    pub(crate) fn is_current(&self, now: DateTime<chrono::Local>) -> bool {
+        self.next_start.is_none_or(|start| now < start) && self.next_end.is_none_or(|end| now < end)
+    }
+
+    /// The window is open when `end` comes before the next `start`
+    fn at(start: &CronExpr, end: &CronExpr, now: DateTime<chrono::Local>) -> Self {
+        let next_start = start.after(&now).next();
+        let next_end = end.after(&now).next();
+        let open = match (next_start, next_end) {
+            (Some(next_start), Some(next_end)) => next_end < next_start,
+            (None, Some(_)) => true,
+            (_, None) => false,
+        };
+        Self {
+            next_start,
+            next_end,
+            open,
+        }
+    }
+}
+
+static NEXT_CONDITION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Tracks the conditions created by [`schedule_passed`](crate::schedule_passed) and
+/// [`schedule_fired`], so tooling can list condition-based jobs next to
+/// [`ScheduleTimer`](crate::ScheduleTimer)s.
+///
+/// Inserted by [`CronJobPlugin`](crate::CronJobPlugin) with the `condition_registry` feature.
+/// A condition appears after it ran for the first time.
+#[derive(Resource, Debug, Default)]
+pub struct CronConditionRegistry {
+    conditions: Mutex<BTreeMap<u64, ConditionEntry>>,
+}
+
+/// A condition tracked by [`CronConditionRegistry`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionEntry {
+    /// unique id of the condition
+    pub id: u64,
+    /// label given with [`schedule_passed_labeled`](crate::schedule_passed_labeled)
+    pub label: Option<String>,
+    /// the cron expression
+    pub expression: String,
+    /// scheduled time of the last occurrence that passed
+    pub last_fire: Option<DateTime<chrono::Local>>,
+    /// next planned occurrence
+    pub next_fire: Option<DateTime<chrono::Local>>,
+}
+
+impl CronConditionRegistry {
+    /// The tracked conditions, in order of creation
+    pub fn entries(&self) -> Vec<ConditionEntry> {
+        self.lock().values().cloned().collect()
+    }
+
+    fn update(
+        &self,
+        id: u64,
+        label: &Option<String>,
+        schedule: &CronExpr,
+        next_fire: Option<DateTime<chrono::Local>>,
+        fired: Option<DateTime<chrono::Local>>,
+    ) {
+        let mut conditions = self.lock();
+        let entry = conditions.entry(id).or_insert_with(|| ConditionEntry {
+            id,
+            label: label.clone(),
+            expression: schedule.as_str().to_string(),
+            last_fire: None,
+            next_fire: None,
+        });
+        entry.next_fire = next_fire;
+        if fired.is_some() {
+            entry.last_fire = fired;
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<u64, ConditionEntry>> {
+        self.conditions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Runs `system` whenever the cron expression passes, passing the scheduled time of the
+/// occurrence as [`In`] so the system knows which instant it is servicing.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use chrono::DateTime;
+///
+/// fn backup(In(scheduled): In<DateTime<chrono::Local>>) {
+///     info!("running backup scheduled for {scheduled}");
+/// }
+///
+/// App::new().add_systems(Update, on_schedule("every hour", backup));
+/// ```
+pub fn on_schedule<M>(
+    expression: impl IntoCronExpression + 'static,
+    system: impl IntoSystem<In<DateTime<chrono::Local>>, (), M>,
+) -> impl System<In = (), Out = ()> {
+    let condition = IntoSystem::into_system(schedule_fired(expression));
+    let system = IntoSystem::into_system(system);
+    let name = format!("on_schedule({})", system.name());
+    CombinatorSystem::<ScheduledPipe, _, _>::new(condition, system, name.into())
+}
+
+/// [`Combine`] implementation running the second system only when the first yields a fire time
+#[doc(hidden)]
+pub struct ScheduledPipe;
+
+impl<A, B> Combine<A, B> for ScheduledPipe
+where
+    A: System<In = (), Out = Option<DateTime<chrono::Local>>>,
+    B: System<In = In<DateTime<chrono::Local>>, Out = ()>,
+{
+    type In = ();
+    type Out = ();
+
+    fn combine(
+        _input: (),
+        a: impl FnOnce(SystemIn<'_, A>) -> A::Out,
+        b: impl FnOnce(SystemIn<'_, B>) -> B::Out,
+    ) {
+        if let Some(scheduled) = a(()) {
+            b(scheduled);
+        }
+    }
+}
+
+#[test]
+fn test_cron_window() {
+    use chrono::TimeZone;
+
+    let start = CronExpr::parse("0 0 22 * * *").unwrap();
+    let end = CronExpr::parse("0 0 6 * * *").unwrap();
+    let at = |hour| {
+        let now = chrono::Local
+            .with_ymd_and_hms(2024, 6, 1, hour, 30, 0)
+            .unwrap();
+        CronWindow::at(&start, &end, now).open
+    };
+    assert!(at(23));
+    assert!(at(2));
+    assert!(!at(6));
+    assert!(!at(12));
+    assert!(!at(21));
+}
+
+#[test]
+fn test_cron_window_condition() {
+    use crate::testing::cron_app;
+    use crate::CronAppExt;
+    use bevy_app::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Runs(u32);
+
+    // open during the first half of every minute
+    let mut app = cron_app();
+    app.init_resource::<Runs>().add_systems(
+        Update,
+        (|mut runs: ResMut<Runs>| runs.0 += 1)
+            .run_if(in_cron_window("0 * * * * ? *", "30 * * * * ? *")),
+    );
+    let mut runs = vec![];
+    for seconds in [9, 20, 20, 20] {
+        app.advance_cron_time(Duration::from_secs(seconds));
+        runs.push(app.world().resource::<Runs>().0);
+    }
+    // at 10 s, 30 s, 50 s and 70 s
+    assert_eq!(runs, [1, 1, 1, 2]);
+}
+
+#[test]
+fn test_condition_registry() {
+    use bevy_app::prelude::*;
+
+    let mut app = App::new();
+    app.init_resource::<CronConditionRegistry>().add_systems(
+        Update,
+        (
+            (|| {}).run_if(crate::schedule_passed("every hour")),
+            (|| {}).run_if(crate::schedule_passed_labeled("backup", "0 0 3 * * ? *")),
+        ),
+    );
+    app.update();
+    let entries = app.world().resource::<CronConditionRegistry>().entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].label, None);
+    assert_eq!(entries[0].expression, "0 0 * * * ? *");
+    assert_eq!(entries[1].label.as_deref(), Some("backup"));
+    assert!(entries.iter().all(|entry| entry.next_fire.is_some()));
+}
+
+#[test]
+fn test_jittered_condition() {
+    use crate::testing::cron_app;
+    use crate::CronAppExt;
+    use bevy_app::prelude::*;
+    use chrono::Timelike;
+
+    #[derive(Resource, Default)]
+    struct Fired(Vec<DateTime<chrono::Local>>);
+
+    let mut app = cron_app();
+    app.init_resource::<Fired>().add_systems(
+        Update,
+        jittered_schedule_fired("every minute", Duration::from_secs(10)).pipe(
+            |In(scheduled): In<Option<DateTime<chrono::Local>>>, mut fired: ResMut<Fired>| {
+                fired.0.extend(scheduled)
+            },
+        ),
+    );
+    // 1 s to 196 s, the minutes 1 to 3 pass once each
+    app.update();
+    for _ in 0..39 {
+        app.advance_cron_time(Duration::from_secs(5));
+    }
+    // the occurrences are reported, not their jittered fire times
+    let fired = &app.world().resource::<Fired>().0;
+    assert_eq!(fired.len(), 3);
+    assert!(fired.iter().all(|scheduled| scheduled.second() == 0));
+}
+
+#[cfg(test)]
+#[derive(Resource, Default)]
+struct CronAttributeRuns(u32);
+
+#[cfg(test)]
+#[crate::cron("* * * * * ? *")]
+fn every_second(mut runs: ResMut<CronAttributeRuns>) {
+    runs.0 += 1;
+}
+
+#[test]
+fn test_cron_attribute() {
+    use crate::testing::cron_app;
+    use crate::CronAppExt;
+
+    assert_eq!(every_second::EXPRESSION, "* * * * * ? *");
+    let mut app = cron_app();
+    app.init_resource::<CronAttributeRuns>()
+        .add_plugins(every_second::plugin);
+    app.update();
+    app.advance_cron_time(Duration::from_millis(1100));
+    assert_eq!(app.world().resource::<CronAttributeRuns>().0, 1);
+}
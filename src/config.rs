@@ -0,0 +1,327 @@
+//! Job definitions loaded from `.cron.ron` or `.cron.toml` assets, requires the `config` feature.
+//!
+//! ```ron
+//! (
+//!     jobs: [
+//!         (name: "backup", expression: "every day at 3 am"),
+//!         (name: "rotate_logs", expression: "0 0 * * * ? *", tags: ["maintenance"]),
+//!     ],
+//! )
+//! ```
+//!
+//! ```toml
+//! [[jobs]]
+//! name = "backup"
+//! expression = "every day at 3 am"
+//! paused = true
+//! ```
+
+use crate::{CronError, CronExpr, ScheduleTimer, TimerSchedule};
+use bevy_app::prelude::*;
+use bevy_asset::io::Reader;
+use bevy_asset::{Asset, AssetApp, AssetEvent, AssetId, AssetLoader, Assets, LoadContext};
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::DespawnRecursiveExt;
+use bevy_reflect::TypePath;
+use bevy_utils::tracing::{debug, warn};
+use serde::Deserialize;
+use std::fmt;
+
+/// Spawns a [`ScheduleTimer`] for each job of the loaded [`CronJobConfigAsset`]s and keeps them
+/// in sync when the assets change, e.g. through hot reloading.
+///
+/// Timers are labeled with the name of their job, so observers of
+/// [`ScheduleArrived`](crate::ScheduleArrived) can tell them apart. A job whose expression
+/// changes keeps its entity and is re-armed, a job whose expression is invalid keeps its previous
+/// timer. Keep the handle of the asset alive, the timers are despawned when it is unloaded.
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_asset::{AssetServer, Handle};
+/// use bevy_cronjob::config::{CronConfigPlugin, CronJobConfigAsset};
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Maintenance(Handle<CronJobConfigAsset>);
+///
+/// fn load(mut commands: Commands, assets: Res<AssetServer>) {
+///     commands.insert_resource(Maintenance(assets.load("maintenance.cron.ron")));
+/// }
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, CronJobPlugin, CronConfigPlugin))
+///     .add_systems(Startup, load)
+///     .add_observer(|trigger: Trigger<ScheduleArrived>| {
+///         info!("{:?} is due", trigger.label);
+///     });
+/// ```
+pub struct CronConfigPlugin;
+
+impl Plugin for CronConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<CronJobConfigAsset>()
+            .register_asset_loader(CronConfigLoader)
+            .add_systems(Update, sync_config_jobs);
+    }
+}
+
+/// Named jobs and their cron or english expressions
+#[derive(Asset, TypePath, Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct CronJobConfigAsset {
+    /// the jobs, with unique names
+    #[serde(default)]
+    pub jobs: Vec<ConfigJob>,
+}
+
+/// A job of a [`CronJobConfigAsset`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ConfigJob {
+    /// name of the job, used as the label of its timer
+    pub name: String,
+    /// cron or english expression
+    pub expression: String,
+    /// whether the timer is paused
+    #[serde(default)]
+    pub paused: bool,
+    /// tags of the timer
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl CronJobConfigAsset {
+    /// Parse the RON representation of the jobs
+    pub fn from_ron(text: &str) -> Result<Self, CronConfigError> {
+        ron::from_str(text).map_err(|error| CronConfigError::Parse(error.to_string()))
+    }
+
+    /// Parse the TOML representation of the jobs
+    pub fn from_toml(text: &str) -> Result<Self, CronConfigError> {
+        toml::from_str(text).map_err(|error| CronConfigError::Parse(error.to_string()))
+    }
+}
+
+/// Marks the timer spawned for a job of a [`CronJobConfigAsset`]
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct CronConfigJob {
+    /// the asset defining the job
+    pub config: AssetId<CronJobConfigAsset>,
+    /// name of the job
+    pub name: String,
+}
+
+/// Why a [`CronJobConfigAsset`] could not be loaded
+#[derive(Debug)]
+pub enum CronConfigError {
+    /// the file could not be read
+    Io(std::io::Error),
+    /// the file is not valid RON or TOML for the jobs
+    Parse(String),
+}
+
+impl fmt::Display for CronConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CronConfigError::Io(error) => write!(f, "failed to read cron config: {error}"),
+            CronConfigError::Parse(reason) => write!(f, "invalid cron config: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for CronConfigError {}
+
+impl From<std::io::Error> for CronConfigError {
+    fn from(error: std::io::Error) -> Self {
+        CronConfigError::Io(error)
+    }
+}
+
+/// Loads `.cron.ron` and `.cron.toml` files as [`CronJobConfigAsset`]
+#[derive(Debug, Default)]
+pub struct CronConfigLoader;
+
+impl AssetLoader for CronConfigLoader {
+    type Asset = CronJobConfigAsset;
+    type Settings = ();
+    type Error = CronConfigError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let text = std::str::from_utf8(&bytes)
+            .map_err(|error| CronConfigError::Parse(error.to_string()))?;
+        match load_context.path().extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => CronJobConfigAsset::from_toml(text),
+            _ => CronJobConfigAsset::from_ron(text),
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["cron.ron", "cron.toml"]
+    }
+}
+
+fn sync_config_jobs(
+    mut events: EventReader<AssetEvent<CronJobConfigAsset>>,
+    configs: Res<Assets<CronJobConfigAsset>>,
+    mut timers: Query<(Entity, &CronConfigJob, &mut ScheduleTimer)>,
+    mut commands: Commands,
+) {
+    // an asset added and modified in the same frame is only synced once, the spawns are deferred
+    let mut changed = vec![];
+    for event in events.read() {
+        if let AssetEvent::Added { id } | AssetEvent::Modified { id } | AssetEvent::Removed { id } =
+            *event
+        {
+            if !changed.contains(&id) {
+                changed.push(id);
+            }
+        }
+    }
+    for id in changed {
+        let jobs = configs
+            .get(id)
+            .map_or(&[][..], |config| config.jobs.as_slice());
+        let mut existing = vec![];
+        for (entity, job, mut timer) in timers.iter_mut() {
+            if job.config != id {
+                continue;
+            }
+            match jobs.iter().find(|config| config.name == job.name) {
+                Some(config) => {
+                    if let Err(error) = update_job(&mut timer, config) {
+                        warn!(job = job.name.as_str(), %error, "cron config job keeps its schedule");
+                    }
+                    existing.push(job.name.clone());
+                }
+                None => {
+                    debug!(job = job.name.as_str(), "cron config job removed");
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+        }
+        for config in jobs {
+            if existing.contains(&config.name) {
+                continue;
+            }
+            match CronExpr::parse(&config.expression) {
+                Ok(expr) => {
+                    debug!(job = config.name.as_str(), "cron config job added");
+                    let mut timer = ScheduleTimer::new(expr).with_label(config.name.as_str());
+                    timer.tags.clone_from(&config.tags);
+                    timer.paused = config.paused;
+                    commands.spawn((
+                        timer,
+                        CronConfigJob {
+                            config: id,
+                            name: config.name.clone(),
+                        },
+                    ));
+                }
+                Err(error) => warn!(job = config.name.as_str(), %error, "cron config job skipped"),
+            }
+        }
+    }
+}
+
+/// Apply the definition of `config` to `timer`, re-arming it when its schedule changed
+fn update_job(timer: &mut Mut<ScheduleTimer>, config: &ConfigJob) -> Result<(), CronError> {
+    let expr = CronExpr::parse(&config.expression)?;
+    if !matches!(&timer.schedule, TimerSchedule::Cron(current) if *current == expr) {
+        timer.set_schedule(TimerSchedule::Cron(expr));
+    }
+    if timer.tags != config.tags {
+        timer.tags.clone_from(&config.tags);
+    }
+    if timer.paused != config.paused {
+        timer.paused = config.paused;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_config_formats() {
+    let ron = CronJobConfigAsset::from_ron(
+        r#"(jobs: [(name: "backup", expression: "every day at 3 am", tags: ["maintenance"])])"#,
+    )
+    .unwrap();
+    let toml = CronJobConfigAsset::from_toml(
+        "[[jobs]]\nname = \"backup\"\nexpression = \"every day at 3 am\"\ntags = [\"maintenance\"]\n",
+    )
+    .unwrap();
+    assert_eq!(ron, toml);
+    assert_eq!(ron.jobs[0].name, "backup");
+    assert!(!ron.jobs[0].paused);
+    assert!(CronJobConfigAsset::from_ron("(jobs: [(name: \"backup\")])").is_err());
+}
+
+#[test]
+fn test_config_jobs() {
+    use crate::testing::cron_app;
+
+    let mut app = cron_app();
+    app.add_plugins((
+        bevy::MinimalPlugins,
+        bevy_asset::AssetPlugin::default(),
+        CronConfigPlugin,
+    ));
+    let job = |name: &str, expression: &str| ConfigJob {
+        name: name.to_string(),
+        expression: expression.to_string(),
+        ..ConfigJob::default()
+    };
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<CronJobConfigAsset>>()
+        .add(CronJobConfigAsset {
+            jobs: vec![
+                job("backup", "every day at 3 am"),
+                job("report", "every hour"),
+            ],
+        });
+    // the asset events are sent at the end of the frame
+    app.update();
+    app.update();
+    let mut timers = app
+        .world_mut()
+        .query::<(Entity, &CronConfigJob, &ScheduleTimer)>();
+    let mut jobs = |world: &mut World| {
+        let mut jobs: Vec<_> = timers
+            .iter(world)
+            .map(|(entity, job, timer)| (entity, job.name.clone(), timer.schedule.to_string()))
+            .collect();
+        jobs.sort_by(|a, b| a.1.cmp(&b.1));
+        jobs
+    };
+    let loaded = jobs(app.world_mut());
+    assert_eq!(loaded.len(), 2);
+
+    // hot reload: report changes, backup is removed, cleanup is added, broken is skipped
+    let config = app
+        .world_mut()
+        .resource_mut::<Assets<CronJobConfigAsset>>()
+        .into_inner()
+        .get_mut(&handle)
+        .unwrap();
+    *config = CronJobConfigAsset {
+        jobs: vec![
+            job("report", "every 30 minutes"),
+            job("cleanup", "every day at 4 am"),
+            job("broken", "every blue moon"),
+        ],
+    };
+    app.update();
+    app.update();
+    let reloaded = jobs(app.world_mut());
+    assert_eq!(reloaded.len(), 2);
+    assert_eq!(reloaded[0].1, "cleanup");
+    assert_eq!(
+        (reloaded[1].0, reloaded[1].1.as_str()),
+        (loaded[1].0, "report")
+    );
+    assert_eq!(reloaded[1].2, "0 0/30 * * * ? *");
+}
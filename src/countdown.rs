@@ -0,0 +1,188 @@
+use crate::{CronClock, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::time::Duration;
+
+/// Which timer a [`UiCountdown`] follows
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CountdownTarget {
+    /// the timer on this entity
+    Entity(Entity),
+    /// the soonest timer with this label
+    Label(String),
+}
+
+/// How a [`UiCountdown`] renders the remaining time
+#[derive(Debug, Clone, Copy, Default)]
+pub enum CountdownFormat {
+    /// `04:32`, or `1:04:32` from an hour on
+    #[default]
+    Clock,
+    /// `272s`
+    Seconds,
+    /// a custom formatter
+    Custom(fn(Duration) -> String),
+}
+
+impl CountdownFormat {
+    /// Render `remaining`
+    pub fn format(&self, remaining: Duration) -> String {
+        let seconds = remaining.as_secs();
+        match self {
+            CountdownFormat::Clock if seconds >= 3600 => format!(
+                "{}:{:02}:{:02}",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            ),
+            CountdownFormat::Clock => format!("{:02}:{:02}", seconds / 60, seconds % 60),
+            CountdownFormat::Seconds => format!("{seconds}s"),
+            CountdownFormat::Custom(format) => format(remaining),
+        }
+    }
+}
+
+/// Keeps the time remaining until the next fire of a timer, for "next event in 04:32" HUD
+/// elements.
+///
+/// Updated by [`CronJobPlugin`](crate::CronJobPlugin) at whole-second resolution, so the
+/// component only changes once per second and `Changed<UiCountdown>` can drive the display.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(ScheduleTimer::new("every hour").with_label("boss"));
+///     commands.spawn(UiCountdown::for_label("boss"));
+/// }
+///
+/// fn show(countdowns: Query<&UiCountdown, Changed<UiCountdown>>) {
+///     for countdown in countdowns.iter() {
+///         info!("boss spawns in {}", countdown.text());
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct UiCountdown {
+    /// the timer to follow
+    pub target: CountdownTarget,
+    /// how [`text`](Self::text) is rendered
+    pub format: CountdownFormat,
+    remaining: Option<Duration>,
+    text: String,
+}
+
+impl UiCountdown {
+    /// Follow the timer on `entity`
+    pub fn for_entity(entity: Entity) -> Self {
+        Self::new(CountdownTarget::Entity(entity))
+    }
+
+    /// Follow the soonest timer labeled `label`
+    pub fn for_label(label: impl Into<String>) -> Self {
+        Self::new(CountdownTarget::Label(label.into()))
+    }
+
+    fn new(target: CountdownTarget) -> Self {
+        Self {
+            target,
+            format: CountdownFormat::default(),
+            remaining: None,
+            text: String::new(),
+        }
+    }
+
+    /// Choose how the remaining time is rendered
+    pub fn with_format(mut self, format: CountdownFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Time until the next fire, rounded up to whole seconds. `None` when the timer is missing,
+    /// paused, expired or not armed yet.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining
+    }
+
+    /// The remaining time rendered with [`format`](Self::format), empty when there is none
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+fn next_fire(timer: &ScheduleTimer) -> Option<DateTime<chrono::Local>> {
+    if timer.is_paused() {
+        return None;
+    }
+    timer.local_schedule
+}
+
+pub(crate) fn update_countdowns(
+    clock: Res<CronClock>,
+    timers: Query<(Entity, &ScheduleTimer)>,
+    mut countdowns: Query<&mut UiCountdown>,
+) {
+    let now = clock.now();
+    for mut countdown in countdowns.iter_mut() {
+        let next = match &countdown.target {
+            CountdownTarget::Entity(entity) => timers
+                .get(*entity)
+                .ok()
+                .and_then(|(_, timer)| next_fire(timer)),
+            CountdownTarget::Label(label) => timers
+                .iter()
+                .filter(|(_, timer)| timer.label() == Some(label.as_str()))
+                .filter_map(|(_, timer)| next_fire(timer))
+                .min(),
+        };
+        let remaining = next.map(|next| {
+            let millis = (next - now).num_milliseconds().max(0) as u64;
+            Duration::from_secs(millis.div_ceil(1000))
+        });
+        if countdown.remaining != remaining {
+            let text =
+                remaining.map_or_else(String::new, |remaining| countdown.format.format(remaining));
+            countdown.remaining = remaining;
+            countdown.text = text;
+        }
+    }
+}
+
+#[test]
+fn test_countdown() {
+    use crate::testing::{cron_app, start};
+
+    assert_eq!(
+        CountdownFormat::Clock.format(Duration::from_secs(272)),
+        "04:32"
+    );
+    assert_eq!(
+        CountdownFormat::Clock.format(Duration::from_secs(3872)),
+        "1:04:32"
+    );
+    assert_eq!(
+        CountdownFormat::Seconds.format(Duration::from_secs(9)),
+        "9s"
+    );
+
+    let mut app = cron_app();
+    let mut boss = ScheduleTimer::new("every hour").with_label("boss");
+    boss.local_schedule = Some(start() + chrono::Duration::seconds(272));
+    let timer = app.world_mut().spawn(boss).id();
+    let by_label = app.world_mut().spawn(UiCountdown::for_label("boss")).id();
+    let by_entity = app
+        .world_mut()
+        .spawn(UiCountdown::for_entity(timer).with_format(CountdownFormat::Seconds))
+        .id();
+    let missing = app.world_mut().spawn(UiCountdown::for_label("nobody")).id();
+    app.update();
+
+    let countdown = app.world().get::<UiCountdown>(by_label).unwrap();
+    assert_eq!(countdown.text(), "04:32");
+    let countdown = app.world().get::<UiCountdown>(by_entity).unwrap();
+    assert!(countdown.text().ends_with('s'));
+    let countdown = app.world().get::<UiCountdown>(missing).unwrap();
+    assert_eq!(countdown.remaining(), None);
+    assert_eq!(countdown.text(), "");
+}
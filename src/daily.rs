@@ -0,0 +1,181 @@
+use crate::{CronClock, CronExpr, CronTimezone, IntoCronExpression};
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, Datelike, NaiveDate};
+
+/// A seed shared by everyone playing on the same day, the backbone of daily challenges.
+///
+/// With this resource present, [`CronJobPlugin`](crate::CronJobPlugin) rotates the seed at every
+/// occurrence of its schedule, midnight UTC by default, and triggers [`DailySeedRotated`]. The
+/// seed is derived from the date of the last rotation, so every machine and every restart
+/// agrees on it without saving anything.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn new_challenge(trigger: Trigger<DailySeedRotated>) {
+///     let mut rng = fastrand::Rng::with_seed(trigger.event().seed);
+///     info!("today's dungeon has {} rooms", rng.u32(5..12));
+/// }
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     // reset at 04:00 UTC, salted so other games do not share the seeds
+///     .insert_resource(DailySeed::default().with_schedule("0 0 4 * * ? *").with_salt(7))
+///     .add_observer(new_challenge);
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct DailySeed {
+    schedule: CronExpr,
+    timezone: CronTimezone,
+    salt: u64,
+    date: Option<NaiveDate>,
+    seed: u64,
+    next_rotation: Option<DateTime<chrono::Local>>,
+}
+
+impl Default for DailySeed {
+    fn default() -> Self {
+        Self {
+            schedule: CronExpr::daily_at(0, 0).expect("midnight is valid"),
+            timezone: CronTimezone::Utc,
+            salt: 0,
+            date: None,
+            seed: 0,
+            next_rotation: None,
+        }
+    }
+}
+
+impl DailySeed {
+    /// Rotate at the occurrences of `expression` instead of midnight
+    pub fn with_schedule(mut self, expression: impl IntoCronExpression) -> Self {
+        self.schedule = expression
+            .into_cron_expr()
+            .expect("Failed to parse cron expression");
+        self
+    }
+
+    /// Evaluate the schedule and dates in `timezone` instead of UTC
+    pub fn with_timezone(mut self, timezone: impl Into<CronTimezone>) -> Self {
+        self.timezone = timezone.into();
+        self
+    }
+
+    /// Mix `salt` into every seed
+    pub fn with_salt(mut self, salt: u64) -> Self {
+        self.salt = salt;
+        self
+    }
+
+    /// The seed of the current day
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The date of the last rotation, `None` before the first update
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.date
+    }
+
+    /// The seed of any `date`, e.g. to replay yesterday's challenge
+    pub fn seed_of(&self, date: NaiveDate) -> u64 {
+        // splitmix64 of the day number, so neighbouring days get unrelated seeds
+        let mut z = (date.num_days_from_ce() as u64 ^ self.salt.rotate_left(32))
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A random number generator seeded with today's seed
+    pub fn rng(&self) -> fastrand::Rng {
+        fastrand::Rng::with_seed(self.seed)
+    }
+
+    /// The latest rotation at or before `now`, searched over the past week
+    fn last_rotation(&self, now: DateTime<chrono::Local>) -> Option<DateTime<chrono::Local>> {
+        let mut at = now - chrono::Duration::days(8);
+        let mut last = None;
+        // bounded, so sub-daily schedules do not search a week of occurrences
+        for _ in 0..64 {
+            match self.timezone.next_after(&self.schedule, &at) {
+                Some(next) if next <= now => {
+                    last = Some(next);
+                    at = next;
+                }
+                _ => break,
+            }
+        }
+        last
+    }
+}
+
+/// Triggered when the [`DailySeed`] rotates, including its first rotation after startup
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct DailySeedRotated {
+    /// the date of the new seed
+    pub date: NaiveDate,
+    /// the new seed
+    pub seed: u64,
+    /// the seed before the rotation
+    pub previous: Option<u64>,
+}
+
+pub(crate) fn rotate_daily_seed(
+    clock: Res<CronClock>,
+    mut daily: ResMut<DailySeed>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    if daily.date.is_some() && daily.next_rotation.is_some_and(|next| now < next) {
+        return;
+    }
+    let rotation = daily.last_rotation(now).unwrap_or(now);
+    let date = daily.timezone.date_of(&rotation);
+    daily.next_rotation = daily.timezone.next_after(&daily.schedule, &now);
+    if daily.date == Some(date) {
+        return;
+    }
+    let previous = daily.date.map(|_| daily.seed);
+    daily.date = Some(date);
+    daily.seed = daily.seed_of(date);
+    commands.trigger(DailySeedRotated {
+        date,
+        seed: daily.seed,
+        previous,
+    });
+}
+
+#[test]
+fn test_daily_seed() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+    use std::time::Duration;
+
+    // 2000-01-01 00:00 UTC, hour by hour
+    let mut app = cron_app();
+    app.insert_resource(DailySeed::default().with_schedule("0 0 4 * * ? *"))
+        .record::<DailySeedRotated>();
+    app.update();
+    for _ in 0..29 {
+        app.advance_cron_time(Duration::from_secs(3600));
+    }
+
+    let rotations = app
+        .triggered::<DailySeedRotated>()
+        .iter()
+        .map(|(_, rotation)| rotation)
+        .collect::<Vec<_>>();
+    let day = |day| NaiveDate::from_ymd_opt(2000, 1, day).unwrap();
+    // before the 04:00 reset, the day still belongs to the previous date
+    assert_eq!(rotations[0].date, day(1).pred_opt().unwrap());
+    assert_eq!(rotations[0].previous, None);
+    assert_eq!(rotations[1].date, day(1));
+    assert_eq!(rotations[1].previous, Some(rotations[0].seed));
+    assert_eq!(rotations[2].date, day(2));
+    assert_eq!(rotations.len(), 3);
+    let daily = app.world().resource::<DailySeed>();
+    assert_eq!(daily.seed(), daily.seed_of(day(2)));
+    assert_ne!(daily.seed_of(day(1)), daily.seed_of(day(2)));
+}
@@ -0,0 +1,398 @@
+use crate::{CronExpr, MonthWeek};
+use chrono::{NaiveTime, Weekday};
+use std::fmt;
+
+/// A value inserted into a [`ScheduleDescription`], kept typed so translations can render
+/// weekdays, months and times in their own language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DescriptionArg {
+    /// a plain count, e.g. the `5` of "every 5 minutes"
+    Number(u32),
+    /// a time of day
+    Time(NaiveTime),
+    /// a day of the month (1-31)
+    Day(u32),
+    /// a month of the year (1-12)
+    Month(u32),
+    /// one or more days of the week
+    Weekdays(Vec<Weekday>),
+    /// a week of the month
+    Week(MonthWeek),
+    /// text passed through untranslated, e.g. an expression without a description
+    Text(String),
+}
+
+impl fmt::Display for DescriptionArg {
+    /// English rendering
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DescriptionArg::Number(n) => write!(f, "{n}"),
+            DescriptionArg::Time(time) if time.format("%S").to_string() == "00" => {
+                write!(f, "{}", time.format("%H:%M"))
+            }
+            DescriptionArg::Time(time) => write!(f, "{}", time.format("%H:%M:%S")),
+            DescriptionArg::Day(day) => {
+                let suffix = match (day % 10, day % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                write!(f, "{day}{suffix}")
+            }
+            DescriptionArg::Month(month) => f.write_str(
+                [
+                    "January",
+                    "February",
+                    "March",
+                    "April",
+                    "May",
+                    "June",
+                    "July",
+                    "August",
+                    "September",
+                    "October",
+                    "November",
+                    "December",
+                ]
+                .get(*month as usize - 1)
+                .copied()
+                .unwrap_or("?"),
+            ),
+            DescriptionArg::Weekdays(days) => {
+                let names = days
+                    .iter()
+                    .map(|day| match day {
+                        Weekday::Mon => "Monday",
+                        Weekday::Tue => "Tuesday",
+                        Weekday::Wed => "Wednesday",
+                        Weekday::Thu => "Thursday",
+                        Weekday::Fri => "Friday",
+                        Weekday::Sat => "Saturday",
+                        Weekday::Sun => "Sunday",
+                    })
+                    .collect::<Vec<_>>();
+                match names.split_last() {
+                    Some((last, [])) => f.write_str(last),
+                    Some((last, rest)) => write!(f, "{} and {last}", rest.join(", ")),
+                    None => Ok(()),
+                }
+            }
+            DescriptionArg::Week(week) => f.write_str(match week {
+                MonthWeek::First => "first",
+                MonthWeek::Second => "second",
+                MonthWeek::Third => "third",
+                MonthWeek::Fourth => "fourth",
+                MonthWeek::Last => "last",
+            }),
+            DescriptionArg::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+/// A language-neutral description of a schedule: a fluent-style message key with named
+/// arguments, built by [`CronExpr::description`].
+///
+/// | key | arguments |
+/// |-----|-----------|
+/// | `cron-every-second` | |
+/// | `cron-every-n-seconds` | `n` |
+/// | `cron-every-minute` | |
+/// | `cron-every-n-minutes` | `n` |
+/// | `cron-hourly` | `minute` |
+/// | `cron-every-n-hours` | `n` |
+/// | `cron-daily` | `time` |
+/// | `cron-weekly` | `weekdays`, `time` |
+/// | `cron-monthly` | `day`, `time` |
+/// | `cron-monthly-weekday` | `week`, `weekdays`, `time` |
+/// | `cron-yearly` | `month`, `day`, `time` |
+/// | `cron-expression` | `expression` |
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleDescription {
+    /// the message key
+    pub key: &'static str,
+    /// the named arguments of the message
+    pub args: Vec<(&'static str, DescriptionArg)>,
+}
+
+impl ScheduleDescription {
+    fn new(key: &'static str) -> Self {
+        Self { key, args: vec![] }
+    }
+
+    fn arg(mut self, name: &'static str, value: DescriptionArg) -> Self {
+        self.args.push((name, value));
+        self
+    }
+
+    /// The argument called `name`
+    pub fn get(&self, name: &str) -> Option<&DescriptionArg> {
+        self.args
+            .iter()
+            .find(|(arg, _)| *arg == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Render with `locale`
+    pub fn render(&self, locale: &impl DescriptionLocale) -> String {
+        locale.message(self.key, &self.args)
+    }
+}
+
+impl fmt::Display for ScheduleDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(&English))
+    }
+}
+
+/// Turns a [`ScheduleDescription`] into text of a language.
+///
+/// Implemented for closures taking the key and arguments, so a game can forward to its own
+/// translation system, falling back to [`English`] for keys it does not know.
+pub trait DescriptionLocale {
+    /// Render the message `key` with `args`
+    fn message(&self, key: &str, args: &[(&'static str, DescriptionArg)]) -> String;
+}
+
+impl<F: Fn(&str, &[(&'static str, DescriptionArg)]) -> String> DescriptionLocale for F {
+    fn message(&self, key: &str, args: &[(&'static str, DescriptionArg)]) -> String {
+        self(key, args)
+    }
+}
+
+/// The built-in english descriptions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl DescriptionLocale for English {
+    fn message(&self, key: &str, args: &[(&'static str, DescriptionArg)]) -> String {
+        let arg = |name: &str| {
+            args.iter()
+                .find(|(arg, _)| *arg == name)
+                .map(|(_, value)| value.to_string())
+                .unwrap_or_default()
+        };
+        match key {
+            "cron-every-second" => "every second".to_string(),
+            "cron-every-n-seconds" => format!("every {} seconds", arg("n")),
+            "cron-every-minute" => "every minute".to_string(),
+            "cron-every-n-minutes" => format!("every {} minutes", arg("n")),
+            "cron-hourly" if arg("minute") == "0" => "every hour".to_string(),
+            "cron-hourly" => format!("every hour at {} minutes past", arg("minute")),
+            "cron-every-n-hours" => format!("every {} hours", arg("n")),
+            "cron-daily" => format!("every day at {}", arg("time")),
+            "cron-weekly" => format!("every {} at {}", arg("weekdays"), arg("time")),
+            "cron-monthly" => format!("on the {} of every month at {}", arg("day"), arg("time")),
+            "cron-monthly-weekday" => format!(
+                "on the {} {} of every month at {}",
+                arg("week"),
+                arg("weekdays"),
+                arg("time")
+            ),
+            "cron-yearly" => format!(
+                "every year on {} {} at {}",
+                arg("month"),
+                arg("day"),
+                arg("time")
+            ),
+            _ => format!("on schedule `{}`", arg("expression")),
+        }
+    }
+}
+
+impl CronExpr {
+    /// Describe the schedule in a language-neutral form, to be rendered with a
+    /// [`DescriptionLocale`]. Expressions without a simpler description use `cron-expression`.
+    pub fn description(&self) -> ScheduleDescription {
+        describe_fields(self.schedule().source(), self.in_last_week_of_month()).unwrap_or_else(
+            || {
+                ScheduleDescription::new("cron-expression").arg(
+                    "expression",
+                    DescriptionArg::Text(self.as_str().to_string()),
+                )
+            },
+        )
+    }
+
+    /// Describe the schedule in english
+    ///
+    /// ```rust
+    /// use bevy_cronjob::CronExpr;
+    ///
+    /// let expr = CronExpr::parse("2nd tuesday of the month at 19:00").unwrap();
+    /// assert_eq!(expr.describe(), "on the second Tuesday of every month at 19:00");
+    /// ```
+    pub fn describe(&self) -> String {
+        self.description().to_string()
+    }
+
+    /// Describe the schedule in the language of `locale`
+    ///
+    /// ```rust
+    /// use bevy_cronjob::{CronExpr, DescriptionArg, DescriptionLocale, English};
+    ///
+    /// let german = |key: &str, args: &[(&'static str, DescriptionArg)]| match key {
+    ///     "cron-daily" => format!("täglich um {}", args[0].1),
+    ///     _ => English.message(key, args),
+    /// };
+    /// let expr = CronExpr::daily_at(18, 30).unwrap();
+    /// assert_eq!(expr.describe_in(&german), "täglich um 18:30");
+    /// ```
+    pub fn describe_in(&self, locale: &impl DescriptionLocale) -> String {
+        self.description().render(locale)
+    }
+}
+
+fn describe_fields(expression: &str, last_week: bool) -> Option<ScheduleDescription> {
+    let mut fields = expression.split_whitespace().collect::<Vec<_>>();
+    if fields.len() == 6 {
+        fields.push("*");
+    }
+    let [second, minute, hour, day, month, weekday, year] = fields[..] else {
+        return None;
+    };
+    if year != "*" {
+        return None;
+    }
+    let any = |field: &str| matches!(field, "*" | "?");
+    let number = |field: &str| field.parse::<u32>().ok();
+    let step = |field: &str| {
+        field
+            .strip_prefix("*/")
+            .or_else(|| field.strip_prefix("0/"))
+            .and_then(|n| n.parse::<u32>().ok())
+    };
+    let any_day = any(day) && month == "*" && any(weekday);
+    let description = match (second, minute, hour) {
+        ("*", "*", "*") if any_day => ScheduleDescription::new("cron-every-second"),
+        (second, "*", "*") if any_day && step(second).is_some() => match step(second)? {
+            1 => ScheduleDescription::new("cron-every-second"),
+            n => {
+                ScheduleDescription::new("cron-every-n-seconds").arg("n", DescriptionArg::Number(n))
+            }
+        },
+        (second, "*", "*") if any_day && number(second).is_some() => {
+            ScheduleDescription::new("cron-every-minute")
+        }
+        (second, minute, "*") if any_day && number(second).is_some() && step(minute).is_some() => {
+            match step(minute)? {
+                1 => ScheduleDescription::new("cron-every-minute"),
+                n => ScheduleDescription::new("cron-every-n-minutes")
+                    .arg("n", DescriptionArg::Number(n)),
+            }
+        }
+        (second, minute, hour) if any_day && number(second).is_some() && step(hour).is_some() => {
+            number(minute)?;
+            match step(hour)? {
+                1 => ScheduleDescription::new("cron-hourly")
+                    .arg("minute", DescriptionArg::Number(number(minute)?)),
+                n => ScheduleDescription::new("cron-every-n-hours")
+                    .arg("n", DescriptionArg::Number(n)),
+            }
+        }
+        (second, minute, "*") if any_day => {
+            number(second)?;
+            ScheduleDescription::new("cron-hourly")
+                .arg("minute", DescriptionArg::Number(number(minute)?))
+        }
+        (second, minute, hour) => {
+            let time = NaiveTime::from_hms_opt(number(hour)?, number(minute)?, number(second)?)?;
+            describe_days(day, month, weekday, last_week)?.arg("time", DescriptionArg::Time(time))
+        }
+    };
+    Some(description)
+}
+
+fn describe_days(
+    day: &str,
+    month: &str,
+    weekday: &str,
+    last_week: bool,
+) -> Option<ScheduleDescription> {
+    let any = |field: &str| matches!(field, "*" | "?");
+    let weekdays = || {
+        weekday
+            .split(',')
+            .map(|day| match day.parse::<u32>() {
+                Ok(n @ 1..=7) => Some(Weekday::try_from(((n + 5) % 7) as u8).ok()?),
+                Ok(_) => None,
+                Err(_) => day.parse::<Weekday>().ok(),
+            })
+            .collect::<Option<Vec<_>>>()
+    };
+    let description = match (any(day), month == "*", any(weekday)) {
+        (true, true, true) => ScheduleDescription::new("cron-daily"),
+        (true, true, false) => ScheduleDescription::new("cron-weekly")
+            .arg("weekdays", DescriptionArg::Weekdays(weekdays()?)),
+        (false, true, true) => ScheduleDescription::new("cron-monthly")
+            .arg("day", DescriptionArg::Day(day.parse().ok()?)),
+        (false, false, true) => ScheduleDescription::new("cron-yearly")
+            .arg("month", DescriptionArg::Month(month.parse().ok()?))
+            .arg("day", DescriptionArg::Day(day.parse().ok()?)),
+        (false, true, false) => {
+            let week = if last_week {
+                MonthWeek::Last
+            } else {
+                [
+                    MonthWeek::First,
+                    MonthWeek::Second,
+                    MonthWeek::Third,
+                    MonthWeek::Fourth,
+                ]
+                .into_iter()
+                .find(|week| week.days() == day)?
+            };
+            let weekdays = weekdays()?;
+            if weekdays.len() != 1 {
+                return None;
+            }
+            ScheduleDescription::new("cron-monthly-weekday")
+                .arg("week", DescriptionArg::Week(week))
+                .arg("weekdays", DescriptionArg::Weekdays(weekdays))
+        }
+        (_, false, _) => return None,
+    };
+    Some(description)
+}
+
+#[test]
+fn test_describe() {
+    let describe = |expression: &str| CronExpr::parse(expression).unwrap().describe();
+    assert_eq!(describe("every second"), "every second");
+    assert_eq!(describe("every 5 seconds"), "every 5 seconds");
+    assert_eq!(describe("every 1 minute"), "every minute");
+    assert_eq!(describe("0 0/15 * * * ? *"), "every 15 minutes");
+    assert_eq!(describe("every hour"), "every hour");
+    assert_eq!(describe("0 30 * * * ? *"), "every hour at 30 minutes past");
+    assert_eq!(describe("0 0 0/6 * * ? *"), "every 6 hours");
+    assert_eq!(describe("0 30 18 * * ? *"), "every day at 18:30");
+    assert_eq!(
+        describe("0 0 9 ? * MON,WED,FRI *"),
+        "every Monday, Wednesday and Friday at 09:00"
+    );
+    assert_eq!(
+        describe("0 0 0 15 * ? *"),
+        "on the 15th of every month at 00:00"
+    );
+    assert_eq!(
+        describe("0 0 9 1 3 ? *"),
+        "every year on March 1st at 09:00"
+    );
+    assert_eq!(
+        describe("0 0 18 * * FRIL"),
+        "on the last Friday of every month at 18:00"
+    );
+    assert_eq!(
+        describe("0 0 9 1 3 ? 2030"),
+        "on schedule `0 0 9 1 3 ? 2030`"
+    );
+
+    let description = CronExpr::daily_at(7, 0).unwrap().description();
+    assert_eq!(description.key, "cron-daily");
+    let french = |key: &str, args: &[(&'static str, DescriptionArg)]| match key {
+        "cron-daily" => format!("tous les jours à {}", args[0].1),
+        _ => English.message(key, args),
+    };
+    assert_eq!(description.render(&french), "tous les jours à 07:00");
+}
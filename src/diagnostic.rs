@@ -0,0 +1,98 @@
+use crate::timer::check_schedule_timer;
+use crate::{AssetGcStats, CronClock, CronFrameStats, ScheduleTimer};
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+/// Publishes cron health as Bevy [`Diagnostic`]s, so it can be shown next to FPS in
+/// `LogDiagnosticsPlugin`, `bevy_dev_tools` overlays or `iyes_perf_ui` diagnostic entries.
+///
+/// Requires [`CronJobPlugin`](crate::CronJobPlugin) and the `dev` feature.
+pub struct CronDiagnosticsPlugin {
+    /// timers whose next fire is within this window are counted as due soon
+    pub due_soon_window: Duration,
+}
+
+impl Default for CronDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            due_soon_window: Duration::from_secs(10),
+        }
+    }
+}
+
+impl CronDiagnosticsPlugin {
+    /// time spent in the timer check system, in milliseconds
+    pub const CHECK_TIME: DiagnosticPath = DiagnosticPath::const_new("cron/check_time");
+    /// number of timers evaluated this frame
+    pub const TIMERS: DiagnosticPath = DiagnosticPath::const_new("cron/timers");
+    /// number of fires dispatched this frame
+    pub const FIRES: DiagnosticPath = DiagnosticPath::const_new("cron/fires");
+    /// number of timers due within [`CronDiagnosticsPlugin::due_soon_window`]
+    pub const DUE_SOON: DiagnosticPath = DiagnosticPath::const_new("cron/due_soon");
+    /// worst delay between the scheduled and the actual fire this frame, in milliseconds
+    pub const WORST_DRIFT: DiagnosticPath = DiagnosticPath::const_new("cron/worst_drift");
+    /// duration of the latest [`AssetGcPlugin`](crate::AssetGcPlugin) cleanup, in milliseconds
+    pub const ASSET_GC_TIME: DiagnosticPath = DiagnosticPath::const_new("cron/asset_gc_time");
+}
+
+#[derive(Resource)]
+struct DueSoonWindow(chrono::Duration);
+
+impl Plugin for CronDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let window =
+            chrono::Duration::from_std(self.due_soon_window).unwrap_or(chrono::TimeDelta::MAX);
+        app.insert_resource(DueSoonWindow(window))
+            .register_diagnostic(Diagnostic::new(Self::CHECK_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::TIMERS))
+            .register_diagnostic(Diagnostic::new(Self::FIRES))
+            .register_diagnostic(Diagnostic::new(Self::DUE_SOON))
+            .register_diagnostic(Diagnostic::new(Self::WORST_DRIFT).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::ASSET_GC_TIME).with_suffix("ms"))
+            .add_systems(Update, diagnostic_system.after(check_schedule_timer))
+            .add_systems(
+                Update,
+                asset_gc_diagnostic_system.after(check_schedule_timer),
+            );
+    }
+}
+
+fn diagnostic_system(
+    mut diagnostics: Diagnostics,
+    stats: Res<CronFrameStats>,
+    window: Res<DueSoonWindow>,
+    clock: Res<CronClock>,
+    query: Query<&ScheduleTimer>,
+) {
+    diagnostics.add_measurement(&CronDiagnosticsPlugin::CHECK_TIME, || {
+        stats.check_duration.as_secs_f64() * 1000.0
+    });
+    diagnostics.add_measurement(&CronDiagnosticsPlugin::TIMERS, || {
+        stats.timers_total() as f64
+    });
+    diagnostics.add_measurement(&CronDiagnosticsPlugin::FIRES, || {
+        stats.fires_dispatched as f64
+    });
+    diagnostics.add_measurement(&CronDiagnosticsPlugin::DUE_SOON, || {
+        let deadline = clock.now() + window.0;
+        query
+            .iter()
+            .filter(|timer| timer.local_schedule.is_some_and(|next| next <= deadline))
+            .count() as f64
+    });
+    diagnostics.add_measurement(&CronDiagnosticsPlugin::WORST_DRIFT, || {
+        stats.worst_drift.as_secs_f64() * 1000.0
+    });
+}
+
+fn asset_gc_diagnostic_system(mut diagnostics: Diagnostics, stats: Option<Res<AssetGcStats>>) {
+    // only measured in frames a cleanup ran
+    let Some(stats) = stats.filter(|stats| stats.is_changed() && stats.runs > 0) else {
+        return;
+    };
+    diagnostics.add_measurement(&CronDiagnosticsPlugin::ASSET_GC_TIME, || {
+        stats.last_duration.as_secs_f64() * 1000.0
+    });
+}
@@ -0,0 +1,106 @@
+use crate::ScheduleTimer;
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::warn;
+use std::collections::HashMap;
+
+/// Timers on several entities sharing the same schedule and label, see
+/// [`find_duplicate_timers`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateSchedule {
+    /// canonical form of the shared schedule
+    pub expression: String,
+    /// the shared label
+    pub label: Option<String>,
+    /// every entity carrying such a timer, in ascending order
+    pub entities: Vec<Entity>,
+}
+
+/// Find [`ScheduleTimer`]s that share their canonical expression, label and timezone with a
+/// timer on another entity.
+///
+/// English and cron spellings of the same schedule are the same canonical expression. A
+/// duplicate usually means a timer was spawned twice by accident and every occurrence fires
+/// twice.
+pub fn find_duplicate_timers(world: &mut World) -> Vec<DuplicateSchedule> {
+    let mut query = world.query::<(Entity, &ScheduleTimer)>();
+    duplicates(query.iter(world))
+}
+
+/// Logs a warning for every [`DuplicateSchedule`], meant to run once after the timers were
+/// spawned.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .add_systems(PostStartup, warn_duplicate_timers);
+/// ```
+pub fn warn_duplicate_timers(query: Query<(Entity, &ScheduleTimer)>) {
+    for duplicate in duplicates(query.iter()) {
+        warn!(
+            label = duplicate.label,
+            entities = ?duplicate.entities,
+            "cron schedule `{}` is spawned {} times, every occurrence fires once per copy",
+            duplicate.expression,
+            duplicate.entities.len()
+        );
+    }
+}
+
+fn duplicates<'a>(
+    timers: impl Iterator<Item = (Entity, &'a ScheduleTimer)>,
+) -> Vec<DuplicateSchedule> {
+    let mut groups = HashMap::<_, Vec<Entity>>::new();
+    for (entity, timer) in timers {
+        let key = (
+            timer.schedule.to_string(),
+            timer.label.clone(),
+            timer.timezone,
+        );
+        groups.entry(key).or_default().push(entity);
+    }
+    let mut duplicates = groups
+        .into_iter()
+        .filter(|(_, entities)| entities.len() > 1)
+        .map(|((expression, label, _), mut entities)| {
+            entities.sort();
+            DuplicateSchedule {
+                expression,
+                label,
+                entities,
+            }
+        })
+        .collect::<Vec<_>>();
+    duplicates.sort_by_key(|duplicate| duplicate.entities[0]);
+    duplicates
+}
+
+#[test]
+fn test_find_duplicate_timers() {
+    let mut world = World::new();
+    let first = world
+        .spawn(ScheduleTimer::new("every hour").with_label("save"))
+        .id();
+    let second = world
+        .spawn(ScheduleTimer::new("0 0 * * * ? *").with_label("save"))
+        .id();
+    world.spawn(ScheduleTimer::new("every hour").with_label("backup"));
+    world.spawn(
+        ScheduleTimer::new("every hour")
+            .with_label("save")
+            .with_timezone(crate::CronTimezone::Utc),
+    );
+    world.spawn(ScheduleTimer::new("every 5 minutes"));
+
+    let duplicates = find_duplicate_timers(&mut world);
+    assert_eq!(
+        duplicates,
+        vec![DuplicateSchedule {
+            expression: "0 0 * * * ? *".to_string(),
+            label: Some("save".to_string()),
+            entities: vec![first, second],
+        }]
+    );
+}
@@ -0,0 +1,369 @@
+use crate::{CronError, CronExpr, IntoCronExpression, ScheduleTimer, TimerSchedule};
+use bevy_ecs::prelude::*;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_utils::tracing::warn;
+
+/// An editable expression driving the [`ScheduleTimer`] on the same entity.
+///
+/// Reflection-based editors such as an inspector can change `expression` live. Every change is
+/// parsed before the timers are checked: a valid expression replaces the schedule of the timer
+/// (spawning one when missing) and re-arms it, an invalid one leaves the timer untouched, sets
+/// `status` to [`ExpressionStatus::Invalid`] and triggers [`ScheduleExpressionInvalid`].
+///
+/// When an invalid expression leaves the entity without a timer, one is spawned with the
+/// [fallback](ScheduleExpression::with_fallback) expression, if set.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(ScheduleExpression::new("every 5 minutes"));
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Default, PartialEq, Eq)]
+#[reflect(Component, Debug, Default)]
+pub struct ScheduleExpression {
+    /// the cron or english expression
+    pub expression: String,
+    /// result of parsing `expression`
+    pub status: ExpressionStatus,
+    /// spawns the timer when `expression` is invalid and there is no timer yet
+    pub fallback: Option<String>,
+}
+
+impl ScheduleExpression {
+    /// Drive the timer on the entity with `expression`
+    pub fn new(expression: impl Into<String>) -> Self {
+        Self {
+            expression: expression.into(),
+            status: ExpressionStatus::default(),
+            fallback: None,
+        }
+    }
+
+    /// The expression of `timer`, when it runs on a cron schedule, to edit it without re-arming
+    /// the timer
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// // expose the timers spawned from code to the inspector
+    /// fn make_editable(
+    ///     query: Query<(Entity, &ScheduleTimer), Added<ScheduleTimer>>,
+    ///     mut commands: Commands,
+    /// ) {
+    ///     for (entity, timer) in query.iter() {
+    ///         if let Some(edit) = ScheduleExpression::from_timer(timer) {
+    ///             commands.entity(entity).insert(edit);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn from_timer(timer: &ScheduleTimer) -> Option<Self> {
+        Some(Self {
+            expression: timer.expression()?.to_string(),
+            status: ExpressionStatus::Valid,
+            fallback: None,
+        })
+    }
+
+    /// Spawn the timer with `fallback` when the expression is invalid and there is no timer yet
+    pub fn with_fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+}
+
+/// Whether the expression of a [`ScheduleExpression`] could be parsed
+#[derive(Reflect, Debug, Clone, Default, PartialEq, Eq)]
+pub enum ExpressionStatus {
+    /// not parsed yet
+    #[default]
+    Pending,
+    /// applied to the timer
+    Valid,
+    /// rejected, with the reason
+    Invalid(String),
+}
+
+/// Triggered on an entity when an edit of its [`ScheduleExpression`] was rejected
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleExpressionInvalid {
+    /// the rejected expression
+    pub expression: String,
+    /// why it was rejected
+    pub error: CronError,
+}
+
+/// Triggered on a timer entity spawned with a fallback schedule, see [`ScheduleTimer::new_or`]
+/// and [`ScheduleExpression::with_fallback`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleFallbackUsed {
+    /// label of the timer
+    pub label: Option<String>,
+    /// why the primary expression was rejected
+    pub error: CronError,
+    /// the schedule the timer runs on instead
+    pub fallback: String,
+}
+
+/// Triggered on a timer entity at the first check after its schedule was replaced, e.g. with
+/// [`ScheduleTimer::set_expression`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleChanged {
+    /// label of the timer
+    pub label: Option<String>,
+    /// the replaced schedule
+    pub previous: String,
+    /// the new schedule
+    pub schedule: String,
+}
+
+impl ScheduleTimer {
+    /// Run on the cron or english `expression` from now on, see
+    /// [`set_schedule`](Self::set_schedule). An invalid expression leaves the timer untouched.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// let mut timer = ScheduleTimer::new("every hour");
+    /// timer.set_expression("every 30 minutes").unwrap();
+    /// assert!(timer.set_expression("every blue moon").is_err());
+    /// assert_eq!(timer.expression(), Some("every 30 minutes"));
+    /// ```
+    pub fn set_expression(&mut self, expression: impl IntoCronExpression) -> Result<(), CronError> {
+        self.set_schedule(TimerSchedule::Cron(expression.into_cron_expr()?));
+        Ok(())
+    }
+
+    /// Replace the schedule, keeping the settings, runs and last run of the timer.
+    ///
+    /// The pending occurrence is dropped and the timer is armed again by the next check, which
+    /// triggers [`ScheduleChanged`]. An expired timer becomes active again.
+    pub fn set_schedule(&mut self, schedule: TimerSchedule) {
+        let previous = std::mem::replace(&mut self.schedule, schedule);
+        self.changed_from
+            .get_or_insert_with(|| previous.to_string());
+        self.local_schedule = None;
+        self.expired = false;
+        self.fallback_error = None;
+        self.frame_timer = None;
+        self.steps = 0;
+        self.pending_ticks = 0;
+        self.caught_up = 0;
+        self.skip_missed = false;
+        self.jitter_offset = chrono::Duration::zero();
+    }
+}
+
+pub(crate) fn report_schedule_fallbacks(
+    query: Query<(Entity, &ScheduleTimer), Added<ScheduleTimer>>,
+    mut commands: Commands,
+) {
+    for (entity, timer) in query.iter() {
+        let Some(error) = timer.fallback_error() else {
+            continue;
+        };
+        let fallback = timer.schedule.to_string();
+        warn!(label = timer.label(), ?entity, %error, %fallback, "cron job uses its fallback schedule");
+        commands.trigger_targets(
+            ScheduleFallbackUsed {
+                label: timer.label.clone(),
+                error: error.clone(),
+                fallback,
+            },
+            entity,
+        );
+    }
+}
+
+pub(crate) fn apply_expression_edits(
+    mut query: Query<
+        (Entity, &mut ScheduleExpression, Option<&mut ScheduleTimer>),
+        Changed<ScheduleExpression>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, mut edit, timer) in query.iter_mut() {
+        // writing the status must not count as another edit
+        let edit = edit.bypass_change_detection();
+        match CronExpr::parse(&edit.expression) {
+            Ok(expr) => {
+                edit.status = ExpressionStatus::Valid;
+                let unchanged = timer.as_deref().is_some_and(|timer| {
+                    matches!(&timer.schedule, TimerSchedule::Cron(current) if *current == expr)
+                });
+                match timer {
+                    // the same schedule written another way, the timer stays armed
+                    Some(_) if unchanged => {}
+                    Some(mut timer) => timer.set_schedule(TimerSchedule::Cron(expr)),
+                    None => {
+                        commands.entity(entity).insert(ScheduleTimer::new(expr));
+                    }
+                }
+            }
+            Err(error) => {
+                edit.status = ExpressionStatus::Invalid(error.to_string());
+                let fallback = edit.fallback.as_deref().map(CronExpr::parse);
+                if let (None, Some(Ok(fallback))) = (&timer, fallback) {
+                    let mut timer = ScheduleTimer::new(fallback);
+                    timer.fallback_error = Some(error.clone());
+                    commands.entity(entity).insert(timer);
+                }
+                commands.trigger_targets(
+                    ScheduleExpressionInvalid {
+                        expression: edit.expression.clone(),
+                        error,
+                    },
+                    entity,
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_expression_edit() {
+    use crate::testing::{cron_app, TestAppExt};
+
+    let mut app = cron_app();
+    app.record::<ScheduleExpressionInvalid>();
+    let entity = app
+        .world_mut()
+        .spawn(ScheduleExpression::new("every hour"))
+        .id();
+    app.update();
+    let status = &app
+        .world()
+        .get::<ScheduleExpression>(entity)
+        .unwrap()
+        .status;
+    assert_eq!(*status, ExpressionStatus::Valid);
+    assert!(app.world().get::<ScheduleTimer>(entity).is_some());
+
+    app.world_mut()
+        .get_mut::<ScheduleExpression>(entity)
+        .unwrap()
+        .expression = "every blue moon".to_string();
+    app.update();
+    app.update();
+    let status = &app
+        .world()
+        .get::<ScheduleExpression>(entity)
+        .unwrap()
+        .status;
+    assert!(matches!(status, ExpressionStatus::Invalid(_)));
+    assert_eq!(app.triggered::<ScheduleExpressionInvalid>().len(), 1);
+    let timer = app.world().get::<ScheduleTimer>(entity).unwrap();
+    assert_eq!(timer.schedule.to_string(), "0 0 * * * ? *");
+}
+
+#[test]
+fn test_schedule_fallback() {
+    use crate::testing::{cron_app, TestAppExt};
+
+    let mut app = cron_app();
+    app.record::<ScheduleFallbackUsed>();
+    let valid = app
+        .world_mut()
+        .spawn(ScheduleTimer::new_or("every minute", "every hour"))
+        .id();
+    let timer = app
+        .world_mut()
+        .spawn(ScheduleTimer::new_or("every blue moon", "every hour"))
+        .id();
+    let edit = app
+        .world_mut()
+        .spawn(ScheduleExpression::new("every blue moon").with_fallback("every minute"))
+        .id();
+    app.update();
+    app.update();
+
+    assert!(app
+        .world()
+        .get::<ScheduleTimer>(valid)
+        .unwrap()
+        .fallback_error()
+        .is_none());
+    assert!(app
+        .world()
+        .get::<ScheduleTimer>(timer)
+        .unwrap()
+        .fallback_error()
+        .is_some());
+    let schedule = app
+        .world()
+        .get::<ScheduleTimer>(edit)
+        .unwrap()
+        .schedule
+        .to_string();
+    assert_eq!(schedule, "0 * * * * ? *");
+    let fallbacks = app
+        .triggered::<ScheduleFallbackUsed>()
+        .iter()
+        .map(|(_, used)| used.fallback.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(fallbacks, ["0 0 * * * ? *", "0 * * * * ? *"]);
+}
+
+#[test]
+fn test_edit_from_timer() {
+    use crate::testing::cron_app;
+    use bevy_reflect::{FromReflect, PartialReflect};
+
+    let mut app = cron_app();
+    let entity = app.world_mut().spawn(ScheduleTimer::new("every hour")).id();
+    app.update();
+    let timer = app.world().get::<ScheduleTimer>(entity).unwrap();
+    let armed = timer.local_schedule;
+    assert!(armed.is_some());
+    let reflected = ScheduleTimer::from_reflect(timer.as_partial_reflect()).unwrap();
+    assert_eq!(reflected.expression(), Some("every hour"));
+
+    let edit = ScheduleExpression::from_timer(timer).unwrap();
+    assert_eq!(edit.expression, "every hour");
+    app.world_mut().entity_mut(entity).insert(edit);
+    app.update();
+    // same schedule, still armed
+    let timer = app.world().get::<ScheduleTimer>(entity).unwrap();
+    assert_eq!(timer.local_schedule, armed);
+
+    app.world_mut()
+        .get_mut::<ScheduleExpression>(entity)
+        .unwrap()
+        .expression = "0 0 * * * ? *".to_string();
+    app.update();
+    let timer = app.world().get::<ScheduleTimer>(entity).unwrap();
+    assert_eq!(timer.local_schedule, armed);
+    assert_eq!(timer.expression(), Some("every hour"));
+}
+
+#[test]
+fn test_set_expression() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronClock;
+
+    let mut app = cron_app();
+    app.record::<ScheduleChanged>();
+    let entity = app.world_mut().spawn(ScheduleTimer::new("every hour")).id();
+    app.update();
+
+    let mut timer = app.world_mut().get_mut::<ScheduleTimer>(entity).unwrap();
+    assert!(timer.set_expression("every blue moon").is_err());
+    timer.set_expression("every 10 seconds").unwrap();
+    app.update();
+    let epoch = CronClock::ticks(1).now();
+    let timer = app.world().get::<ScheduleTimer>(entity).unwrap();
+    assert_eq!(
+        timer.local_schedule,
+        Some(epoch + chrono::Duration::seconds(10))
+    );
+    let changes = app
+        .triggered::<ScheduleChanged>()
+        .iter()
+        .map(|(_, changed)| (changed.previous.as_str(), changed.schedule.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(changes, [("0 0 * * * ? *", "0/10 * * * * ? *")]);
+}
@@ -0,0 +1,102 @@
+use std::fmt;
+
+/// Errors produced while building or parsing schedules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CronError {
+    /// the expression is neither a valid cron expression nor a supported english phrase
+    InvalidExpression {
+        /// the offending expression
+        expression: String,
+        /// why it was rejected
+        reason: String,
+    },
+    /// a value given to a schedule builder is outside of its allowed range
+    OutOfRange {
+        /// name of the field, e.g. `"hour"`
+        field: &'static str,
+        /// the rejected value
+        value: u32,
+        /// smallest allowed value
+        min: u32,
+        /// largest allowed value
+        max: u32,
+    },
+    /// the timezone name could not be resolved
+    UnknownTimezone {
+        /// the offending name
+        name: String,
+    },
+    /// the timezone of the system could not be determined, see
+    /// [`CronTimezone::system`](crate::CronTimezone::system)
+    SystemTimezoneUnavailable {
+        /// why the lookup failed
+        reason: String,
+    },
+    /// a save was written by a newer version of the crate
+    UnsupportedSaveVersion {
+        /// version of the save
+        version: u32,
+        /// newest version this crate reads
+        supported: u32,
+    },
+    /// an expression from an untrusted source exceeds its [`ExpressionLimits`](crate::ExpressionLimits)
+    TooComplex {
+        /// the offending expression, truncated to the length limit
+        expression: String,
+        /// which limit was exceeded
+        reason: String,
+    },
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CronError::InvalidExpression { expression, reason } => {
+                write!(f, "invalid cron expression `{expression}`: {reason}")
+            }
+            CronError::OutOfRange {
+                field,
+                value,
+                min,
+                max,
+            } => write!(f, "{field} {value} is out of range {min}-{max}"),
+            CronError::UnknownTimezone { name } => {
+                write!(f, "unknown timezone `{name}`")?;
+                if cfg!(not(feature = "timezone")) && name.contains('/') {
+                    write!(f, ", named timezones require the `timezone` feature")?;
+                }
+                Ok(())
+            }
+            CronError::SystemTimezoneUnavailable { reason } => {
+                write!(f, "system timezone unavailable: {reason}")
+            }
+            CronError::UnsupportedSaveVersion { version, supported } => write!(
+                f,
+                "save format version {version} is newer than the supported version {supported}"
+            ),
+            CronError::TooComplex { expression, reason } => {
+                write!(f, "cron expression `{expression}` is too complex: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CronError {}
+
+pub(crate) fn check_range(
+    field: &'static str,
+    value: u32,
+    min: u32,
+    max: u32,
+) -> Result<u32, CronError> {
+    if (min..=max).contains(&value) {
+        Ok(value)
+    } else {
+        Err(CronError::OutOfRange {
+            field,
+            value,
+            min,
+            max,
+        })
+    }
+}
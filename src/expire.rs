@@ -0,0 +1,176 @@
+use crate::{CronClock, CronExpr, IntoCronExpression, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::DespawnRecursiveExt;
+use chrono::DateTime;
+
+/// Despawns the entity, with its children, once the deadline passed.
+///
+/// Expired entities are swept in batches on the cadence of [`CleanupOnSchedule`], which is far
+/// cheaper than a [`ScheduleTimer`] per short-lived entity such as floating damage text or
+/// corpses.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn spawn_corpse(mut commands: Commands, clock: Res<CronClock>) {
+///     commands.spawn(ExpireBy::at(clock.now() + chrono::Duration::seconds(30)));
+///     // gone at the next daily reset
+///     commands.spawn(ExpireBy::next("0 0 4 * * ? *"));
+/// }
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(CleanupOnSchedule::new("every second"))
+///     .add_systems(Update, spawn_corpse);
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub enum ExpireBy {
+    /// expires at this time
+    At(DateTime<chrono::Local>),
+    /// expires at the next occurrence of the expression after the entity was spawned
+    Next(Box<CronExpr>),
+}
+
+impl ExpireBy {
+    /// Expire at `time`
+    pub fn at(time: DateTime<chrono::Local>) -> Self {
+        Self::At(time)
+    }
+
+    /// Expire at the next occurrence of `expression`, panics when it is invalid
+    pub fn next(expression: impl IntoCronExpression) -> Self {
+        Self::Next(Box::new(
+            expression
+                .into_cron_expr()
+                .expect("Failed to parse cron expression"),
+        ))
+    }
+
+    /// The deadline, `None` for an expression until it was resolved or when it never fires
+    pub fn deadline(&self) -> Option<DateTime<chrono::Local>> {
+        match self {
+            Self::At(time) => Some(*time),
+            Self::Next(_) => None,
+        }
+    }
+}
+
+/// Sweeps entities whose [`ExpireBy`] deadline passed, on its own schedule.
+///
+/// At most `batch_size` entities are despawned per frame. When more expired, the sweep continues
+/// on the following frames until none are left.
+#[derive(Resource, Debug, Clone)]
+pub struct CleanupOnSchedule {
+    /// when sweeps start
+    pub timer: ScheduleTimer,
+    /// most entities despawned per frame
+    pub batch_size: usize,
+    /// entities despawned so far
+    pub despawned: u64,
+    backlog: bool,
+}
+
+impl Default for CleanupOnSchedule {
+    fn default() -> Self {
+        Self::new("every second")
+    }
+}
+
+impl CleanupOnSchedule {
+    /// Sweep on the cron or english `expression`
+    pub fn new(expression: impl IntoCronExpression) -> Self {
+        Self {
+            timer: ScheduleTimer::new(expression),
+            batch_size: 1024,
+            despawned: 0,
+            backlog: false,
+        }
+    }
+
+    /// Set the most entities despawned per frame
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+pub(crate) fn resolve_expiry(
+    mut query: Query<(Entity, &mut ExpireBy), Added<ExpireBy>>,
+    clock: Res<CronClock>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    for (entity, mut expire) in query.iter_mut() {
+        let ExpireBy::Next(expr) = &*expire else {
+            continue;
+        };
+        let deadline = expr.after(&now).next();
+        match deadline {
+            Some(deadline) => *expire = ExpireBy::At(deadline),
+            // never expires
+            None => {
+                commands.entity(entity).remove::<ExpireBy>();
+            }
+        }
+    }
+}
+
+pub(crate) fn sweep_expired(
+    mut cleanup: ResMut<CleanupOnSchedule>,
+    query: Query<(Entity, &ExpireBy)>,
+    clock: Res<CronClock>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    let cleanup = &mut *cleanup;
+    let due = cleanup
+        .timer
+        .poll(now, cleanup.timer.timezone.unwrap_or_default())
+        .is_some();
+    if !due && !cleanup.backlog {
+        return;
+    }
+    let mut expired = query
+        .iter()
+        .filter(|(_, expire)| expire.deadline().is_some_and(|deadline| deadline <= now))
+        .map(|(entity, _)| entity);
+    for entity in expired.by_ref().take(cleanup.batch_size) {
+        commands.entity(entity).despawn_recursive();
+        cleanup.despawned += 1;
+    }
+    cleanup.backlog = expired.next().is_some();
+}
+
+#[test]
+fn test_cleanup_on_schedule() {
+    use crate::testing::{cron_app, start};
+
+    let past = start() - chrono::Duration::seconds(1);
+    let future = start() + chrono::Duration::hours(1);
+    let mut app = cron_app();
+    let mut cleanup = CleanupOnSchedule::new("every minute").with_batch_size(2);
+    cleanup.timer.local_schedule = Some(past);
+    app.insert_resource(cleanup);
+    let expired = (0..3)
+        .map(|_| app.world_mut().spawn(ExpireBy::at(past)).id())
+        .collect::<Vec<_>>();
+    let alive = app.world_mut().spawn(ExpireBy::at(future)).id();
+    let scheduled = app.world_mut().spawn(ExpireBy::next("0 0 0 1 1 ? *")).id();
+
+    app.update();
+    assert_eq!(app.world().resource::<CleanupOnSchedule>().despawned, 2);
+    app.update();
+    assert_eq!(app.world().resource::<CleanupOnSchedule>().despawned, 3);
+    assert!(expired
+        .iter()
+        .all(|entity| app.world().get_entity(*entity).is_err()));
+    assert!(app.world().get_entity(alive).is_ok());
+    let deadline = app.world().get::<ExpireBy>(scheduled).unwrap().deadline();
+    assert!(deadline.is_some_and(|deadline| deadline > start()));
+
+    // swept again only on the next occurrence
+    app.world_mut().spawn(ExpireBy::at(past));
+    app.update();
+    assert_eq!(app.world().resource::<CleanupOnSchedule>().despawned, 3);
+}
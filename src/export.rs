@@ -0,0 +1,211 @@
+use crate::{CronHistory, CronHistoryEntry, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::fmt::Write;
+
+/// Output format of the export functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// a JSON array of objects
+    Json,
+    /// comma separated values with a header line
+    Csv,
+}
+
+/// A row of the current schedule table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleRow {
+    /// the timer entity
+    pub entity: Entity,
+    /// label of the timer
+    pub label: Option<String>,
+    /// human readable schedule, e.g. the cron expression
+    pub schedule: String,
+    /// next planned fire
+    pub next_fire: Option<DateTime<chrono::Local>>,
+}
+
+/// Collect the schedule table of all timers in the world
+pub fn schedule_table(world: &mut World) -> Vec<ScheduleRow> {
+    world
+        .query::<(Entity, &ScheduleTimer)>()
+        .iter(world)
+        .map(|(entity, timer)| ScheduleRow {
+            entity,
+            label: timer.label.clone(),
+            schedule: timer.schedule.to_string(),
+            next_fire: timer.local_schedule,
+        })
+        .collect()
+}
+
+/// Serialize the schedule table
+pub fn export_schedule_table(rows: &[ScheduleRow], format: ExportFormat) -> String {
+    let mut out = String::new();
+    match format {
+        ExportFormat::Json => {
+            out.push('[');
+            for (index, row) in rows.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                let _ = write!(
+                    out,
+                    r#"{{"entity":"{}","label":{},"schedule":{},"next_fire":{}}}"#,
+                    row.entity,
+                    json_option(row.label.as_deref()),
+                    json_string(&row.schedule),
+                    json_option(row.next_fire.map(|time| time.to_rfc3339()).as_deref()),
+                );
+            }
+            out.push(']');
+        }
+        ExportFormat::Csv => {
+            out.push_str("entity,label,schedule,next_fire\n");
+            for row in rows {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},{}",
+                    row.entity,
+                    csv_field(row.label.as_deref().unwrap_or_default()),
+                    csv_field(&row.schedule),
+                    row.next_fire
+                        .map(|time| time.to_rfc3339())
+                        .unwrap_or_default(),
+                );
+            }
+        }
+    }
+    out
+}
+
+impl CronHistory {
+    /// Serialize the recorded fires for offline analysis
+    pub fn export(&self, format: ExportFormat) -> String {
+        export_history(self.iter(), format)
+    }
+}
+
+/// Serialize fires, e.g. a filtered view of a [`CronHistory`]
+pub fn export_history<'a>(
+    entries: impl IntoIterator<Item = &'a CronHistoryEntry>,
+    format: ExportFormat,
+) -> String {
+    let mut out = String::new();
+    match format {
+        ExportFormat::Json => {
+            out.push('[');
+            for (index, entry) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                let _ = write!(
+                    out,
+                    r#"{{"entity":"{}","label":{},"scheduled":"{}","actual":"{}","lateness_ms":{}}}"#,
+                    entry.entity,
+                    json_option(entry.label.as_deref()),
+                    entry.record.scheduled.to_rfc3339(),
+                    entry.record.actual.to_rfc3339(),
+                    entry.record.lateness().num_milliseconds(),
+                );
+            }
+            out.push(']');
+        }
+        ExportFormat::Csv => {
+            out.push_str("entity,label,scheduled,actual,lateness_ms\n");
+            for entry in entries {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},{},{}",
+                    entry.entity,
+                    csv_field(entry.label.as_deref().unwrap_or_default()),
+                    entry.record.scheduled.to_rfc3339(),
+                    entry.record.actual.to_rfc3339(),
+                    entry.record.lateness().num_milliseconds(),
+                );
+            }
+        }
+    }
+    out
+}
+
+/// Dump the schedule table and the [`CronHistory`] (when present) as a single document.
+///
+/// Meant to be called from a debug console command, an exclusive system or a remote protocol
+/// handler. JSON produces `{"timers":[...],"history":[...]}`, CSV two sections separated by a
+/// blank line.
+pub fn export_cron_snapshot(world: &mut World, format: ExportFormat) -> String {
+    let timers = export_schedule_table(&schedule_table(world), format);
+    let history = world
+        .get_resource::<CronHistory>()
+        .map(|history| history.export(format));
+    match (format, history) {
+        (ExportFormat::Json, history) => format!(
+            r#"{{"timers":{timers},"history":{}}}"#,
+            history.as_deref().unwrap_or("[]")
+        ),
+        (ExportFormat::Csv, Some(history)) => format!("{timers}\n{history}"),
+        (ExportFormat::Csv, None) => timers,
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_option(value: Option<&str>) -> String {
+    value.map_or_else(|| "null".to_string(), json_string)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[test]
+fn test_export() {
+    use crate::FireRecord;
+
+    let now = chrono::Local::now();
+    let mut history = CronHistory::default();
+    history.push(CronHistoryEntry {
+        label: Some("say \"hi\", twice".to_string()),
+        entity: Entity::PLACEHOLDER,
+        record: FireRecord {
+            scheduled: now,
+            actual: now + chrono::Duration::milliseconds(250),
+        },
+    });
+    let json = history.export(ExportFormat::Json);
+    assert!(json.contains(r#""label":"say \"hi\", twice""#));
+    assert!(json.contains(r#""lateness_ms":250"#));
+    let csv = history.export(ExportFormat::Csv);
+    assert_eq!(csv.lines().count(), 2);
+    assert!(csv.contains(r#""say ""hi"", twice""#));
+
+    let mut world = World::new();
+    world.spawn(ScheduleTimer::new("0 0 * * * ? *").with_label("hourly"));
+    world.insert_resource(history);
+    let snapshot = export_cron_snapshot(&mut world, ExportFormat::Json);
+    assert!(snapshot.starts_with(r#"{"timers":[{"#));
+    assert!(snapshot.contains(r#""schedule":"0 0 * * * ? *""#));
+}
@@ -0,0 +1,635 @@
+use crate::error::{check_range, CronError};
+pub use bevy_cronjob_parser::MonthWeek;
+use bevy_cronjob_parser::{weekday_name, MonthWeekday};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Timelike, Weekday};
+use cron::{Schedule, TimeUnitSpec};
+use english_to_cron::str_cron_syntax;
+use std::fmt;
+use std::str::FromStr;
+
+/// Anything that can be turned into a cron (or english) expression.
+///
+/// Implemented for string types and [`CommonSchedule`], so every API accepting an expression
+/// ([`schedule_passed`](crate::schedule_passed), [`ScheduleTimer::new`](crate::ScheduleTimer::new), ...)
+/// can be given either.
+pub trait IntoCronExpression {
+    /// parse into a [`CronExpr`]
+    fn into_cron_expr(self) -> Result<CronExpr, CronError>;
+}
+
+impl IntoCronExpression for &str {
+    fn into_cron_expr(self) -> Result<CronExpr, CronError> {
+        CronExpr::parse(self)
+    }
+}
+
+impl IntoCronExpression for String {
+    fn into_cron_expr(self) -> Result<CronExpr, CronError> {
+        CronExpr::parse(&self)
+    }
+}
+
+impl IntoCronExpression for &String {
+    fn into_cron_expr(self) -> Result<CronExpr, CronError> {
+        CronExpr::parse(self)
+    }
+}
+
+/// Frequently used schedules
+///
+/// ```rust
+/// use bevy_cronjob::CommonSchedule;
+///
+/// assert_eq!(CommonSchedule::Every5Sec.expression(), "0/5 * * * * ? *");
+/// assert_eq!(CommonSchedule::DailyAt { hour: 13 }.expression(), "0 0 13 */1 * ? *");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommonSchedule {
+    /// run every 5 sec
+    Every5Sec,
+    /// run every 10 sec
+    Every10Sec,
+    /// run every 30 sec
+    Every30Sec,
+    /// run every minute
+    EveryMin,
+    /// run every 5 minutes
+    Every5Min,
+    /// run every 10 minutes
+    Every10Min,
+    /// run every 30 minutes
+    Every30Min,
+    /// run every hour
+    EveryHour,
+    /// run every day at midnight
+    EveryDay,
+    /// run every day at the given hour (0-23)
+    DailyAt { hour: u32 },
+}
+
+impl CommonSchedule {
+    /// the cron expression of this schedule
+    pub fn expression(&self) -> String {
+        match self {
+            CommonSchedule::Every5Sec => "0/5 * * * * ? *".to_string(),
+            CommonSchedule::Every10Sec => "0/10 * * * * ? *".to_string(),
+            CommonSchedule::Every30Sec => "0/30 * * * * ? *".to_string(),
+            CommonSchedule::EveryMin => "0 * * * * ? *".to_string(),
+            CommonSchedule::Every5Min => "0 0/5 * * * ? *".to_string(),
+            CommonSchedule::Every10Min => "0 0/10 * * * ? *".to_string(),
+            CommonSchedule::Every30Min => "0 0/30 * * * ? *".to_string(),
+            CommonSchedule::EveryHour => "0 0 * * * ? *".to_string(),
+            CommonSchedule::EveryDay => "0 0 0 */1 * ? *".to_string(),
+            CommonSchedule::DailyAt { hour } => format!("0 0 {hour} */1 * ? *"),
+        }
+    }
+}
+
+impl fmt::Display for CommonSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression())
+    }
+}
+
+impl IntoCronExpression for CommonSchedule {
+    fn into_cron_expr(self) -> Result<CronExpr, CronError> {
+        CronExpr::parse(&self.expression())
+    }
+}
+
+/// Day of the month used by [`CronExpr::monthly_on`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonthDay {
+    /// the given day (1-31), clamped to the last day of shorter months
+    Day(u32),
+    /// the last day of every month
+    Last,
+}
+
+/// What happens to occurrences on days 29-31 in months too short to have them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MonthEndPolicy {
+    /// skip the month, as plain cron does
+    #[default]
+    Skip,
+    /// fire on the last day of the month instead
+    Clamp,
+}
+
+/// A validated cron expression.
+///
+/// Besides parsing arbitrary cron or english expressions with [`CronExpr::parse`], it offers
+/// builder presets for common schedules, which are checked when they are built instead of
+/// panicking later when the schedule is used.
+///
+/// ```rust
+/// use bevy_cronjob::CronExpr;
+///
+/// let expr = CronExpr::daily_at(18, 30).unwrap();
+/// assert_eq!(expr.as_str(), "0 30 18 * * ? *");
+/// assert!(CronExpr::daily_at(24, 0).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CronExpr {
+    schedule: Schedule,
+    /// same schedule with every day of month allowed, searched when clamping to the month end
+    month_end: Option<Schedule>,
+    /// the expression with its weekday written as `FRIL` when only occurrences within the last
+    /// seven days of their month are kept
+    last_week: Option<String>,
+    /// the expression as written, e.g. an english phrase
+    source: String,
+}
+
+// expressions are equal when they describe the same schedule, however they were written
+impl PartialEq for CronExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.schedule == other.schedule
+            && self.month_end == other.month_end
+            && self.last_week == other.last_week
+    }
+}
+
+impl Eq for CronExpr {}
+
+impl CronExpr {
+    /// Parse a cron or english expression
+    ///
+    /// Besides plain cron syntax, the day-of-week field accepts `TUE#2` (2nd Tuesday of the
+    /// month) and `FRIL` (last Friday of the month), and english phrases such as
+    /// `"2nd tuesday of the month at 19:00"` are understood.
+    pub fn parse(expression: &str) -> Result<Self, CronError> {
+        let mut expr = match parse_month_weekday(expression) {
+            Some(expr) => expr?,
+            None => Self {
+                schedule: Schedule::from_str(&to_cron_syntax(expression)?)
+                    .expect("validated by to_cron_syntax"),
+                month_end: None,
+                last_week: None,
+                source: String::new(),
+            },
+        };
+        expr.source = expression.to_string();
+        Ok(expr)
+    }
+
+    /// Run once a day at `hour:minute`
+    pub fn daily_at(hour: u32, minute: u32) -> Result<Self, CronError> {
+        let hour = check_range("hour", hour, 0, 23)?;
+        let minute = check_range("minute", minute, 0, 59)?;
+        Self::parse(&format!("0 {minute} {hour} * * ? *"))
+    }
+
+    /// Run once a day at the given time, seconds included
+    pub fn daily_at_time(time: NaiveTime) -> Result<Self, CronError> {
+        Self::parse(&format!(
+            "{} {} {} * * ? *",
+            time.second(),
+            time.minute(),
+            time.hour()
+        ))
+    }
+
+    /// Run on the given weekdays at `hour:minute`, e.g. Mon/Wed/Fri at 18:30
+    ///
+    /// Days are written by name, so the result does not depend on the day-of-week numbering of
+    /// the cron dialect.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::CronExpr;
+    /// use chrono::Weekday;
+    ///
+    /// let expr = CronExpr::weekly_on(&[Weekday::Fri, Weekday::Mon, Weekday::Wed], 18, 30).unwrap();
+    /// assert_eq!(expr.as_str(), "0 30 18 ? * MON,WED,FRI *");
+    /// ```
+    pub fn weekly_on(days: &[Weekday], hour: u32, minute: u32) -> Result<Self, CronError> {
+        if days.is_empty() {
+            return Err(CronError::InvalidExpression {
+                expression: String::new(),
+                reason: "at least one weekday is required".to_string(),
+            });
+        }
+        let hour = check_range("hour", hour, 0, 23)?;
+        let minute = check_range("minute", minute, 0, 59)?;
+        let mut days = days.to_vec();
+        days.sort_by_key(|day| day.num_days_from_monday());
+        days.dedup();
+        let days = days
+            .iter()
+            .map(|day| weekday_name(*day))
+            .collect::<Vec<_>>()
+            .join(",");
+        Self::parse(&format!("0 {minute} {hour} ? * {days} *"))
+    }
+
+    /// Run once a month on the `week`-th `weekday` at `hour:minute`, e.g. on the 2nd Tuesday
+    ///
+    /// ```rust
+    /// use bevy_cronjob::{CronExpr, MonthWeek};
+    /// use chrono::Weekday;
+    ///
+    /// let raid_night = CronExpr::monthly_on_weekday(MonthWeek::Second, Weekday::Tue, 19, 0).unwrap();
+    /// assert_eq!(raid_night.as_str(), "0 0 19 8-14 * TUE *");
+    /// assert_eq!(raid_night, CronExpr::parse("2nd tuesday of the month at 19:00").unwrap());
+    /// ```
+    pub fn monthly_on_weekday(
+        week: MonthWeek,
+        weekday: Weekday,
+        hour: u32,
+        minute: u32,
+    ) -> Result<Self, CronError> {
+        let hour = check_range("hour", hour, 0, 23)?;
+        let minute = check_range("minute", minute, 0, 59)?;
+        Self::from_month_weekday(&MonthWeekday::Phrase {
+            week,
+            weekday,
+            hour,
+            minute,
+        })
+    }
+
+    /// Build the expression of a weekday restricted to a week of the month
+    fn from_month_weekday(month_weekday: &MonthWeekday) -> Result<Self, CronError> {
+        let expr = Self::parse(&month_weekday.to_cron())?;
+        Ok(if month_weekday.week() == MonthWeek::Last {
+            expr.in_last_week()
+        } else {
+            expr
+        })
+    }
+
+    /// Only keep occurrences within the last seven days of their month
+    pub(crate) fn in_last_week(mut self) -> Self {
+        let mut fields = self
+            .schedule
+            .source()
+            .split_whitespace()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        fields[5].push('L');
+        let last_week = fields.join(" ");
+        if self.source == self.schedule.source() {
+            self.source.clone_from(&last_week);
+        }
+        self.last_week = Some(last_week);
+        self
+    }
+
+    /// Whether only occurrences within the last seven days of their month are kept, as for
+    /// [`MonthWeek::Last`]
+    pub fn in_last_week_of_month(&self) -> bool {
+        self.last_week.is_some()
+    }
+
+    /// Run once a month on `day` at `hour:minute`
+    ///
+    /// Days past the end of a shorter month fire on its last day instead of skipping the month,
+    /// so `MonthDay::Day(31)` runs on Feb 28th (or 29th), Apr 30th, ...
+    ///
+    /// ```rust
+    /// use bevy_cronjob::{CronExpr, MonthDay};
+    ///
+    /// let first = CronExpr::monthly_on(MonthDay::Day(1), 0, 0).unwrap();
+    /// assert_eq!(first.as_str(), "0 0 0 1 * ? *");
+    /// let payout = CronExpr::monthly_on(MonthDay::Last, 18, 0).unwrap();
+    /// ```
+    pub fn monthly_on(day: MonthDay, hour: u32, minute: u32) -> Result<Self, CronError> {
+        let day = match day {
+            MonthDay::Day(day) => check_range("day", day, 1, 31)?,
+            MonthDay::Last => 31,
+        };
+        let hour = check_range("hour", hour, 0, 23)?;
+        let minute = check_range("minute", minute, 0, 59)?;
+        let expr = Self::parse(&format!("0 {minute} {hour} {day} * ? *"))?;
+        Ok(if day > 28 {
+            expr.clamp_to_month_end()
+        } else {
+            expr
+        })
+    }
+
+    /// Fire on the last day of months that are too short for the requested day of month
+    pub(crate) fn clamp_to_month_end(mut self) -> Self {
+        let mut fields = self
+            .schedule
+            .source()
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        fields[3] = "*";
+        self.month_end =
+            Some(Schedule::from_str(&fields.join(" ")).expect("only day of month changed"));
+        self
+    }
+
+    /// Choose what happens to days of month beyond the end of shorter months
+    ///
+    /// ```rust
+    /// use bevy_cronjob::{CronExpr, MonthEndPolicy};
+    /// use chrono::{Datelike, Local, TimeZone};
+    ///
+    /// let rent = CronExpr::parse("0 0 9 31 * ? *")
+    ///     .unwrap()
+    ///     .with_month_end_policy(MonthEndPolicy::Clamp);
+    /// let start = Local.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(rent.after(&start).next().unwrap().day(), 28);
+    /// ```
+    pub fn with_month_end_policy(mut self, policy: MonthEndPolicy) -> Self {
+        match policy {
+            MonthEndPolicy::Skip => {
+                self.month_end = None;
+                self
+            }
+            MonthEndPolicy::Clamp => self.clamp_to_month_end(),
+        }
+    }
+
+    /// The current [`MonthEndPolicy`]
+    pub fn month_end_policy(&self) -> MonthEndPolicy {
+        if self.clamps_to_month_end() {
+            MonthEndPolicy::Clamp
+        } else {
+            MonthEndPolicy::Skip
+        }
+    }
+
+    /// Whether days of month beyond the end of a month fire on its last day instead
+    pub fn clamps_to_month_end(&self) -> bool {
+        self.month_end.is_some()
+    }
+
+    /// the cron expression, with the weekday of a [`MonthWeek::Last`] schedule written as `FRIL`
+    pub fn as_str(&self) -> &str {
+        self.last_week
+            .as_deref()
+            .unwrap_or_else(|| self.schedule.source())
+    }
+
+    /// The expression as it was parsed, e.g. `"every hour"` where [`as_str`](Self::as_str) is
+    /// its cron syntax `"0 0 * * * ? *"`
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// the parsed cron schedule
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Occurrences after the given time
+    pub fn after<'a, Z: TimeZone + 'a>(
+        &'a self,
+        after: &DateTime<Z>,
+    ) -> Box<dyn Iterator<Item = DateTime<Z>> + 'a> {
+        let occurrences: Box<dyn Iterator<Item = DateTime<Z>> + 'a> = match &self.month_end {
+            Some(month_end) => Box::new(
+                month_end
+                    .after(after)
+                    .filter(|datetime| self.includes_day(datetime)),
+            ),
+            None => Box::new(self.schedule.after(after)),
+        };
+        if self.last_week.is_some() {
+            Box::new(occurrences.filter(|datetime| {
+                datetime.day() + 7 > last_day_of_month(datetime.year(), datetime.month())
+            }))
+        } else {
+            occurrences
+        }
+    }
+
+    /// Upcoming occurrences in the given timezone
+    pub fn upcoming<'a, Z: TimeZone + 'a>(
+        &'a self,
+        timezone: Z,
+    ) -> Box<dyn Iterator<Item = DateTime<Z>> + 'a> {
+        self.after(&chrono::Utc::now().with_timezone(&timezone))
+    }
+
+    fn includes_day<Z: TimeZone>(&self, datetime: &DateTime<Z>) -> bool {
+        let days = self.schedule.days_of_month();
+        let day = datetime.day();
+        let last = last_day_of_month(datetime.year(), datetime.month());
+        days.includes(day) || (day == last && (last + 1..=31).any(|day| days.includes(day)))
+    }
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (year, month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|first| first.pred_opt())
+        .map_or(31, |last| last.day())
+}
+
+impl fmt::Display for CronExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CronExpr {
+    type Err = CronError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl IntoCronExpression for CronExpr {
+    fn into_cron_expr(self) -> Result<CronExpr, CronError> {
+        Ok(self)
+    }
+}
+
+impl IntoCronExpression for &CronExpr {
+    fn into_cron_expr(self) -> Result<CronExpr, CronError> {
+        Ok(self.clone())
+    }
+}
+
+/// Parse expressions naming a weekday of a given week of the month, in cron (`TUE#2`, `FRIL`) or
+/// english (`"last friday of the month at 6 pm"`) form. `None` when the expression is neither.
+fn parse_month_weekday(expression: &str) -> Option<Result<CronExpr, CronError>> {
+    Some(
+        match bevy_cronjob_parser::parse_month_weekday(expression)? {
+            Ok(MonthWeekday::Phrase {
+                week,
+                weekday,
+                hour,
+                minute,
+            }) => CronExpr::monthly_on_weekday(week, weekday, hour, minute),
+            Ok(month_weekday) => CronExpr::from_month_weekday(&month_weekday),
+            Err(reason) => Err(CronError::InvalidExpression {
+                expression: expression.to_string(),
+                reason: reason.to_string(),
+            }),
+        },
+    )
+}
+
+/// Convert an expression into cron syntax, translating english phrases when it is not
+/// already a valid cron expression.
+pub(crate) fn to_cron_syntax(expression: &str) -> Result<String, CronError> {
+    let invalid = |reason: String| CronError::InvalidExpression {
+        expression: expression.to_string(),
+        reason,
+    };
+    match Schedule::from_str(expression) {
+        Ok(_) => Ok(expression.to_string()),
+        Err(err) if !expression.chars().any(|c| c.is_ascii_alphabetic()) => {
+            Err(invalid(err.to_string()))
+        }
+        Err(_) => {
+            let cron = str_cron_syntax(expression).map_err(|err| invalid(err.to_string()))?;
+            Schedule::from_str(&cron).map_err(|err| invalid(err.to_string()))?;
+            Ok(cron)
+        }
+    }
+}
+
+#[test]
+fn test_common_schedule() {
+    use crate::str_cron_syntax;
+
+    let english = [
+        (CommonSchedule::Every5Sec, "every 5 seconds"),
+        (CommonSchedule::Every10Sec, "every 10 seconds"),
+        (CommonSchedule::Every30Sec, "every 30 seconds"),
+        (CommonSchedule::EveryMin, "every minute"),
+        (CommonSchedule::Every5Min, "every 5 minutes"),
+        (CommonSchedule::Every10Min, "every 10 minutes"),
+        (CommonSchedule::Every30Min, "every 30 minutes"),
+        (CommonSchedule::EveryHour, "every hour"),
+        (CommonSchedule::EveryDay, "every day"),
+        (CommonSchedule::DailyAt { hour: 0 }, "every day at 12 am"),
+        (CommonSchedule::DailyAt { hour: 7 }, "every day at 7 am"),
+        (CommonSchedule::DailyAt { hour: 12 }, "every day at 12 pm"),
+        (CommonSchedule::DailyAt { hour: 23 }, "every day at 11 pm"),
+    ];
+    for (schedule, phrase) in english {
+        assert_eq!(schedule.expression(), str_cron_syntax(phrase).unwrap());
+    }
+}
+
+#[test]
+fn test_daily_at() {
+    assert_eq!(CronExpr::daily_at(0, 0).unwrap().as_str(), "0 0 0 * * ? *");
+    assert_eq!(
+        CronExpr::daily_at_time(NaiveTime::from_hms_opt(7, 15, 30).unwrap())
+            .unwrap()
+            .as_str(),
+        "30 15 7 * * ? *"
+    );
+    assert!(matches!(
+        CronExpr::daily_at(12, 60),
+        Err(CronError::OutOfRange {
+            field: "minute",
+            ..
+        })
+    ));
+    assert!(CronExpr::parse("every 5 seconds").is_ok());
+    assert!(CronExpr::parse("0 0 12 ? * SUN *").is_ok());
+    assert!(CronExpr::parse("not a schedule").is_err());
+}
+
+#[test]
+fn test_weekly_on() {
+    use chrono::Local;
+
+    let expr = CronExpr::weekly_on(&[Weekday::Sun, Weekday::Sat, Weekday::Sun], 9, 0).unwrap();
+    assert_eq!(expr.as_str(), "0 0 9 ? * SAT,SUN *");
+    let start = Local.with_ymd_and_hms(2024, 6, 3, 0, 0, 0).unwrap();
+    let days = expr
+        .schedule()
+        .after(&start)
+        .take(4)
+        .map(|datetime| datetime.weekday())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        days,
+        vec![Weekday::Sat, Weekday::Sun, Weekday::Sat, Weekday::Sun]
+    );
+    assert!(CronExpr::weekly_on(&[], 9, 0).is_err());
+}
+
+#[test]
+fn test_month_end_policy() {
+    use chrono::Local;
+
+    let start = Local.with_ymd_and_hms(2024, 1, 31, 12, 0, 0).unwrap();
+    let days = |policy| {
+        CronExpr::parse("0 0 0 30,31 * ? *")
+            .unwrap()
+            .with_month_end_policy(policy)
+            .after(&start)
+            .take(3)
+            .map(|datetime| (datetime.month(), datetime.day()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(days(MonthEndPolicy::Skip), vec![(3, 30), (3, 31), (4, 30)]);
+    assert_eq!(days(MonthEndPolicy::Clamp), vec![(2, 29), (3, 30), (3, 31)]);
+}
+
+#[test]
+fn test_monthly_on_weekday() {
+    use chrono::Local;
+
+    let start = Local.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    let dates = |expr: CronExpr| {
+        expr.after(&start)
+            .take(3)
+            .map(|datetime| (datetime.month(), datetime.day(), datetime.hour()))
+            .collect::<Vec<_>>()
+    };
+    let second_tuesday = CronExpr::parse("2nd Tuesday of the month at 19:00").unwrap();
+    assert_eq!(second_tuesday, CronExpr::parse("0 0 19 ? * TUE#2").unwrap());
+    assert_eq!(
+        dates(second_tuesday),
+        vec![(6, 11, 19), (7, 9, 19), (8, 13, 19)]
+    );
+
+    let last_friday = CronExpr::parse("every last friday of the month at 6 pm").unwrap();
+    assert!(last_friday.in_last_week_of_month());
+    assert_eq!(last_friday, CronExpr::parse("0 0 18 ? * FRIL *").unwrap());
+    // the flag survives printing
+    assert_eq!(last_friday.to_string(), "0 0 18 22-31 * FRIL *");
+    assert_eq!(CronExpr::parse(last_friday.as_str()).unwrap(), last_friday);
+    let payday = CronExpr::monthly_on_weekday(MonthWeek::Last, Weekday::Fri, 18, 0).unwrap();
+    assert_eq!(payday.source(), "0 0 18 22-31 * FRIL *");
+    // in August 2024 the 23rd and the 30th are both fridays
+    assert_eq!(
+        dates(last_friday),
+        vec![(6, 28, 18), (7, 26, 18), (8, 30, 18)]
+    );
+
+    assert!(CronExpr::parse("0 0 0 ? * MON#6 *").is_err());
+    assert!(CronExpr::parse("first monday of the month at noonish").is_err());
+    assert!(CronExpr::monthly_on_weekday(MonthWeek::First, Weekday::Mon, 24, 0).is_err());
+}
+
+#[test]
+fn test_monthly_on() {
+    use chrono::Local;
+
+    let start = Local.with_ymd_and_hms(2023, 12, 31, 12, 0, 0).unwrap();
+    let last = CronExpr::monthly_on(MonthDay::Last, 0, 0).unwrap();
+    let days = last
+        .after(&start)
+        .take(4)
+        .map(|datetime| (datetime.month(), datetime.day()))
+        .collect::<Vec<_>>();
+    assert_eq!(days, vec![(1, 31), (2, 29), (3, 31), (4, 30)]);
+
+    let thirtieth = CronExpr::monthly_on(MonthDay::Day(30), 0, 0).unwrap();
+    let days = thirtieth
+        .after(&start)
+        .take(3)
+        .map(|datetime| (datetime.month(), datetime.day()))
+        .collect::<Vec<_>>();
+    assert_eq!(days, vec![(1, 30), (2, 29), (3, 30)]);
+
+    assert!(CronExpr::monthly_on(MonthDay::Day(0), 0, 0).is_err());
+}
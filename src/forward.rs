@@ -0,0 +1,119 @@
+use crate::AnyScheduleArrived;
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// A fire forwarded by [`CronFireSender`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardedFire {
+    /// the timer entity
+    pub entity: Entity,
+    /// label of the timer
+    pub label: Option<String>,
+    /// when the occurrence was scheduled
+    pub scheduled: DateTime<chrono::Local>,
+    /// when it was dispatched
+    pub actual: DateTime<chrono::Local>,
+}
+
+/// Forwards every fire into a channel, so threads outside the ECS such as audio engines or
+/// network stacks react to the same schedules.
+///
+/// With this resource present, [`CronJobPlugin`](crate::CronJobPlugin) sends a
+/// [`ForwardedFire`] for every [`AnyScheduleArrived`]. Fires are dropped once the receiver is
+/// gone.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// let (sender, receiver) = CronFireSender::channel();
+/// std::thread::spawn(move || {
+///     for fire in receiver {
+///         println!("audio thread: {:?} fired", fire.label);
+///     }
+/// });
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(sender.with_labels(["music-change"]));
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct CronFireSender {
+    sender: Sender<ForwardedFire>,
+    labels: Option<Vec<String>>,
+}
+
+impl CronFireSender {
+    /// Forward into `sender`
+    pub fn new(sender: Sender<ForwardedFire>) -> Self {
+        Self {
+            sender,
+            labels: None,
+        }
+    }
+
+    /// A sender forwarding into a new channel, together with the receiving end
+    pub fn channel() -> (Self, Receiver<ForwardedFire>) {
+        let (sender, receiver) = mpsc::channel();
+        (Self::new(sender), receiver)
+    }
+
+    /// Only forward fires of timers with one of `labels`
+    pub fn with_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.labels = Some(labels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn forwards(&self, label: Option<&str>) -> bool {
+        match &self.labels {
+            Some(labels) => label.is_some_and(|label| labels.iter().any(|l| l == label)),
+            None => true,
+        }
+    }
+}
+
+pub(crate) fn forward_fires(
+    trigger: Trigger<AnyScheduleArrived>,
+    sender: Option<Res<CronFireSender>>,
+) {
+    let Some(sender) = sender else {
+        return;
+    };
+    let fire = trigger.event();
+    if !sender.forwards(fire.label.as_deref()) {
+        return;
+    }
+    // a closed receiver means nobody listens anymore
+    let _ = sender.sender.send(ForwardedFire {
+        entity: fire.entity,
+        label: fire.label.clone(),
+        scheduled: fire.scheduled,
+        actual: fire.actual,
+    });
+}
+
+#[test]
+fn test_forward_fires() {
+    use crate::testing::cron_app;
+    use crate::{CronAppExt, ScheduleTimer};
+
+    let (sender, receiver) = CronFireSender::channel();
+    let mut app = cron_app();
+    app.insert_resource(sender.with_labels(["music"]));
+    for label in ["music", "weather"] {
+        app.world_mut()
+            .spawn(ScheduleTimer::new("every hour").with_label(label));
+    }
+    app.update();
+    app.advance_cron_time(std::time::Duration::from_secs(3600));
+
+    let fires = std::thread::spawn(move || receiver.try_iter().collect::<Vec<_>>())
+        .join()
+        .unwrap();
+    assert_eq!(fires.len(), 1);
+    assert_eq!(fires[0].label.as_deref(), Some("music"));
+    assert_eq!(
+        fires[0].scheduled,
+        crate::CronClock::ticks(1).now() + chrono::Duration::hours(1)
+    );
+}
@@ -0,0 +1,77 @@
+use crate::{CronError, IntoCronExpression};
+use std::time::Duration;
+
+/// Most occurrences sampled by [`estimate_frequency`], the rate of hotter schedules is
+/// extrapolated from the span these cover
+const MAX_SAMPLES: usize = 10_000;
+
+/// Expected fire rate of an expression, see [`estimate_frequency`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyEstimate {
+    /// expected fires per hour
+    pub per_hour: f64,
+    /// expected fires per day
+    pub per_day: f64,
+    /// occurrences the estimate is based on
+    pub samples: usize,
+}
+
+/// Estimate how often an expression fires by sampling its occurrences within `window` from now,
+/// so validators can flag surprisingly hot schedules.
+///
+/// ```rust
+/// use bevy_cronjob::prelude::*;
+/// use std::time::Duration;
+///
+/// let week = Duration::from_secs(7 * 24 * 3600);
+/// let estimate = estimate_frequency("0 */15 9-17 * * MON-FRI *", week).unwrap();
+/// assert!(estimate.per_day > 20.0 && estimate.per_day < 30.0);
+/// ```
+pub fn estimate_frequency(
+    expression: impl IntoCronExpression,
+    window: Duration,
+) -> Result<FrequencyEstimate, CronError> {
+    let expr = expression.into_cron_expr()?;
+    let start = chrono::Local::now();
+    let end = chrono::Duration::from_std(window)
+        .ok()
+        .and_then(|window| start.checked_add_signed(window));
+    let mut samples = 0;
+    let mut span = window.as_secs_f64();
+    for occurrence in expr.after(&start) {
+        if end.is_some_and(|end| occurrence > end) {
+            break;
+        }
+        samples += 1;
+        if samples == MAX_SAMPLES {
+            span = ((occurrence - start).num_milliseconds() as f64 / 1000.0).max(1.0);
+            break;
+        }
+    }
+    let per_second = if span > 0.0 {
+        samples as f64 / span
+    } else {
+        0.0
+    };
+    Ok(FrequencyEstimate {
+        per_hour: per_second * 3600.0,
+        per_day: per_second * 86400.0,
+        samples,
+    })
+}
+
+#[test]
+fn test_estimate_frequency() {
+    let day = Duration::from_secs(24 * 3600);
+    let hourly = estimate_frequency("every hour", day).unwrap();
+    assert!((23..=24).contains(&hourly.samples));
+    assert!((hourly.per_hour - 1.0).abs() < 0.1);
+
+    let hot = estimate_frequency("* * * * * ? *", day).unwrap();
+    assert_eq!(hot.samples, MAX_SAMPLES);
+    assert!((hot.per_hour - 3600.0).abs() < 10.0);
+
+    let never = estimate_frequency("0 0 12 31 2 ? *", day).unwrap();
+    assert_eq!(never.per_day, 0.0);
+    assert!(estimate_frequency("every blue moon", day).is_err());
+}
@@ -0,0 +1,228 @@
+//! Await fires from async code, e.g. tasks of [bevy_defer](https://docs.rs/bevy_defer).
+//!
+//! The futures do not depend on an executor: they are woken by
+//! [`CronJobPlugin`](crate::CronJobPlugin) and can be awaited from any async runtime.
+
+use crate::{AnyScheduleArrived, CronClock, IntoCronExpression};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+/// Hands out futures resolving on fires, inserted by [`CronJobPlugin`](crate::CronJobPlugin)
+/// with the `async` feature. Clone it into async tasks.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::future::CronFutures;
+///
+/// async fn weather_loop(cron: CronFutures) {
+///     loop {
+///         let at = cron.next_fire("every 5 minutes").await;
+///         println!("weather changes at {at}");
+///     }
+/// }
+///
+/// fn start(cron: Res<CronFutures>) {
+///     let _task = weather_loop(cron.clone());
+///     // spawn the task on the async executor of the game
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CronFutures {
+    shared: Arc<Mutex<Waiters>>,
+}
+
+type Deadline = (DateTime<chrono::Local>, Arc<Slot<DateTime<chrono::Local>>>);
+
+#[derive(Debug, Default)]
+struct Waiters {
+    /// the clock time of the last frame
+    now: Option<DateTime<chrono::Local>>,
+    deadlines: Vec<Deadline>,
+    timers: Vec<(Entity, Arc<Slot<AnyScheduleArrived>>)>,
+}
+
+impl CronFutures {
+    /// Resolves with the next occurrence of `expression` once the [`CronClock`] passed it.
+    /// Never resolves when the expression has no upcoming occurrence.
+    pub fn next_fire(
+        &self,
+        expression: impl IntoCronExpression,
+    ) -> CronFuture<DateTime<chrono::Local>> {
+        let expr = expression
+            .into_cron_expr()
+            .expect("Failed to parse cron expression");
+        let now = self.lock().now.unwrap_or_else(chrono::Local::now);
+        let next = expr.after(&now).next();
+        match next {
+            Some(next) => self.at(next),
+            None => CronFuture::new(),
+        }
+    }
+
+    /// Resolves with `time` once the [`CronClock`] passed it
+    pub fn at(&self, time: DateTime<chrono::Local>) -> CronFuture<DateTime<chrono::Local>> {
+        let future = CronFuture::new();
+        self.lock().deadlines.push((time, future.slot.clone()));
+        future
+    }
+
+    /// Resolves with the next fire of the timer on `entity`
+    pub fn timer_fired(&self, entity: Entity) -> CronFuture<AnyScheduleArrived> {
+        let future = CronFuture::new();
+        self.lock().timers.push((entity, future.slot.clone()));
+        future
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Waiters> {
+        self.shared
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[derive(Debug)]
+struct Slot<T> {
+    state: Mutex<(Option<T>, Option<Waker>)>,
+}
+
+impl<T> Slot<T> {
+    fn complete(&self, value: T) {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.0 = Some(value);
+        if let Some(waker) = state.1.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A fire awaited through [`CronFutures`]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless awaited"]
+pub struct CronFuture<T> {
+    slot: Arc<Slot<T>>,
+}
+
+impl<T> CronFuture<T> {
+    fn new() -> Self {
+        Self {
+            slot: Arc::new(Slot {
+                state: Mutex::new((None, None)),
+            }),
+        }
+    }
+}
+
+impl<T> Future for CronFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self
+            .slot
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match state.0.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+pub(crate) fn wake_cron_futures(clock: Res<CronClock>, futures: Res<CronFutures>) {
+    let now = clock.now();
+    let mut waiters = futures.lock();
+    waiters.now = Some(now);
+    // futures dropped by their task are only referenced here
+    waiters.deadlines.retain(|(deadline, slot)| {
+        if *deadline <= now {
+            slot.complete(*deadline);
+            false
+        } else {
+            Arc::strong_count(slot) > 1
+        }
+    });
+    waiters
+        .timers
+        .retain(|(_, slot)| Arc::strong_count(slot) > 1);
+}
+
+pub(crate) fn complete_timer_futures(
+    trigger: Trigger<AnyScheduleArrived>,
+    futures: Option<Res<CronFutures>>,
+) {
+    let Some(futures) = futures else {
+        return;
+    };
+    let fire = trigger.event();
+    futures.lock().timers.retain(|(entity, slot)| {
+        if *entity == fire.entity {
+            slot.complete(fire.clone());
+            false
+        } else {
+            true
+        }
+    });
+}
+
+#[test]
+fn test_cron_futures() {
+    use crate::testing::cron_app;
+    use crate::{CronAppExt, ScheduleTimer};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::Wake;
+
+    struct CountWakes(AtomicUsize);
+
+    impl Wake for CountWakes {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let wakes = Arc::new(CountWakes(AtomicUsize::new(0)));
+    let waker = Waker::from(wakes.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut app = cron_app();
+    app.update();
+    let timer = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every 5 seconds"))
+        .id();
+    let cron = app.world().resource::<CronFutures>().clone();
+    let mut next = std::pin::pin!(cron.next_fire("every 10 seconds"));
+    let mut fired = std::pin::pin!(cron.timer_fired(timer));
+    assert!(next.as_mut().poll(&mut cx).is_pending());
+    assert!(fired.as_mut().poll(&mut cx).is_pending());
+
+    for _ in 0..7 {
+        app.advance_cron_time(std::time::Duration::from_secs(1));
+    }
+    assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+    let Poll::Ready(fire) = fired.as_mut().poll(&mut cx) else {
+        panic!("timer fire not delivered");
+    };
+    assert_eq!(fire.entity, timer);
+    assert!(next.as_mut().poll(&mut cx).is_pending());
+
+    for _ in 0..5 {
+        app.advance_cron_time(std::time::Duration::from_secs(1));
+    }
+    let Poll::Ready(at) = next.as_mut().poll(&mut cx) else {
+        panic!("deadline not reached");
+    };
+    assert_eq!(
+        at,
+        CronClock::ticks(1).now() + chrono::Duration::seconds(10)
+    );
+}
@@ -0,0 +1,371 @@
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+/// How long recorded fires are kept by [`CronHistory`] and [`FireHistory`]
+///
+/// Old fires are pruned automatically whenever a new one is recorded. The default keeps the last
+/// 1000 fires, [`HistoryRetention::UNBOUNDED`] keeps all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryRetention {
+    /// keep at most this many fires
+    pub max_entries: Option<usize>,
+    /// forget fires dispatched longer ago than this
+    pub max_age: Option<Duration>,
+}
+
+impl Default for HistoryRetention {
+    fn default() -> Self {
+        Self::max_entries(1000)
+    }
+}
+
+impl HistoryRetention {
+    /// Keep every fire forever
+    pub const UNBOUNDED: Self = Self {
+        max_entries: None,
+        max_age: None,
+    };
+
+    /// Keep at most `max_entries` fires
+    pub fn max_entries(max_entries: usize) -> Self {
+        Self {
+            max_entries: Some(max_entries),
+            max_age: None,
+        }
+    }
+
+    /// Forget fires older than `max_age`
+    pub fn max_age(max_age: Duration) -> Self {
+        Self {
+            max_entries: None,
+            max_age: Some(max_age),
+        }
+    }
+
+    /// Also forget fires older than `max_age`
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    fn prune<T>(
+        &self,
+        entries: &mut VecDeque<T>,
+        now: DateTime<chrono::Local>,
+        actual: impl Fn(&T) -> DateTime<chrono::Local>,
+    ) {
+        if let Some(max_entries) = self.max_entries {
+            while entries.len() > max_entries {
+                entries.pop_front();
+            }
+        }
+        if let Some(max_age) = self
+            .max_age
+            .and_then(|age| chrono::Duration::from_std(age).ok())
+        {
+            while entries
+                .front()
+                .is_some_and(|entry| now - actual(entry) > max_age)
+            {
+                entries.pop_front();
+            }
+        }
+    }
+}
+
+/// Scheduled and actual time of a single fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FireRecord {
+    /// the occurrence the timer was scheduled for
+    pub scheduled: DateTime<chrono::Local>,
+    /// when the fire was dispatched
+    pub actual: DateTime<chrono::Local>,
+}
+
+impl FireRecord {
+    /// how late the fire was dispatched
+    pub fn lateness(&self) -> chrono::Duration {
+        self.actual - self.scheduled
+    }
+}
+
+/// A fire recorded in [`CronHistory`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronHistoryEntry {
+    /// label of the timer that fired
+    pub label: Option<String>,
+    /// the timer entity
+    pub entity: Entity,
+    /// scheduled and actual fire time
+    pub record: FireRecord,
+}
+
+/// Opt-in log of every fire of every [`ScheduleTimer`](crate::ScheduleTimer).
+///
+/// Insert it with `app.init_resource::<CronHistory>()` and the plugin appends to it, which is
+/// handy for admin panels and post-incident analysis on servers.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct CronHistory {
+    entries: VecDeque<CronHistoryEntry>,
+    retention: HistoryRetention,
+}
+
+impl CronHistory {
+    /// Create an empty history pruned according to `retention`
+    ///
+    /// The default history keeps the last 1000 fires, pass [`HistoryRetention::UNBOUNDED`] to
+    /// keep all of them.
+    pub fn with_retention(retention: HistoryRetention) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            retention,
+        }
+    }
+
+    /// The retention policy
+    pub fn retention(&self) -> HistoryRetention {
+        self.retention
+    }
+
+    /// Change the retention policy, pruning immediately
+    pub fn set_retention(&mut self, retention: HistoryRetention) {
+        self.retention = retention;
+        self.prune(chrono::Local::now());
+    }
+
+    /// Record a fire, pruning old ones
+    pub fn push(&mut self, entry: CronHistoryEntry) {
+        let now = entry.record.actual;
+        self.entries.push_back(entry);
+        self.prune(now);
+    }
+
+    /// Drop fires not allowed by the retention policy anymore
+    pub fn prune(&mut self, now: DateTime<chrono::Local>) {
+        self.retention
+            .prune(&mut self.entries, now, |entry| entry.record.actual);
+    }
+
+    /// All recorded fires, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &CronHistoryEntry> {
+        self.entries.iter()
+    }
+
+    /// Fires of timers with the given label
+    pub fn by_label<'a>(&'a self, label: &'a str) -> impl Iterator<Item = &'a CronHistoryEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.label.as_deref() == Some(label))
+    }
+
+    /// Fires of the given timer entity
+    pub fn by_entity(&self, entity: Entity) -> impl Iterator<Item = &CronHistoryEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.entity == entity)
+    }
+
+    /// Fires dispatched within `start..end`
+    pub fn in_range(
+        &self,
+        start: DateTime<chrono::Local>,
+        end: DateTime<chrono::Local>,
+    ) -> impl Iterator<Item = &CronHistoryEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| (start..end).contains(&entry.record.actual))
+    }
+
+    /// Number of recorded fires
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing was recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forget all recorded fires
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Fixed-size ring buffer of the most recent fires of a timer
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FireHistory {
+    records: VecDeque<FireRecord>,
+    capacity: usize,
+    max_age: Option<Duration>,
+}
+
+impl FireHistory {
+    /// Create a buffer keeping the last `capacity` fires
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: VecDeque::with_capacity(capacity),
+            capacity,
+            max_age: None,
+        }
+    }
+
+    /// Also forget fires older than `max_age`
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Record a fire, dropping the oldest one when full
+    pub fn push(&mut self, record: FireRecord) {
+        self.records.push_back(record);
+        HistoryRetention {
+            max_entries: Some(self.capacity),
+            max_age: self.max_age,
+        }
+        .prune(&mut self.records, record.actual, |record| record.actual);
+    }
+
+    /// Recorded fires, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &FireRecord> {
+        self.records.iter()
+    }
+
+    /// The most recent fire
+    pub fn last(&self) -> Option<&FireRecord> {
+        self.records.back()
+    }
+
+    /// Number of recorded fires
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether nothing was recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Maximum number of recorded fires
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Age after which fires are forgotten, if any
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+}
+
+/// Last fire time of every labeled [`ScheduleTimer`](crate::ScheduleTimer).
+///
+/// The resource is optional: insert it (e.g. `app.init_resource::<LastCronFire>()`) and the
+/// plugin keeps it up to date, so any system can cheaply ask when a job last ran without access
+/// to its timer entity.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct LastCronFire {
+    fires: HashMap<String, DateTime<chrono::Local>>,
+}
+
+impl LastCronFire {
+    /// When the timer with the given label last fired
+    pub fn get(&self, label: &str) -> Option<DateTime<chrono::Local>> {
+        self.fires.get(label).copied()
+    }
+
+    /// Iterate over all labels and their last fire time
+    pub fn iter(&self) -> impl Iterator<Item = (&str, DateTime<chrono::Local>)> {
+        self.fires
+            .iter()
+            .map(|(label, datetime)| (label.as_str(), *datetime))
+    }
+
+    /// Record a fire of the timer with the given label
+    pub fn record(&mut self, label: &str, datetime: DateTime<chrono::Local>) {
+        match self.fires.get_mut(label) {
+            Some(last) => *last = datetime,
+            None => {
+                self.fires.insert(label.to_string(), datetime);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_last_cron_fire() {
+    use crate::testing::{cron_app, start};
+    use crate::{CronAppExt, CronClock, ScheduleTimer};
+
+    let mut app = cron_app();
+    app.init_resource::<LastCronFire>()
+        .init_resource::<CronHistory>();
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_label("daily_reset"));
+    app.update();
+    app.advance_cron_time(Duration::from_secs(3600));
+    app.world_mut().spawn(ScheduleTimer::new("every hour"));
+    app.update();
+
+    let last_fire = app.world().resource::<LastCronFire>();
+    assert!(last_fire.get("daily_reset").is_some());
+    assert_eq!(last_fire.iter().count(), 1);
+
+    let history = app.world().resource::<CronHistory>();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.by_label("daily_reset").count(), 1);
+    let now = app.world().resource::<CronClock>().now();
+    assert_eq!(history.in_range(start(), now).count(), 0);
+    assert_eq!(
+        history
+            .in_range(now, now + chrono::Duration::minutes(1))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_history_retention() {
+    let start = chrono::Local::now();
+    let entry = |seconds: i64| CronHistoryEntry {
+        label: None,
+        entity: Entity::PLACEHOLDER,
+        record: FireRecord {
+            scheduled: start + chrono::Duration::seconds(seconds),
+            actual: start + chrono::Duration::seconds(seconds),
+        },
+    };
+
+    let mut history = CronHistory::with_retention(HistoryRetention::max_entries(3));
+    for seconds in 0..10 {
+        history.push(entry(seconds));
+    }
+    assert_eq!(history.len(), 3);
+
+    // bounded unless opted out
+    let mut history = CronHistory::default();
+    for seconds in 0..1010 {
+        history.push(entry(seconds));
+    }
+    assert_eq!(history.len(), 1000);
+    history.set_retention(HistoryRetention::UNBOUNDED);
+    history.push(entry(1010));
+    assert_eq!(history.len(), 1001);
+
+    let mut history =
+        CronHistory::with_retention(HistoryRetention::max_age(Duration::from_secs(5)));
+    for seconds in 0..10 {
+        history.push(entry(seconds));
+    }
+    assert_eq!(
+        history.iter().next().unwrap().record.actual,
+        start + chrono::Duration::seconds(4)
+    );
+
+    let mut fires = FireHistory::new(5).with_max_age(Duration::from_secs(2));
+    for seconds in 0..10 {
+        fires.push(entry(seconds).record);
+    }
+    assert_eq!(fires.len(), 3);
+}
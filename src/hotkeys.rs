@@ -0,0 +1,224 @@
+use crate::{CronClock, ScheduleTimer};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::keyboard::KeyCode;
+use bevy_input::ButtonInput;
+use bevy_utils::tracing::info;
+use std::time::Duration;
+
+/// Binds debug keys to schedule manipulation, to iterate on hourly and daily content without
+/// waiting for it.
+///
+/// With the default [`CronDevHotkeys`] bindings:
+///
+/// | key | action |
+/// |-----|--------|
+/// | F5  | select the next timer label |
+/// | F6  | fast-forward the [`CronClock`] by an hour |
+/// | F7  | fast-forward the [`CronClock`] by a day |
+/// | F8  | fire the timers with the selected label now |
+/// | F9  | pause or resume all timers |
+///
+/// Requires [`CronJobPlugin`](crate::CronJobPlugin), keyboard input and the `dev` feature.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// App::new()
+///     .add_plugins((CronJobPlugin, CronDevHotkeysPlugin))
+///     .insert_resource(CronDevHotkeys::default().with_selected("daily-reset"));
+/// ```
+pub struct CronDevHotkeysPlugin;
+
+impl Plugin for CronDevHotkeysPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CronDevHotkeys>().add_systems(
+            PreUpdate,
+            handle_dev_hotkeys.run_if(resource_exists::<ButtonInput<KeyCode>>),
+        );
+    }
+}
+
+/// Key bindings and state of the [`CronDevHotkeysPlugin`]
+#[derive(Resource, Debug, Clone)]
+pub struct CronDevHotkeys {
+    /// selects the next label among the timers
+    pub select_next: KeyCode,
+    /// moves the clock forward by an hour
+    pub fast_forward_hour: KeyCode,
+    /// moves the clock forward by a day
+    pub fast_forward_day: KeyCode,
+    /// fires the timers with the selected label
+    pub fire_selected: KeyCode,
+    /// pauses all timers, or resumes the ones paused through it
+    pub toggle_pause: KeyCode,
+    /// label of the timers fired by [`CronDevHotkeys::fire_selected`]
+    pub selected: Option<String>,
+    /// the timers paused by [`CronDevHotkeys::toggle_pause`], `None` when not paused
+    paused: Option<Vec<Entity>>,
+}
+
+impl Default for CronDevHotkeys {
+    fn default() -> Self {
+        Self {
+            select_next: KeyCode::F5,
+            fast_forward_hour: KeyCode::F6,
+            fast_forward_day: KeyCode::F7,
+            fire_selected: KeyCode::F8,
+            toggle_pause: KeyCode::F9,
+            selected: None,
+            paused: None,
+        }
+    }
+}
+
+impl CronDevHotkeys {
+    /// Select the timers labeled `label`
+    pub fn with_selected(mut self, label: impl Into<String>) -> Self {
+        self.selected = Some(label.into());
+        self
+    }
+
+    /// Whether the timers are paused through [`CronDevHotkeys::toggle_pause`]
+    pub fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+}
+
+fn handle_dev_hotkeys(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut hotkeys: ResMut<CronDevHotkeys>,
+    mut clock: ResMut<CronClock>,
+    mut timers: Query<(Entity, &mut ScheduleTimer)>,
+) {
+    if keys.just_pressed(hotkeys.select_next) {
+        let mut labels: Vec<&str> = timers
+            .iter()
+            .filter_map(|(_, timer)| timer.label())
+            .collect();
+        labels.sort_unstable();
+        labels.dedup();
+        let next = match &hotkeys.selected {
+            Some(selected) => labels
+                .iter()
+                .find(|label| **label > selected.as_str())
+                .or(labels.first()),
+            None => labels.first(),
+        };
+        let next = next.map(|label| label.to_string());
+        info!(label = next.as_deref(), "cron dev hotkeys: selected");
+        hotkeys.selected = next;
+    }
+    for (key, by) in [
+        (hotkeys.fast_forward_hour, Duration::from_secs(60 * 60)),
+        (hotkeys.fast_forward_day, Duration::from_secs(24 * 60 * 60)),
+    ] {
+        if keys.just_pressed(key) {
+            clock.fast_forward(by);
+            info!(now = %clock.now(), "cron dev hotkeys: fast-forwarded by {by:?}");
+        }
+    }
+    if keys.just_pressed(hotkeys.fire_selected) {
+        if let Some(selected) = hotkeys.selected.as_deref() {
+            // a pending occurrence just passed fires on the next check
+            let due = clock.now() - chrono::Duration::nanoseconds(1);
+            for (_, mut timer) in timers.iter_mut() {
+                if timer.label() == Some(selected) && !timer.is_expired() {
+                    timer.local_schedule = Some(due);
+                }
+            }
+            info!(label = selected, "cron dev hotkeys: fired");
+        }
+    }
+    if keys.just_pressed(hotkeys.toggle_pause) {
+        match hotkeys.paused.take() {
+            Some(paused) => {
+                let mut iter = timers.iter_many_mut(&paused);
+                while let Some((_, mut timer)) = iter.fetch_next() {
+                    timer.resume();
+                }
+                info!("cron dev hotkeys: resumed");
+            }
+            None => {
+                let mut paused = vec![];
+                for (entity, mut timer) in timers.iter_mut() {
+                    if !timer.is_paused() {
+                        timer.pause();
+                        paused.push(entity);
+                    }
+                }
+                hotkeys.paused = Some(paused);
+                info!("cron dev hotkeys: paused");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dev_hotkeys() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::ScheduleArrived;
+
+    let mut app = cron_app();
+    app.add_plugins(CronDevHotkeysPlugin)
+        .init_resource::<ButtonInput<KeyCode>>()
+        .record::<ScheduleArrived>();
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_label("hourly"));
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every day").with_label("daily"));
+    let mut user_paused = ScheduleTimer::new("every hour");
+    user_paused.pause();
+    let user_paused = app.world_mut().spawn(user_paused).id();
+    // off the hour, so fast-forwarding passes the occurrences instead of landing on them
+    app.update();
+
+    let press = |app: &mut App, key: KeyCode| {
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release_all();
+        keys.clear();
+        keys.press(key);
+        app.update();
+    };
+    let fired = |app: &App| {
+        app.triggered::<ScheduleArrived>()
+            .iter()
+            .filter_map(|(_, fire)| fire.label.clone())
+            .collect::<Vec<_>>()
+    };
+
+    press(&mut app, KeyCode::F6);
+    assert_eq!(fired(&app), ["hourly"]);
+    press(&mut app, KeyCode::F7);
+    assert_eq!(fired(&app).len(), 3);
+    assert!(fired(&app).contains(&"daily".to_string()));
+
+    // labels are selected in order, wrapping around
+    press(&mut app, KeyCode::F5);
+    press(&mut app, KeyCode::F5);
+    assert_eq!(
+        app.world().resource::<CronDevHotkeys>().selected.as_deref(),
+        Some("hourly")
+    );
+    press(&mut app, KeyCode::F8);
+    press(&mut app, KeyCode::F5);
+    assert_eq!(
+        app.world().resource::<CronDevHotkeys>().selected.as_deref(),
+        Some("daily")
+    );
+    assert_eq!(fired(&app).len(), 4);
+
+    press(&mut app, KeyCode::F9);
+    assert!(app.world().resource::<CronDevHotkeys>().is_paused());
+    press(&mut app, KeyCode::F7);
+    assert_eq!(fired(&app).len(), 4);
+    press(&mut app, KeyCode::F9);
+    assert!(!app.world().resource::<CronDevHotkeys>().is_paused());
+    // timers paused before stay paused
+    assert!(app
+        .world()
+        .get::<ScheduleTimer>(user_paused)
+        .unwrap()
+        .is_paused());
+}
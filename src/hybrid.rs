@@ -0,0 +1,188 @@
+use crate::{
+    AnyScheduleArrived, CronClock, CronExpr, GameClock, GameSchedule, IntoCronExpression,
+    ScheduleArrived,
+};
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::debug;
+use chrono::DateTime;
+
+/// Which clock fired a [`HybridTimer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HybridClock {
+    /// the real-time cron expression, following the [`CronClock`]
+    Real,
+    /// the in-world schedule, following the [`GameClock`]
+    Game,
+}
+
+/// A timer firing on whichever comes first of a real-time cron expression and a
+/// [`GameSchedule`], e.g. every real hour or every game day.
+///
+/// After each fire both clocks start over. Besides [`ScheduleArrived`] and
+/// [`AnyScheduleArrived`], a [`HybridScheduleArrived`] tells which clock fired. Without a
+/// [`GameClock`] resource only the real-time expression fires.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn(HybridTimer::new("every hour", GameSchedule::daily(0, 0)).with_label("harvest"))
+///         .observe(|trigger: Trigger<HybridScheduleArrived>| {
+///             info!("harvest ready, thanks to the {:?} clock", trigger.event().clock);
+///         });
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct HybridTimer {
+    /// the real-time schedule
+    pub real: CronExpr,
+    /// the in-world schedule
+    pub game: GameSchedule,
+    /// name identifying the job
+    pub label: Option<String>,
+    /// next planned real-time fire
+    pub next_real: Option<DateTime<chrono::Local>>,
+    /// next planned in-world fire, in seconds of the [`GameClock`]
+    pub next_game: Option<u64>,
+}
+
+impl HybridTimer {
+    /// Fire on whichever of `real` and `game` comes first
+    pub fn new(real: impl IntoCronExpression, game: GameSchedule) -> Self {
+        Self {
+            real: real
+                .into_cron_expr()
+                .expect("Failed to parse cron expression"),
+            game,
+            label: None,
+            next_real: None,
+            next_game: None,
+        }
+    }
+
+    /// Name the job, the label is passed along with the fired events
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Whether [`poll`](Self::poll) would change the timer
+    fn is_due(&self, now: DateTime<chrono::Local>, game: Option<&GameClock>) -> bool {
+        self.next_real.is_none_or(|next| now > next)
+            || game.is_some_and(|game| self.next_game.is_none_or(|next| game.seconds() >= next))
+    }
+
+    /// Advance the timer, returning the clock that fired and the real time of the occurrence
+    fn poll(
+        &mut self,
+        now: DateTime<chrono::Local>,
+        game: Option<&GameClock>,
+    ) -> Option<(HybridClock, DateTime<chrono::Local>)> {
+        let game_now = game.map(GameClock::seconds);
+        let fired = match self.next_real {
+            Some(next) if now > next => Some((HybridClock::Real, next)),
+            _ if self
+                .next_game
+                .is_some_and(|next| game_now.is_some_and(|game_now| game_now >= next)) =>
+            {
+                Some((HybridClock::Game, now))
+            }
+            _ => None,
+        };
+        if fired.is_some() || self.next_real.is_none() {
+            self.next_real = self.real.after(&now).next();
+        }
+        if let (Some(game), Some(game_now)) = (game, game_now) {
+            if fired.is_some() || self.next_game.is_none() {
+                self.next_game = self.game.next_after(game.calendar(), game_now);
+            }
+        }
+        fired
+    }
+}
+
+/// Triggered on a [`HybridTimer`] entity when it fires, along with [`ScheduleArrived`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct HybridScheduleArrived {
+    /// label of the timer
+    pub label: Option<String>,
+    /// the clock that fired
+    pub clock: HybridClock,
+}
+
+pub(crate) fn check_hybrid_timers(
+    clock: Res<CronClock>,
+    game: Option<Res<GameClock>>,
+    mut query: Query<(Entity, &mut HybridTimer)>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    for (entity, mut timer) in query.iter_mut() {
+        // only take the timer mutably when it changes
+        if !timer.is_due(now, game.as_deref()) {
+            continue;
+        }
+        let Some((fired_by, scheduled)) = timer.poll(now, game.as_deref()) else {
+            continue;
+        };
+        debug!(
+            label = timer.label.as_deref(),
+            ?entity,
+            ?fired_by,
+            "hybrid timer fired"
+        );
+        commands.trigger_targets(
+            HybridScheduleArrived {
+                label: timer.label.clone(),
+                clock: fired_by,
+            },
+            entity,
+        );
+        commands.trigger(AnyScheduleArrived {
+            entity,
+            label: timer.label.clone(),
+            scheduled,
+            actual: now,
+        });
+        commands.trigger_targets(
+            ScheduleArrived {
+                label: timer.label.clone(),
+                scheduled,
+                actual: now,
+                lateness: (now - scheduled).to_std().unwrap_or_default(),
+            },
+            entity,
+        );
+    }
+}
+
+#[test]
+fn test_hybrid_timer() {
+    use crate::SimpleCalendar;
+    use std::time::Duration;
+
+    let calendar = SimpleCalendar::new(12, 30);
+    let mut game = GameClock::new(calendar);
+    let now = chrono::Local::now();
+    let mut timer = HybridTimer::new("every hour", GameSchedule::daily(0, 0));
+    assert_eq!(timer.poll(now, Some(&game)), None);
+
+    // a game day passes within a few real seconds
+    game.advance(Duration::from_secs(24 * 3600));
+    let soon = now + chrono::Duration::seconds(5);
+    assert_eq!(
+        timer.poll(soon, Some(&game)),
+        Some((HybridClock::Game, soon))
+    );
+    assert_eq!(timer.poll(soon, Some(&game)), None);
+
+    // then the game is idle while a real hour passes
+    let later = now + chrono::Duration::hours(2);
+    assert!(matches!(
+        timer.poll(later, Some(&game)),
+        Some((HybridClock::Real, scheduled)) if scheduled < later
+    ));
+    assert_eq!(timer.poll(later, None), None);
+}
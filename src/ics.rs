@@ -0,0 +1,459 @@
+use crate::{CronClock, CronExpr, CronTimezone, CronTimezones, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike, Weekday};
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Timers created from an iCalendar file by [`import_ics`]
+#[derive(Debug, Clone, Default)]
+pub struct IcsImport {
+    /// one timer per importable event, labeled with its summary
+    pub timers: Vec<ScheduleTimer>,
+    /// events that could not be turned into a timer
+    pub skipped: Vec<IcsSkipped>,
+}
+
+impl IcsImport {
+    /// Spawn the imported timers
+    pub fn spawn(self, commands: &mut Commands) -> Vec<Entity> {
+        self.timers
+            .into_iter()
+            .map(|timer| commands.spawn(timer).id())
+            .collect()
+    }
+}
+
+/// An event of an iCalendar file without timer, see [`IcsImport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsSkipped {
+    /// summary of the event
+    pub summary: Option<String>,
+    /// why it was skipped
+    pub reason: String,
+}
+
+/// Create timers from the `VEVENT`s of an iCalendar (`.ics`) file, so calendars maintained
+/// outside the game can drive in-game schedules.
+///
+/// Events without `RRULE` become one-shot timers on their start. Recurring events are supported
+/// for the `HOURLY`, `DAILY`, `WEEKLY`, `MONTHLY` and `YEARLY` frequencies with an `INTERVAL` of
+/// 1, including `BYDAY` weekdays and monthly rules such as `BYDAY=2TU` or `BYDAY=-1FR`. Start
+/// times in UTC and with a `TZID` keep their timezone, the latter needs the `timezone` feature
+/// for names other than fixed offsets. Other events are listed in [`IcsImport::skipped`].
+///
+/// ```rust
+/// use bevy_cronjob::import_ics;
+///
+/// let calendar = "BEGIN:VCALENDAR\r
+/// BEGIN:VEVENT\r
+/// SUMMARY:Raid night\r
+/// DTSTART:20300108T190000\r
+/// RRULE:FREQ=MONTHLY;BYDAY=2TU\r
+/// END:VEVENT\r
+/// END:VCALENDAR\r
+/// ";
+/// let import = import_ics(calendar);
+/// assert_eq!(import.timers[0].label(), Some("Raid night"));
+/// assert_eq!(import.timers[0].schedule.to_string(), "0 0 19 8-14 * TUE *");
+/// ```
+pub fn import_ics(ics: &str) -> IcsImport {
+    let mut import = IcsImport::default();
+    let mut event: Option<Vec<IcsProperty>> = None;
+    for line in unfold(ics) {
+        match line.as_str() {
+            "BEGIN:VEVENT" => event = Some(vec![]),
+            "END:VEVENT" => {
+                let Some(properties) = event.take() else {
+                    continue;
+                };
+                let summary =
+                    property(&properties, "SUMMARY").map(|summary| unescape(&summary.value));
+                match event_timer(&properties) {
+                    Ok(timer) => import.timers.push(match &summary {
+                        Some(summary) => timer.with_label(summary.clone()),
+                        None => timer,
+                    }),
+                    Err(reason) => import.skipped.push(IcsSkipped { summary, reason }),
+                }
+            }
+            _ => {
+                if let (Some(properties), Some(property)) =
+                    (event.as_mut(), IcsProperty::parse(&line))
+                {
+                    properties.push(property);
+                }
+            }
+        }
+    }
+    import
+}
+
+/// A content line: `NAME;PARAM=VALUE:value`
+struct IcsProperty {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl IcsProperty {
+    fn parse(line: &str) -> Option<Self> {
+        // the value starts at the first colon outside of quoted parameter values
+        let mut quoted = false;
+        let colon = line.find(|c| {
+            quoted ^= c == '"';
+            c == ':' && !quoted
+        })?;
+        let (head, value) = (&line[..colon], &line[colon + 1..]);
+        let mut parts = head.split(';');
+        let name = parts.next()?.to_ascii_uppercase();
+        let params = parts
+            .filter_map(|param| param.split_once('='))
+            .map(|(key, value)| {
+                (
+                    key.to_ascii_uppercase(),
+                    value.trim_matches('"').to_string(),
+                )
+            })
+            .collect();
+        Some(Self {
+            name,
+            params,
+            value: value.to_string(),
+        })
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn property<'a>(properties: &'a [IcsProperty], name: &str) -> Option<&'a IcsProperty> {
+    properties.iter().find(|property| property.name == name)
+}
+
+/// Join folded lines, which continue on lines starting with a space or tab
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for line in ics.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("\\n", "\n")
+        .replace("\\N", "\n")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+fn event_timer(properties: &[IcsProperty]) -> Result<ScheduleTimer, String> {
+    let start = property(properties, "DTSTART").ok_or("missing DTSTART")?;
+    let (value, utc) = match start.value.strip_suffix('Z') {
+        Some(value) => (value, true),
+        None => (start.value.as_str(), false),
+    };
+    let start_time = if start.param("VALUE") == Some("DATE") || value.len() == 8 {
+        NaiveDate::parse_from_str(value, "%Y%m%d").map(|date| date.and_time(chrono::NaiveTime::MIN))
+    } else {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+    }
+    .map_err(|err| format!("invalid DTSTART `{}`: {err}", start.value))?;
+    let timezone = match (utc, start.param("TZID")) {
+        (true, _) => Some(CronTimezone::Utc),
+        (false, Some(tzid)) => Some(CronTimezone::from_str(tzid).map_err(|err| err.to_string())?),
+        (false, None) => None,
+    };
+
+    let expression = match property(properties, "RRULE") {
+        None => format!(
+            "{} {} {} {} {} ? {}",
+            start_time.second(),
+            start_time.minute(),
+            start_time.hour(),
+            start_time.day(),
+            start_time.month(),
+            start_time.year()
+        ),
+        Some(rule) => recurring_expression(&rule.value, &start_time)?,
+    };
+    let expr = CronExpr::parse(&expression).map_err(|err| err.to_string())?;
+    let timer = ScheduleTimer::new(expr);
+    Ok(match timezone {
+        Some(timezone) => timer.with_timezone(timezone),
+        None => timer,
+    })
+}
+
+fn recurring_expression(rule: &str, start: &NaiveDateTime) -> Result<String, String> {
+    let mut frequency = None;
+    let mut by_day = None;
+    for part in rule.split(';') {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| format!("invalid RRULE part `{part}`"))?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => frequency = Some(value.to_ascii_uppercase()),
+            "BYDAY" => by_day = Some(value.to_ascii_uppercase()),
+            "INTERVAL" if value == "1" => {}
+            "WKST" => {}
+            key => return Err(format!("unsupported RRULE part `{key}`")),
+        }
+    }
+    let (second, minute, hour) = (start.second(), start.minute(), start.hour());
+    let weekday = |day: &str| match day {
+        "MO" => Ok("MON"),
+        "TU" => Ok("TUE"),
+        "WE" => Ok("WED"),
+        "TH" => Ok("THU"),
+        "FR" => Ok("FRI"),
+        "SA" => Ok("SAT"),
+        "SU" => Ok("SUN"),
+        _ => Err(format!("invalid BYDAY `{day}`")),
+    };
+    let start_weekday = || match start.weekday() {
+        Weekday::Mon => "MON",
+        Weekday::Tue => "TUE",
+        Weekday::Wed => "WED",
+        Weekday::Thu => "THU",
+        Weekday::Fri => "FRI",
+        Weekday::Sat => "SAT",
+        Weekday::Sun => "SUN",
+    };
+    let (day, month) = (start.day(), start.month());
+    Ok(match (frequency.as_deref(), by_day.as_deref()) {
+        (Some("HOURLY"), None) => format!("{second} {minute} * * * ? *"),
+        (Some("DAILY"), None) => format!("{second} {minute} {hour} * * ? *"),
+        (Some("WEEKLY"), None) => format!("{second} {minute} {hour} ? * {} *", start_weekday()),
+        (Some("WEEKLY"), Some(days)) => {
+            let days = days
+                .split(',')
+                .map(weekday)
+                .collect::<Result<Vec<_>, _>>()?;
+            format!("{second} {minute} {hour} ? * {} *", days.join(","))
+        }
+        (Some("MONTHLY"), None) => format!("{second} {minute} {hour} {day} * ? *"),
+        (Some("MONTHLY"), Some(by_day)) => {
+            let split = by_day.len().saturating_sub(2);
+            let (nth, day) = by_day.split_at(split);
+            let day = weekday(day)?;
+            match nth {
+                "1" | "+1" | "2" | "+2" | "3" | "+3" | "4" | "+4" => format!(
+                    "{second} {minute} {hour} ? * {day}#{} *",
+                    nth.trim_start_matches('+')
+                ),
+                "-1" => format!("{second} {minute} {hour} ? * {day}L *"),
+                _ => return Err(format!("unsupported BYDAY `{by_day}`")),
+            }
+        }
+        (Some("YEARLY"), None) => format!("{second} {minute} {hour} {day} {month} ? *"),
+        (Some(frequency), _) => return Err(format!("unsupported RRULE frequency `{frequency}`")),
+        (None, _) => return Err("RRULE without FREQ".to_string()),
+    })
+}
+
+/// Export the next `count` occurrences of the timers matching `filter` as an iCalendar
+/// (`.ics`) file, so the in-game event schedule can be subscribed to from calendar apps.
+///
+/// Every occurrence becomes a `VEVENT` in UTC, summarized with the label of its timer or its
+/// schedule. Pending occurrences count as the first ones.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// let mut world = World::new();
+/// world.spawn(ScheduleTimer::new("0 0 20 ? * SAT *").with_label("Tournament"));
+/// world.spawn(ScheduleTimer::new("every 5 seconds"));
+/// let ics = export_ics(&mut world, 4, |timer| timer.label().is_some());
+/// assert_eq!(ics.matches("SUMMARY:Tournament").count(), 4);
+/// ```
+pub fn export_ics(
+    world: &mut World,
+    count: usize,
+    filter: impl Fn(&ScheduleTimer) -> bool,
+) -> String {
+    let now = world
+        .get_resource::<CronClock>()
+        .map_or_else(chrono::Local::now, CronClock::now);
+    let timezones = world.get_resource::<CronTimezones>().cloned();
+    let stamp = ics_time(&now);
+    let mut out = String::new();
+    out.push_str("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//bevy_cronjob//EN\r\n");
+    for (entity, timer) in world.query::<(Entity, &ScheduleTimer)>().iter(world) {
+        if !filter(timer) {
+            continue;
+        }
+        let timezone = match &timezones {
+            Some(timezones) => timezones.resolve(timer),
+            None => timer.timezone.unwrap_or_default(),
+        };
+        let summary = timer
+            .label
+            .clone()
+            .unwrap_or_else(|| timer.schedule.to_string());
+        let mut schedule = timer.schedule.clone();
+        let mut next = timer
+            .local_schedule
+            .or_else(|| schedule.next_after_in(&now, timezone));
+        let mut exported = 0;
+        // bounded, so timers on excluded dates do not search forever
+        for _ in 0..count.saturating_mul(8).max(64) {
+            let (Some(occurrence), true) = (next, exported < count) else {
+                break;
+            };
+            if !timer.is_excluded(timezone.date_of(&occurrence)) {
+                let start = ics_time(&occurrence);
+                let _ = write!(
+                    out,
+                    "BEGIN:VEVENT\r\nUID:{entity}-{start}@bevy_cronjob\r\nDTSTAMP:{stamp}\r\nDTSTART:{start}\r\n{}\r\nEND:VEVENT\r\n",
+                    fold(&format!("SUMMARY:{}", escape(&summary))),
+                );
+                exported += 1;
+            }
+            next = schedule.next_after_in(&occurrence, timezone);
+        }
+    }
+    out.push_str("END:VCALENDAR\r\n");
+    out
+}
+
+fn ics_time(time: &DateTime<chrono::Local>) -> String {
+    time.with_timezone(&chrono::Utc)
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold lines longer than 75 bytes onto continuation lines starting with a space
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            length = 1;
+        }
+        out.push(c);
+        length += c.len_utf8();
+    }
+    out
+}
+
+#[test]
+fn test_import_ics() {
+    let calendar = "BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+UID:1
+SUMMARY:Launch party\\, all servers
+DTSTART:20300101T180000Z
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Weekly
+  tournament
+DTSTART;TZID=\"+02:00\":20300105T200000
+RRULE:FREQ=WEEKLY;BYDAY=SA,SU
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Payday
+DTSTART;VALUE=DATE:20300131
+RRULE:FREQ=MONTHLY;BYDAY=-1FR
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Daily
+DTSTART:20300101T043000
+RRULE:FREQ=DAILY
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Every other day
+DTSTART:20300101T043000
+RRULE:FREQ=DAILY;INTERVAL=2
+END:VEVENT
+END:VCALENDAR
+";
+    let import = import_ics(calendar);
+    let timers = import
+        .timers
+        .iter()
+        .map(|timer| {
+            (
+                timer.label().unwrap().to_string(),
+                timer.schedule.to_string(),
+                timer.timezone,
+            )
+        })
+        .collect::<Vec<_>>();
+    let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+    assert_eq!(
+        timers,
+        [
+            (
+                "Launch party, all servers".to_string(),
+                "0 0 18 1 1 ? 2030".to_string(),
+                Some(CronTimezone::Utc)
+            ),
+            (
+                "Weekly tournament".to_string(),
+                "0 0 20 ? * SAT,SUN *".to_string(),
+                Some(CronTimezone::Fixed(offset))
+            ),
+            (
+                "Payday".to_string(),
+                "0 0 0 22-31 * FRIL *".to_string(),
+                None
+            ),
+            ("Daily".to_string(), "0 30 4 * * ? *".to_string(), None),
+        ]
+    );
+    assert_eq!(
+        import.skipped,
+        [IcsSkipped {
+            summary: Some("Every other day".to_string()),
+            reason: "unsupported RRULE part `INTERVAL`".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_export_ics() {
+    use chrono::TimeZone;
+
+    let mut world = World::new();
+    let epoch = chrono::Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+    world.insert_resource(CronClock::ticks(1).with_epoch(epoch.with_timezone(&chrono::Local)));
+    world.spawn(
+        ScheduleTimer::new("0 0 20 ? * SAT *")
+            .with_label("Tournament, finals")
+            .with_timezone(CronTimezone::Utc),
+    );
+    world.spawn(ScheduleTimer::new("every hour").with_label("hidden"));
+
+    let ics = export_ics(&mut world, 2, |timer| timer.label() != Some("hidden"));
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+    assert!(ics.contains("DTSTART:20300105T200000Z\r\n"));
+    assert!(ics.contains("DTSTART:20300112T200000Z\r\n"));
+    assert!(ics.contains("SUMMARY:Tournament\\, finals\r\n"));
+
+    // the export reads back as one-shot timers
+    let import = import_ics(&ics);
+    assert_eq!(import.timers.len(), 2);
+    assert_eq!(import.timers[0].schedule.to_string(), "0 0 20 5 1 ? 2030");
+    assert_eq!(import.timers[0].label(), Some("Tournament, finals"));
+}
@@ -0,0 +1,176 @@
+use crate::{
+    CronClock, CronConditionRegistry, CronScheduler, CronSlab, HybridTimer, ScheduleTimer,
+};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::time::Duration;
+
+/// Puts the app to sleep at the end of every frame until the next fire, so headless servers
+/// running hourly jobs do not spin at 60 Hz.
+///
+/// With this resource present, [`CronJobPlugin`](crate::CronJobPlugin) sleeps in [`Last`]
+/// until the earliest upcoming fire of [`ScheduleTimer`]s, [`HybridTimer`]s, the
+/// [`CronScheduler`] and the [`CronSlab`], but never longer than `max_sleep` so the app stays
+/// responsive to other work. [`schedule_passed`](crate::schedule_passed) conditions are only
+/// taken into account with the [`CronConditionRegistry`]. Tick [`CronClock`]s do not sleep.
+///
+/// ```rust,no_run
+/// use bevy::app::ScheduleRunnerPlugin;
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use std::time::Duration;
+///
+/// App::new()
+///     .add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::ZERO)))
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(CronIdle::new(Duration::from_secs(5)))
+///     .run();
+/// ```
+///
+/// [`Last`]: bevy_app::Last
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct CronIdle {
+    /// longest sleep of a single frame
+    pub max_sleep: Duration,
+}
+
+impl CronIdle {
+    /// Sleep at most `max_sleep` per frame
+    pub fn new(max_sleep: Duration) -> Self {
+        Self { max_sleep }
+    }
+}
+
+impl Default for CronIdle {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1))
+    }
+}
+
+/// Time until the earliest upcoming fire of [`ScheduleTimer`]s, [`HybridTimer`]s, the
+/// [`CronSlab`], the [`CronScheduler`] and tracked conditions, `None` when nothing is scheduled. Timers waiting to be
+/// armed count as due now.
+///
+/// Headless apps driving their own loop can sleep for the returned duration.
+pub fn time_until_next_fire(world: &mut World) -> Option<Duration> {
+    let now = world.get_resource::<CronClock>()?.now();
+    let mut fires = world
+        .query::<&ScheduleTimer>()
+        .iter(world)
+        .map(|timer| timer_next_fire(timer, now))
+        .collect::<Vec<_>>();
+    fires.extend(
+        world
+            .query::<&HybridTimer>()
+            .iter(world)
+            .map(|timer| Some(timer.next_real.unwrap_or(now))),
+    );
+    if let Some(scheduler) = world.get_resource::<CronScheduler>() {
+        fires.extend(scheduler.timers().map(|timer| timer_next_fire(timer, now)));
+    }
+    let next = earliest(
+        fires,
+        world.get_resource::<CronSlab>(),
+        world.get_resource::<CronConditionRegistry>(),
+        now,
+    )?;
+    Some((next - now).to_std().unwrap_or_default())
+}
+
+fn timer_next_fire(
+    timer: &ScheduleTimer,
+    now: DateTime<chrono::Local>,
+) -> Option<DateTime<chrono::Local>> {
+    if timer.is_paused() || timer.is_expired() {
+        return None;
+    }
+    Some(timer.local_schedule.unwrap_or(now))
+}
+
+fn earliest(
+    fires: impl IntoIterator<Item = Option<DateTime<chrono::Local>>>,
+    slab: Option<&CronSlab>,
+    registry: Option<&CronConditionRegistry>,
+    now: DateTime<chrono::Local>,
+) -> Option<DateTime<chrono::Local>> {
+    let slab = slab.map(|slab| {
+        if slab.has_unarmed() {
+            Some(now)
+        } else {
+            slab.earliest_fire()
+        }
+    });
+    let conditions = registry
+        .map(|registry| registry.entries())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| entry.next_fire);
+    fires
+        .into_iter()
+        .chain(slab)
+        .chain(conditions)
+        .flatten()
+        .min()
+}
+
+pub(crate) fn sleep_until_next_fire(
+    idle: Res<CronIdle>,
+    clock: Res<CronClock>,
+    timers: Query<&ScheduleTimer>,
+    hybrids: Query<&HybridTimer>,
+    scheduler: Option<Res<CronScheduler>>,
+    slab: Option<Res<CronSlab>>,
+    registry: Option<Res<CronConditionRegistry>>,
+) {
+    if !clock.is_real_time() {
+        return;
+    }
+    let now = clock.now();
+    let fires = timers
+        .iter()
+        .map(|timer| timer_next_fire(timer, now))
+        .chain(
+            hybrids
+                .iter()
+                .map(|timer| Some(timer.next_real.unwrap_or(now))),
+        )
+        .chain(
+            scheduler
+                .iter()
+                .flat_map(|scheduler| scheduler.timers())
+                .map(|timer| timer_next_fire(timer, now)),
+        );
+    let sleep =
+        earliest(fires, slab.as_deref(), registry.as_deref(), now).map_or(idle.max_sleep, |next| {
+            (next - now)
+                .to_std()
+                .unwrap_or_default()
+                .min(idle.max_sleep)
+        });
+    if !sleep.is_zero() {
+        std::thread::sleep(sleep);
+    }
+}
+
+#[test]
+fn test_time_until_next_fire() {
+    let mut world = World::new();
+    world.init_resource::<CronClock>();
+    assert_eq!(time_until_next_fire(&mut world), None);
+
+    let now = chrono::Local::now();
+    let mut hourly = ScheduleTimer::new("every hour");
+    hourly.local_schedule = Some(now + chrono::Duration::minutes(30));
+    world.spawn(hourly);
+    let mut paused = ScheduleTimer::new("every second");
+    paused.local_schedule = Some(now + chrono::Duration::seconds(1));
+    paused.pause();
+    world.spawn(paused);
+    let until = time_until_next_fire(&mut world).unwrap();
+    assert!(until > Duration::from_secs(29 * 60) && until <= Duration::from_secs(30 * 60));
+
+    let mut slab = CronSlab::default();
+    slab.insert("every second", 0).unwrap();
+    world.insert_resource(slab);
+    assert_eq!(time_until_next_fire(&mut world), Some(Duration::ZERO));
+}
@@ -0,0 +1,81 @@
+use crate::{CronTimezone, ScheduleTimer};
+use chrono::DateTime;
+use std::time::Duration;
+
+impl ScheduleTimer {
+    /// Offset each fire by a random amount within `±jitter`, drawn per timer, so that many
+    /// timers on the same schedule spread their fires over several frames.
+    ///
+    /// The occurrences themselves are not moved: the next one is still searched from the
+    /// occurrence that fired. Frame and step schedules are not jittered.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let timer = ScheduleTimer::new("every minute").with_jitter(Duration::from_secs(5));
+    /// ```
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = Some(jitter).filter(|jitter| !jitter.is_zero());
+        self
+    }
+
+    /// The occurrence after `after` in `timezone`, offset by a new random jitter
+    pub(crate) fn next_jittered(
+        &mut self,
+        after: &DateTime<chrono::Local>,
+        timezone: CronTimezone,
+    ) -> Option<DateTime<chrono::Local>> {
+        let next = self.schedule.next_after_in(after, timezone)?;
+        self.jitter_offset = match self.jitter {
+            Some(jitter) => random_offset(&mut self.jitter_rng, jitter),
+            None => chrono::Duration::zero(),
+        };
+        next.checked_add_signed(self.jitter_offset)
+    }
+}
+
+/// A random offset within `±jitter`
+pub(crate) fn random_offset(rng: &mut fastrand::Rng, jitter: Duration) -> chrono::Duration {
+    let nanos = i64::try_from(jitter.as_nanos()).unwrap_or(i64::MAX);
+    chrono::Duration::nanoseconds(rng.i64(-nanos..=nanos))
+}
+
+#[test]
+fn test_jitter() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{CronAppExt, CronClock, ScheduleArrived};
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>();
+    for seed in 0..20 {
+        app.world_mut().spawn(
+            ScheduleTimer::new("every minute")
+                .with_jitter(Duration::from_secs(10))
+                .with_seed(seed),
+        );
+    }
+    app.update();
+    for _ in 0..29 {
+        app.advance_cron_time(Duration::from_secs(5));
+    }
+
+    // 1 s to 146 s: every timer fires for the minutes 1 and 2, once each
+    let fired = app
+        .triggered::<ScheduleArrived>()
+        .iter()
+        .map(|(_, fire)| fire.scheduled)
+        .collect::<Vec<_>>();
+    assert_eq!(fired.len(), 40);
+    let epoch = CronClock::ticks(1).now();
+    let minute = chrono::Duration::minutes(1);
+    let jitter = chrono::Duration::seconds(10);
+    assert!(fired.iter().all(|scheduled| {
+        (epoch + minute - jitter..=epoch + minute + jitter).contains(scheduled)
+            || (epoch + minute * 2 - jitter..=epoch + minute * 2 + jitter).contains(scheduled)
+    }));
+    let mut spread = fired.clone();
+    spread.sort_unstable();
+    spread.dedup();
+    assert!(spread.len() > 2);
+}
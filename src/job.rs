@@ -0,0 +1,78 @@
+use crate::ScheduleTimer;
+use bevy_ecs::prelude::*;
+
+/// A job of the catalog, declared on a marker component with `#[derive(CronJob)]`.
+///
+/// The `#[cron(...)]` attribute takes the `expression` and optionally a `label`, any number of
+/// `tag`s, a `timezone`, the `alignment` (`"actual_fire"` or `"schedule"`), the `month_end`
+/// policy (`"skip"` or `"clamp"`) and the fire `history` capacity. Spawn
+/// [`bundle`](Self::bundle) or register the job with
+/// [`CronAppExt::add_cron_job`](crate::CronAppExt::add_cron_job).
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(Component, CronJob, Default)]
+/// #[cron(expression = "every hour", label = "autosave", tag = "persistence", history = 8)]
+/// struct Autosave;
+///
+/// #[derive(Component, CronJob, Default)]
+/// #[cron(expression = "0 0 4 * * ? *", label = "daily-reset", timezone = "UTC")]
+/// struct DailyReset;
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .add_cron_job::<Autosave>()
+///     .add_cron_job::<DailyReset>();
+/// ```
+pub trait CronJob: Component + Sized {
+    /// the cron or english expression
+    const EXPRESSION: &'static str;
+    /// the label of the timer
+    const LABEL: Option<&'static str>;
+
+    /// A timer with the declared schedule and policies
+    fn timer() -> ScheduleTimer;
+
+    /// The job component together with its timer, ready to spawn
+    fn bundle(self) -> (Self, ScheduleTimer) {
+        (self, Self::timer())
+    }
+}
+
+#[test]
+fn test_derive_cron_job() {
+    use crate::{CronTimezone, MonthEndPolicy, ScheduleAlignment};
+    use bevy_cronjob_macros::CronJob;
+
+    #[derive(Component, CronJob, Default)]
+    #[cron(
+        expression = "0 0 0 31 * ? *",
+        label = "payday",
+        tag = "economy",
+        tag = "monthly",
+        timezone = "UTC",
+        alignment = "schedule",
+        month_end = "clamp",
+        history = 4
+    )]
+    struct Payday;
+
+    #[derive(Component, CronJob)]
+    #[cron(expression = "every minute")]
+    struct Ping;
+
+    assert_eq!(Payday::LABEL, Some("payday"));
+    assert_eq!(Ping::LABEL, None);
+    let timer = Payday::timer();
+    assert_eq!(timer.label(), Some("payday"));
+    assert!(timer.has_tag("economy") && timer.has_tag("monthly"));
+    assert_eq!(timer.timezone, Some(CronTimezone::Utc));
+    assert_eq!(timer.alignment, ScheduleAlignment::Schedule);
+    assert!(matches!(
+        &timer.schedule,
+        crate::TimerSchedule::Cron(expr) if expr.month_end_policy() == MonthEndPolicy::Clamp
+    ));
+    assert_eq!(Ping::timer().schedule.to_string(), "0 * * * * ? *");
+}
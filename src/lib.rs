@@ -1,165 +1,397 @@
-use bevy_app::prelude::*;
+//! bevy_cronjob is a simple helper to run cronjobs (at repeated schedule) in Bevy.
+//! # Usage
+//!
+//! ``` rust,no_run
+//! use bevy::log::LogPlugin;
+//! use bevy::prelude::*;
+//! use bevy_app::ScheduleRunnerPlugin;
+//! use bevy_cronjob::prelude::*;
+//! use std::time::Duration;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(
+//!             MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(
+//!                 1.0 / 60.0,
+//!             ))),
+//!         )
+//!         .add_plugins(LogPlugin::default())
+//!         .add_plugins(CronJobPlugin)
+//!         .add_systems(Update, print_per_5_sec.run_if(schedule_passed("0/5 * * * ? *")))
+//!         .add_systems(Update, print_per_min.run_if(schedule_passed("0 * * * ? *")))
+//!         .add_systems(Update, print_per_hour.run_if(schedule_passed("0 0 * * ? *")))
+//!         .run();
+//! }
+//!
+//! fn print_per_5_sec() {
+//!     info!("print every 5 sec")
+//! }
+//!
+//! fn print_per_min() {
+//!     info!("print every minute")
+//! }
+//! fn print_per_hour() {
+//!     info!("print every hour")
+//! }
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands
+//!         .spawn(ScheduleTimer::new("every 3 seconds"))
+//!         .observe(|_: Trigger<ScheduleArrived>| {
+//!             info!("3 seconds passed");
+//!         });
+//! }
+//!
+//! ```
+//!
+//! ## Expression
+//!
+//! the scheduling expression is base on [cron](https://github.com/zslayton/cron)
+//!
+//! | sec  | min  | hour | day of month | month | day of week | year      |
+//! |------|------|------|--------------|-------|-------------|-----------|
+//! | *    | *    | *    | *            | *     | *           | *         |
+//! | 0-59 | 0-59 | 0-23 | 1-23         | 1-12  | 1-7         | 1970-2100 |
+//!
+//! Time is specified in Local Time. Note that the year may be omitted.
+//!
+//! Comma separated values such as `1,2,3` are allowed. For example, a schedule of `0,15,30,45 * * *
+//! * *`' would execute on every 15 seconds.
+//!
+//! Ranges can be specified with a dash. For example `1-5 * * * * *`' would execute on every second
+//! for the first 5 seconds of a minute.
+
+// lets the derive macros refer to `::bevy_cronjob` inside this crate
+extern crate self as bevy_cronjob;
+
 use bevy_ecs::prelude::*;
 use chrono::DateTime;
-use cron::Schedule;
 pub use english_to_cron::str_cron_syntax;
-use std::str::FromStr;
 
-/// bevy_cronjob is a simple helper to run cronjobs (at repeated schedule) in Bevy.
-/// # Usage
+mod asset_gc;
+mod autosave;
+mod builder;
+mod calendar;
+mod catch_up;
+mod clock;
+mod condition;
+#[cfg(feature = "config")]
+pub mod config;
+mod countdown;
+mod daily;
+mod describe;
+#[cfg(feature = "dev")]
+mod diagnostic;
+mod duplicate;
+mod edit;
+mod error;
+mod expire;
+mod export;
+mod expression;
+mod forward;
+mod frequency;
+#[cfg(feature = "async")]
+pub mod future;
+mod history;
+#[cfg(feature = "dev")]
+mod hotkeys;
+mod hybrid;
+mod ics;
+mod idle;
+mod jitter;
+mod job;
+#[cfg(feature = "lifecycle")]
+mod lifecycle;
+mod limits;
+mod lint;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+mod observer;
+mod offline;
+#[cfg(feature = "serialize")]
+pub mod persist;
+mod plugin;
+mod queue;
+mod quiet;
+mod quota;
+#[cfg(feature = "bevy_replicon")]
+pub mod replicon;
+mod reset;
+mod rollback;
+mod run_limit;
+mod scheduler;
+#[cfg(feature = "dev")]
+mod screenshot;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+mod selector;
+#[cfg(feature = "serialize")]
+pub mod serialize;
+mod setter;
+mod slab;
+mod state;
+mod stats;
+mod streak;
+mod system;
+#[cfg(test)]
+mod testing;
+mod timer;
+mod timezone;
+#[cfg(feature = "bevy_tweening")]
+pub mod tween;
+#[cfg(feature = "bevy_ui")]
+pub mod ui;
+mod watchdog;
+mod window;
+
+pub use asset_gc::{AssetGcPlugin, AssetGcRequested, AssetGcStats, AssetGcTimer};
+pub use autosave::{Autosave, AutosavePlugin, AutosaveTimer, SaveRequested};
+
+/// Runs a system whenever the cron or english expression passes, checked at compile time.
+///
+/// Next to the system, a module of the same name is generated with a `plugin` function adding
+/// the system to [`Update`] gated on [`schedule_passed`]. Another schedule can be given with
+/// `schedule = FixedUpdate`. Only functions at module level can be annotated, as the module
+/// refers to the system through `super`.
 ///
-/// ``` rust,no_run
-/// use bevy::log::LogPlugin;
+/// ```rust
 /// use bevy::prelude::*;
-/// use bevy_app::ScheduleRunnerPlugin;
 /// use bevy_cronjob::prelude::*;
-/// use std::time::Duration;
 ///
-/// fn main() {
-///     App::new()
-///         .add_plugins(
-///             MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(
-///                 1.0 / 60.0,
-///             ))),
-///         )
-///         .add_plugins(LogPlugin::default())
-///         .add_plugins(CronJobPlugin)
-///         .add_systems(Update, print_per_5_sec.run_if(schedule_passed("0/5 * * * ? *")))
-///         .add_systems(Update, print_per_min.run_if(schedule_passed("0 * * * ? *")))
-///         .add_systems(Update, print_per_hour.run_if(schedule_passed("0 0 * * ? *")))
-///         .run();
+/// #[cron("every hour")]
+/// fn autosave() {
+///     info!("saving");
 /// }
 ///
-/// fn print_per_5_sec() {
-///     info!("print every 5 sec")
-/// }
-///
-/// fn print_per_min() {
-///     info!("print every minute")
-/// }
-/// fn print_per_hour() {
-///     info!("print every hour")
-/// }
+/// #[cron("0 0 4 * * ? *", schedule = FixedUpdate)]
+/// fn daily_reset() {}
 ///
-/// fn setup(mut commands: Commands) {
-///     commands
-///         .spawn(ScheduleTimer::new("every 3 seconds"))
-///         .observe(|_: Trigger<ScheduleArrived>| {
-///             info!("3 seconds passed");
-///         });
+/// fn main() {
+///     App::new()
+///         .add_plugins(CronJobPlugin)
+///         .add_plugins((autosave::plugin, daily_reset::plugin));
 /// }
-///
 /// ```
 ///
-/// ## Expression
-///
-/// the scheduling expression is base on [cron](https://github.com/zslayton/cron)
-///
-/// | sec  | min  | hour | day of month | month | day of week | year      |
-/// |------|------|------|--------------|-------|-------------|-----------|
-/// | *    | *    | *    | *            | *     | *           | *         |
-/// | 0-59 | 0-59 | 0-23 | 1-23         | 1-12  | 1-7         | 1970-2100 |
+/// Invalid expressions do not compile:
 ///
-/// Time is specified in Local Time. Note that the year may be omitted.
+/// ```compile_fail
+/// #[bevy_cronjob::cron("0 0 25 * * ? *")]
+/// fn never() {}
+/// # fn main() {}
+/// ```
 ///
-/// Comma separated values such as `1,2,3` are allowed. For example, a schedule of `0,15,30,45 * * *
-/// * *`' would execute on every 15 seconds.
+/// ```compile_fail
+/// #[bevy_cronjob::cron("last friday of the month at 25:00")]
+/// fn never() {}
+/// # fn main() {}
+/// ```
 ///
-/// Ranges can be specified with a dash. For example `1-5 * * * * *`' would execute on every second
-/// for the first 5 seconds of a minute.
+/// ```compile_fail
+/// #[bevy_cronjob::cron("0 0 0 ? * MON#6 *")]
+/// fn never() {}
+/// # fn main() {}
+/// ```
+pub use bevy_cronjob_macros::cron;
+pub use bevy_cronjob_macros::CronJob;
+pub use builder::ScheduleTimerBuilder;
+pub use calendar::{CalendarSystem, GameClock, GameDate, GameSchedule, GameTimer, SimpleCalendar};
+pub use catch_up::{CatchUpPolicy, ScheduleCaughtUp};
+pub use clock::CronClock;
+pub use condition::{
+    in_cron_window, on_schedule, schedule_fired, ConditionEntry, CronConditionRegistry,
+};
+pub use countdown::{CountdownFormat, CountdownTarget, UiCountdown};
+pub use daily::{DailySeed, DailySeedRotated};
+pub use describe::{DescriptionArg, DescriptionLocale, English, ScheduleDescription};
+#[cfg(feature = "dev")]
+pub use diagnostic::CronDiagnosticsPlugin;
+pub use duplicate::{find_duplicate_timers, warn_duplicate_timers, DuplicateSchedule};
+pub use edit::{
+    ExpressionStatus, ScheduleChanged, ScheduleExpression, ScheduleExpressionInvalid,
+    ScheduleFallbackUsed,
+};
+pub use error::CronError;
+pub use expire::{CleanupOnSchedule, ExpireBy};
+pub use export::{
+    export_cron_snapshot, export_history, export_schedule_table, schedule_table, ExportFormat,
+    ScheduleRow,
+};
+pub use expression::{
+    CommonSchedule, CronExpr, IntoCronExpression, MonthDay, MonthEndPolicy, MonthWeek,
+};
+pub use forward::{CronFireSender, ForwardedFire};
+pub use frequency::{estimate_frequency, FrequencyEstimate};
+pub use history::{
+    CronHistory, CronHistoryEntry, FireHistory, FireRecord, HistoryRetention, LastCronFire,
+};
+#[cfg(feature = "dev")]
+pub use hotkeys::{CronDevHotkeys, CronDevHotkeysPlugin};
+pub use hybrid::{HybridClock, HybridScheduleArrived, HybridTimer};
+pub use ics::{export_ics, import_ics, IcsImport, IcsSkipped};
+pub use idle::{time_until_next_fire, CronIdle};
+pub use job::CronJob;
+#[cfg(feature = "lifecycle")]
+pub use lifecycle::{CronLifecycle, ScheduleResumed, SuspendPolicy};
+pub use limits::ExpressionLimits;
+pub use lint::{lint_expression, lint_expression_with, ExpressionLint};
+pub use observer::CronAppExt;
+pub use offline::{offline_progress, OfflinePolicy, OfflineProgress, OfflineReport};
+pub use plugin::{
+    CronClockPlugin, CronConditionPlugin, CronJobPlugin, CronSlabPlugin, CronTimerPlugin,
+};
+pub use quiet::{CronQuietHours, QuietPolicy};
+pub use quota::{FireQuota, QuotaExhausted, QuotaPeriod};
+pub use reset::{PeriodId, ResetPeriod, ResetPeriodElapsed};
+pub use rollback::{CronSnapshot, TimerSnapshot};
+pub use run_limit::{FinishAction, ScheduleFinished};
+pub use scheduler::CronScheduler;
+#[cfg(feature = "dev")]
+pub use screenshot::ScheduledScreenshot;
+pub use selector::WeightedEventTable;
+pub use setter::SetResourceOnSchedule;
+pub use slab::{CronJobHandle, CronSlab, CronSlabFired};
+pub use state::{CronArmed, CronExpiredState, CronPausedState};
+pub use stats::CronFrameStats;
+pub use streak::{CronStreaks, Streak};
+pub use system::CronSystem;
+pub use timer::{
+    tick_cron, CronDispatch, CronJobConfig, ResumePolicy, ScheduleAlignment, ScheduleFireDropped,
+    ScheduleLate, ScheduleRegistered, ScheduleSkipped, ScheduleTimer, TimerSchedule,
+    FRAME_TIMER_THRESHOLD,
+};
+pub use timezone::{CronTimezone, CronTimezones};
+pub use watchdog::{CronWatchdog, ScheduleOverdue};
+pub use window::{InWindow, ScheduleWindow, WindowEntered, WindowExited};
 
 /// run every 5 sec
+#[deprecated(note = "use `CommonSchedule::Every5Sec` instead")]
 pub const EVERY_5_SEC: &str = "0/5 * * * * ? *";
 /// run every 10 sec
+#[deprecated(note = "use `CommonSchedule::Every10Sec` instead")]
 pub const EVERY_10_SEC: &str = "0/10 * * * * ? *";
 /// run every 30 sec
+#[deprecated(note = "use `CommonSchedule::Every30Sec` instead")]
 pub const EVERY_30_SEC: &str = "0/30 * * * * ? *";
 /// run every minute
+#[deprecated(note = "use `CommonSchedule::EveryMin` instead")]
 pub const EVERY_MIN: &str = "0 * * * * ? *";
 /// run every 5 minutes
+#[deprecated(note = "use `CommonSchedule::Every5Min` instead")]
 pub const EVERY_5_MIN: &str = "0 0/5 * * * ? *";
 /// run every 10 minutes
+#[deprecated(note = "use `CommonSchedule::Every10Min` instead")]
 pub const EVERY_10_MIN: &str = "0 0/10 * * * ? *";
 /// run every 30 minutes
+#[deprecated(note = "use `CommonSchedule::Every30Min` instead")]
 pub const EVERY_30_MIN: &str = "0 0/30 * * * ? *";
 /// run every hour
+#[deprecated(note = "use `CommonSchedule::EveryHour` instead")]
 pub const EVERY_HOUR: &str = "0 0 * * * ? *";
 /// run every day
+#[deprecated(note = "use `CommonSchedule::EveryDay` instead")]
 pub const EVERY_DAY: &str = "0 0 0 */1 * ? *";
 
 /// run every day at 1 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 1 }` instead")]
 pub const EVERY_1_AM: &str = "0 0 1 */1 * ? *";
 
 /// run every day at 2 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 2 }` instead")]
 pub const EVERY_2_AM: &str = "0 0 2 */1 * ? *";
 
 /// run every day at 3 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 3 }` instead")]
 pub const EVERY_3_AM: &str = "0 0 3 */1 * ? *";
 
 /// run every day at 4 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 4 }` instead")]
 pub const EVERY_4_AM: &str = "0 0 4 */1 * ? *";
 
 /// run every day at 5 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 5 }` instead")]
 pub const EVERY_5_AM: &str = "0 0 5 */1 * ? *";
 
 /// run every day at 6 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 6 }` instead")]
 pub const EVERY_6_AM: &str = "0 0 6 */1 * ? *";
 
 /// run every day at 7 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 7 }` instead")]
 pub const EVERY_7_AM: &str = "0 0 7 */1 * ? *";
 
 /// run every day at 8 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 8 }` instead")]
 pub const EVERY_8_AM: &str = "0 0 8 */1 * ? *";
 
 /// run every day at 9 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 9 }` instead")]
 pub const EVERY_9_AM: &str = "0 0 9 */1 * ? *";
 
 /// run every day at 10 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 10 }` instead")]
 pub const EVERY_10_AM: &str = "0 0 10 */1 * ? *";
 
 /// run every day at 11 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 11 }` instead")]
 pub const EVERY_11_AM: &str = "0 0 11 */1 * ? *";
 
 /// run every day at 12 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 12 }` instead")]
 pub const EVERY_12_PM: &str = "0 0 12 */1 * ? *";
 
 /// run every day at 1 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 13 }` instead")]
 pub const EVERY_1_PM: &str = "0 0 13 */1 * ? *";
 
 /// run every day at 2 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 14 }` instead")]
 pub const EVERY_2_PM: &str = "0 0 14 */1 * ? *";
 
 /// run every day at 3 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 15 }` instead")]
 pub const EVERY_3_PM: &str = "0 0 15 */1 * ? *";
 
 /// run every day at 4 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 16 }` instead")]
 pub const EVERY_4_PM: &str = "0 0 16 */1 * ? *";
 
 /// run every day at 5 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 17 }` instead")]
 pub const EVERY_5_PM: &str = "0 0 17 */1 * ? *";
 
 /// run every day at 6 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 18 }` instead")]
 pub const EVERY_6_PM: &str = "0 0 18 */1 * ? *";
 
 /// run every day at 7 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 19 }` instead")]
 pub const EVERY_7_PM: &str = "0 0 19 */1 * ? *";
 
 /// run every day at 8 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 20 }` instead")]
 pub const EVERY_8_PM: &str = "0 0 20 */1 * ? *";
 
 /// run every day at 9 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 21 }` instead")]
 pub const EVERY_9_PM: &str = "0 0 21 */1 * ? *";
 
 /// run every day at 10 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 22 }` instead")]
 pub const EVERY_10_PM: &str = "0 0 22 */1 * ? *";
 
 /// run every day at 11 pm
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 23 }` instead")]
 pub const EVERY_11_PM: &str = "0 0 23 */1 * ? *";
 
 /// run every day at 12 am
+#[deprecated(note = "use `CommonSchedule::DailyAt { hour: 0 }` instead")]
 pub const EVERY_12_AM: &str = "0 0 0 */1 * ? *";
 
 /// Creates a closure that checks if the cron expression has passed
+///
+/// The expression is evaluated against the [`CronClock`] when the resource exists, e.g. to
+/// follow the virtual game time, and against the system clock otherwise.
 /// # expression format:
 /// Note that the year may be omitted.
 /// | sec  | min  | hour | day of month | month | day of week | year      |
@@ -184,108 +416,127 @@ pub const EVERY_12_AM: &str = "0 0 0 */1 * ? *";
 /// |every day | 0 0 0 */1 * *|
 /// |every day at 1 am | 0 0 1 */1 * *|
 ///
+#[allow(clippy::type_complexity)]
 pub fn schedule_passed(
-    expression: &str,
-) -> impl FnMut(Local<Option<DateTime<chrono::Local>>>) -> bool {
-    let expression = try_english_pattern(expression);
-
-    let schedule = Schedule::from_str(&expression).expect("Failed to parse cron expression");
-    move |mut local_schedule: Local<Option<DateTime<chrono::Local>>>| {
-        if let Some(datetime) = schedule.upcoming(chrono::Local).next() {
-            let now = chrono::Local::now();
-            match *local_schedule {
-                Some(local) => {
-                    if now > local {
-                        *local_schedule = Some(datetime);
-                        return true;
-                    }
-                }
-
-                None => *local_schedule = Some(datetime),
-            }
-        }
-
-        false
-    }
+    expression: impl IntoCronExpression,
+) -> impl FnMut(
+    Local<Option<DateTime<chrono::Local>>>,
+    Option<Res<CronClock>>,
+    Option<Res<CronConditionRegistry>>,
+) -> bool {
+    let mut fired = schedule_fired(expression);
+    move |local_schedule, clock, registry| fired(local_schedule, clock, registry).is_some()
 }
 
-/// A Bevy plugin for running cron jobs
-pub struct CronJobPlugin;
-
-impl Plugin for CronJobPlugin {
-    fn build(&self, app: &mut App) {
-        app.add_systems(Update, check_schedule_timer);
-    }
+/// Like [`schedule_passed`], passing each occurrence at a random offset within `±jitter`, so
+/// that systems on the same schedule do not all run in the same frame, see
+/// [`ScheduleTimer::with_jitter`]
+pub fn schedule_passed_jittered(
+    expression: impl IntoCronExpression,
+    jitter: std::time::Duration,
+) -> impl FnMut(Option<Res<CronClock>>, Option<Res<CronConditionRegistry>>) -> bool {
+    let mut fired = condition::jittered_schedule_fired(expression, jitter);
+    move |clock, registry| fired(clock, registry).is_some()
 }
 
-/// A component that holds a cron expression
-#[derive(Debug, Component)]
-pub struct ScheduleTimer {
-    pub schedule: Schedule,
-    pub local_schedule: Option<DateTime<chrono::Local>>,
+/// Like [`schedule_passed`], with a label shown in the [`CronConditionRegistry`]
+#[allow(clippy::type_complexity)]
+pub fn schedule_passed_labeled(
+    label: impl Into<String>,
+    expression: impl IntoCronExpression,
+) -> impl FnMut(
+    Local<Option<DateTime<chrono::Local>>>,
+    Option<Res<CronClock>>,
+    Option<Res<CronConditionRegistry>>,
+) -> bool {
+    let mut fired = condition::labeled_schedule_fired(Some(label.into()), expression);
+    move |local_schedule, clock, registry| fired(local_schedule, clock, registry).is_some()
 }
 
-impl ScheduleTimer {
-    pub fn new(expression: &str) -> Self {
-        let expression = try_english_pattern(expression);
-
-        let schedule = Schedule::from_str(&expression).expect("Failed to parse cron expression");
-        Self {
-            schedule,
-            local_schedule: None,
-        }
-    }
-
-    fn schedule_passed(&mut self) -> bool {
-        if let Some(datetime) = self.schedule.upcoming(chrono::Local).next() {
-            let now = chrono::Local::now();
-            match self.local_schedule {
-                Some(local) => {
-                    if now > local {
-                        self.local_schedule = Some(datetime);
-                        return true;
-                    }
-                }
-
-                None => self.local_schedule = Some(datetime),
-            }
-        }
-
-        false
-    }
+/// Triggered on a [`ScheduleTimer`] entity each time its schedule fires
+///
+/// Timers firing in the same frame with an identical event, e.g. the same label and occurrence,
+/// share a single trigger targeting all of them.
+#[derive(Event, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ScheduleArrived {
+    /// label of the timer that fired, see [`ScheduleTimer::with_label`]
+    pub label: Option<String>,
+    /// the occurrence that fired, with sub-second precision for interval timers
+    pub scheduled: DateTime<chrono::Local>,
+    /// when the fire was dispatched
+    pub actual: DateTime<chrono::Local>,
+    /// `actual - scheduled`
+    pub lateness: std::time::Duration,
 }
 
-fn try_english_pattern(expression: &str) -> String {
-    if expression.chars().any(|c| c.is_ascii_alphabetic()) {
-        str_cron_syntax(expression).expect("Failed to parse cron expression")
-    } else {
-        expression.to_string()
-    }
+/// Triggered globally, without a target, for every fire of every [`ScheduleTimer`].
+///
+/// A single observer can implement cross-cutting concerns such as logging or achievements for all
+/// timers:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .add_observer(|trigger: Trigger<AnyScheduleArrived>| {
+///         info!("{:?} fired on {}", trigger.label, trigger.entity);
+///     });
+/// ```
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AnyScheduleArrived {
+    /// the timer entity that fired
+    pub entity: Entity,
+    /// label of the timer that fired
+    pub label: Option<String>,
+    /// the occurrence that fired, with sub-second precision for interval timers
+    pub scheduled: DateTime<chrono::Local>,
+    /// when the fire was dispatched
+    pub actual: DateTime<chrono::Local>,
 }
 
-/// A system that checks if the cron expression has passed
-fn check_schedule_timer(mut query: Query<(Entity, &mut ScheduleTimer)>, mut commands: Commands) {
-    let mut targets = vec![];
-
-    for (entity, mut schedule_timer) in query.iter_mut() {
-        if schedule_timer.schedule_passed() {
-            targets.push(entity);
-        }
-    }
-
-    if !targets.is_empty() {
-        commands.trigger_targets(ScheduleArrived, targets);
-    }
+#[doc(hidden)]
+pub mod __private {
+    pub use bevy_app::{App, Update};
+    pub use bevy_ecs::schedule::IntoSystemConfigs;
 }
 
-#[derive(Event)]
-pub struct ScheduleArrived;
-
 pub mod prelude {
-    pub use crate::{schedule_passed, CronJobPlugin, ScheduleArrived, ScheduleTimer};
+    pub use crate::{
+        cron, estimate_frequency, export_ics, find_duplicate_timers, import_ics, in_cron_window,
+        lint_expression, lint_expression_with, offline_progress, on_schedule, schedule_fired,
+        schedule_passed, schedule_passed_jittered, schedule_passed_labeled, tick_cron,
+        time_until_next_fire, warn_duplicate_timers, AnyScheduleArrived, AssetGcPlugin,
+        AssetGcRequested, AssetGcStats, AssetGcTimer, Autosave, AutosavePlugin, AutosaveTimer,
+        CalendarSystem, CatchUpPolicy, CleanupOnSchedule, CommonSchedule, CountdownFormat,
+        CountdownTarget, CronAppExt, CronArmed, CronClock, CronClockPlugin, CronConditionPlugin,
+        CronConditionRegistry, CronDispatch, CronError, CronExpiredState, CronExpr, CronFireSender,
+        CronFrameStats, CronHistory, CronIdle, CronJob, CronJobConfig, CronJobHandle,
+        CronJobPlugin, CronPausedState, CronQuietHours, CronScheduler, CronSlab, CronSlabFired,
+        CronSlabPlugin, CronSnapshot, CronStreaks, CronSystem, CronTimerPlugin, CronTimezone,
+        CronTimezones, CronWatchdog, DailySeed, DailySeedRotated, DescriptionArg,
+        DescriptionLocale, DuplicateSchedule, ExpireBy, ExpressionLimits, ExpressionLint,
+        ExpressionStatus, FinishAction, ForwardedFire, FrequencyEstimate, GameClock, GameDate,
+        GameSchedule, GameTimer, HybridClock, HybridScheduleArrived, HybridTimer, IcsImport,
+        IcsSkipped, InWindow, LastCronFire, MonthDay, MonthEndPolicy, MonthWeek, OfflinePolicy,
+        OfflineProgress, OfflineReport, PeriodId, QuietPolicy, QuotaExhausted, QuotaPeriod,
+        ResetPeriod, ResetPeriodElapsed, ResumePolicy, SaveRequested, ScheduleAlignment,
+        ScheduleArrived, ScheduleCaughtUp, ScheduleChanged, ScheduleDescription,
+        ScheduleExpression, ScheduleExpressionInvalid, ScheduleFallbackUsed, ScheduleFinished,
+        ScheduleFireDropped, ScheduleLate, ScheduleOverdue, ScheduleRegistered, ScheduleSkipped,
+        ScheduleTimer, ScheduleTimerBuilder, ScheduleWindow, SetResourceOnSchedule, SimpleCalendar,
+        Streak, TimerSchedule, TimerSnapshot, UiCountdown, WeightedEventTable, WindowEntered,
+        WindowExited,
+    };
+    #[cfg(feature = "dev")]
+    pub use crate::{CronDevHotkeys, CronDevHotkeysPlugin, ScheduledScreenshot};
+    #[cfg(feature = "lifecycle")]
+    pub use crate::{CronLifecycle, ScheduleResumed, SuspendPolicy};
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_expression() {
     assert_eq!(EVERY_5_SEC, str_cron_syntax("every 5 seconds").unwrap());
     assert_eq!(EVERY_10_SEC, str_cron_syntax("every 10 seconds").unwrap());
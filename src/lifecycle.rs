@@ -0,0 +1,197 @@
+use crate::{CronClock, CronTimezones, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::debug;
+use bevy_window::AppLifecycle;
+use chrono::DateTime;
+use std::time::Duration;
+
+/// Enables handling of app suspension on Android and iOS, where the app stops updating while it
+/// is in the background.
+///
+/// With this resource present, [`CronJobPlugin`](crate::CronJobPlugin) watches the
+/// [`AppLifecycle`] events. On resume, every timer with occurrences elapsed in the background
+/// receives a [`ScheduleResumed`] event with their count before it fires, so games can grant the
+/// missed rewards at once. Requires the `lifecycle` feature.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(CronLifecycle::new(SuspendPolicy::Pause))
+///     .add_observer(|trigger: Trigger<ScheduleResumed>| {
+///         info!("{} occurrences missed in the background", trigger.missed);
+///     });
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct CronLifecycle {
+    /// what happens to the timers while the app is suspended
+    pub policy: SuspendPolicy,
+    /// most missed occurrences counted per timer
+    pub max_missed: u64,
+    suspended_at: Option<DateTime<chrono::Local>>,
+    /// timers paused by [`SuspendPolicy::Pause`]
+    paused: Vec<Entity>,
+}
+
+impl CronLifecycle {
+    /// Handle suspension with `policy`
+    pub fn new(policy: SuspendPolicy) -> Self {
+        Self {
+            policy,
+            max_missed: 10_000,
+            suspended_at: None,
+            paused: Vec::new(),
+        }
+    }
+
+    /// Count at most `max_missed` occurrences per timer
+    pub fn with_max_missed(mut self, max_missed: u64) -> Self {
+        self.max_missed = max_missed;
+        self
+    }
+
+    /// When the app was suspended, `None` while it is running
+    pub fn suspended_at(&self) -> Option<DateTime<chrono::Local>> {
+        self.suspended_at
+    }
+}
+
+impl Default for CronLifecycle {
+    fn default() -> Self {
+        Self::new(SuspendPolicy::default())
+    }
+}
+
+/// What happens to the timers while the app is suspended, see [`CronLifecycle`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SuspendPolicy {
+    /// keep the schedules: on resume, timers fire for the occurrences they missed according to
+    /// their [alignment](crate::ScheduleAlignment)
+    #[default]
+    CatchUp,
+    /// pause the timers: the time spent in the background does not count, pending occurrences
+    /// are postponed by it on resume
+    Pause,
+}
+
+/// Triggered on a timer entity on resume when occurrences elapsed while the app was suspended,
+/// see [`CronLifecycle`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleResumed {
+    /// label of the timer
+    pub label: Option<String>,
+    /// occurrences that elapsed in the background
+    pub missed: u64,
+    /// how long the app was suspended
+    pub suspended_for: Duration,
+}
+
+pub(crate) fn handle_app_lifecycle(
+    mut events: EventReader<AppLifecycle>,
+    mut lifecycle: ResMut<CronLifecycle>,
+    clock: Res<CronClock>,
+    timezones: Option<Res<CronTimezones>>,
+    mut timers: Query<(Entity, &mut ScheduleTimer)>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        match event {
+            AppLifecycle::WillSuspend | AppLifecycle::Suspended => {
+                if lifecycle.suspended_at.is_some() {
+                    continue;
+                }
+                lifecycle.suspended_at = Some(clock.now());
+                if lifecycle.policy == SuspendPolicy::Pause {
+                    for (entity, mut timer) in timers.iter_mut() {
+                        if !timer.is_paused() {
+                            timer.pause();
+                            lifecycle.paused.push(entity);
+                        }
+                    }
+                }
+                debug!("cron timers suspended");
+            }
+            AppLifecycle::WillResume | AppLifecycle::Running => {
+                let Some(suspended_at) = lifecycle.suspended_at.take() else {
+                    continue;
+                };
+                let now = clock.now();
+                let suspended_for = (now - suspended_at).to_std().unwrap_or_default();
+                let paused = std::mem::take(&mut lifecycle.paused);
+                for (entity, mut timer) in timers.iter_mut() {
+                    let by_policy = paused.contains(&entity);
+                    if timer.is_paused() && !by_policy {
+                        continue;
+                    }
+                    let timezone = match &timezones {
+                        Some(timezones) => timezones.resolve(&timer),
+                        None => timer.timezone.unwrap_or_default(),
+                    };
+                    let missed =
+                        timer.count_occurrences(suspended_at, now, timezone, lifecycle.max_missed);
+                    if by_policy {
+                        timer.resume();
+                        // checked, the clock may have jumped arbitrarily far while suspended
+                        timer.local_schedule = timer.local_schedule.and_then(|pending| {
+                            pending
+                                .checked_add_signed(chrono::Duration::from_std(suspended_for).ok()?)
+                        });
+                    }
+                    if missed > 0 {
+                        commands.trigger_targets(
+                            ScheduleResumed {
+                                label: timer.label.clone(),
+                                missed,
+                                suspended_for,
+                            },
+                            entity,
+                        );
+                    }
+                }
+                debug!(?suspended_for, "cron timers resumed");
+            }
+            AppLifecycle::Idle => {}
+        }
+    }
+}
+
+#[test]
+fn test_lifecycle() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{CronAppExt, ScheduleArrived};
+
+    for (policy, arrived) in [(SuspendPolicy::CatchUp, 1), (SuspendPolicy::Pause, 0)] {
+        let mut app = cron_app();
+        app.insert_resource(CronLifecycle::new(policy))
+            .record::<ScheduleArrived>()
+            .record::<ScheduleResumed>();
+        let timer = app
+            .world_mut()
+            .spawn(ScheduleTimer::new("every 10 seconds"))
+            .id();
+        app.update();
+
+        app.world_mut().send_event(AppLifecycle::Suspended);
+        app.update();
+        app.world_mut().send_event(AppLifecycle::Running);
+        app.advance_cron_time(Duration::from_secs(35));
+
+        let missed = app
+            .triggered::<ScheduleResumed>()
+            .iter()
+            .map(|(_, resumed)| resumed.missed)
+            .sum::<u64>();
+        assert_eq!(missed, 3);
+        assert_eq!(app.triggered::<ScheduleArrived>().len(), arrived);
+        let timer = app.world().get::<ScheduleTimer>(timer).unwrap();
+        assert!(!timer.is_paused());
+        if policy == SuspendPolicy::Pause {
+            // the occurrence at 10 s is postponed by the 35 s spent in the background
+            let pending = timer.local_schedule.unwrap();
+            let clock = app.world().resource::<CronClock>();
+            assert_eq!((pending - clock.now()).num_seconds(), 9);
+        }
+    }
+}
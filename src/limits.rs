@@ -0,0 +1,154 @@
+use crate::{CronError, CronExpr};
+use chrono::{DateTime, Duration};
+
+/// Limits on expressions from untrusted sources such as mods or player input, see
+/// [`CronExpr::parse_limited`].
+///
+/// ```rust
+/// use bevy_cronjob::prelude::*;
+///
+/// let limits = ExpressionLimits::default().with_max_length(64);
+/// assert!(CronExpr::parse_limited("0 0 9 * * MON-FRI *", &limits).is_ok());
+/// let list = (0..60).map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+/// let err = CronExpr::parse_limited(&format!("{list} * * * * ? *"), &limits);
+/// assert!(matches!(err, Err(CronError::TooComplex { .. })));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpressionLimits {
+    /// longest accepted expression, in bytes
+    pub max_length: usize,
+    /// most whitespace separated fields of the cron form
+    pub max_fields: usize,
+    /// most comma separated items in a single field
+    pub max_list_items: usize,
+    /// the first occurrence must lie within this span from now
+    pub search_horizon: Duration,
+}
+
+impl Default for ExpressionLimits {
+    fn default() -> Self {
+        Self {
+            max_length: 256,
+            max_fields: 7,
+            max_list_items: 32,
+            search_horizon: Duration::days(5 * 366),
+        }
+    }
+}
+
+impl ExpressionLimits {
+    /// Set the longest accepted expression, in bytes
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Set the most whitespace separated fields of the cron form
+    pub fn with_max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = max_fields;
+        self
+    }
+
+    /// Set the most comma separated items in a single field
+    pub fn with_max_list_items(mut self, max_list_items: usize) -> Self {
+        self.max_list_items = max_list_items;
+        self
+    }
+
+    /// Set the span from now the first occurrence must lie within
+    pub fn with_search_horizon(mut self, search_horizon: Duration) -> Self {
+        self.search_horizon = search_horizon;
+        self
+    }
+
+    /// Check an already parsed expression against the limits, searching from `now`
+    pub fn check(&self, expr: &CronExpr, now: &DateTime<chrono::Local>) -> Result<(), CronError> {
+        let too_complex = |reason: String| CronError::TooComplex {
+            expression: expr.as_str().to_string(),
+            reason,
+        };
+        let fields = expr.as_str().split_whitespace().collect::<Vec<_>>();
+        if fields.len() > self.max_fields {
+            return Err(too_complex(format!(
+                "{} fields exceed the limit of {}",
+                fields.len(),
+                self.max_fields
+            )));
+        }
+        if let Some(items) = fields
+            .iter()
+            .map(|field| field.split(',').count())
+            .find(|items| *items > self.max_list_items)
+        {
+            return Err(too_complex(format!(
+                "a list of {items} items exceeds the limit of {}",
+                self.max_list_items
+            )));
+        }
+        let horizon = *now + self.search_horizon;
+        match expr.after(now).next() {
+            Some(next) if next <= horizon => Ok(()),
+            _ => Err(too_complex(format!(
+                "no occurrence within the next {} days",
+                self.search_horizon.num_days()
+            ))),
+        }
+    }
+}
+
+impl CronExpr {
+    /// Parse a cron or english expression from an untrusted source, rejecting it with
+    /// [`CronError::TooComplex`] when it exceeds `limits`.
+    ///
+    /// The length is checked before parsing, so oversized input is never handed to the parser.
+    pub fn parse_limited(expression: &str, limits: &ExpressionLimits) -> Result<Self, CronError> {
+        if expression.len() > limits.max_length {
+            return Err(CronError::TooComplex {
+                expression: expression.chars().take(limits.max_length).collect(),
+                reason: format!(
+                    "{} bytes exceed the limit of {}",
+                    expression.len(),
+                    limits.max_length
+                ),
+            });
+        }
+        let expr = Self::parse(expression)?;
+        limits.check(&expr, &chrono::Local::now())?;
+        Ok(expr)
+    }
+}
+
+#[test]
+fn test_expression_limits() {
+    let limits = ExpressionLimits::default();
+    assert!(CronExpr::parse_limited("every 5 minutes", &limits).is_ok());
+    assert!(CronExpr::parse_limited("0 0 0 29 2 ? *", &limits).is_ok());
+
+    let long = format!("0 0 0 * * ? *{}", " ".repeat(300));
+    assert!(matches!(
+        CronExpr::parse_limited(&long, &limits),
+        Err(CronError::TooComplex { .. })
+    ));
+
+    let list = (0..40).map(|n| (n % 60).to_string()).collect::<Vec<_>>();
+    let expr = format!("{} * * * * ? *", list.join(","));
+    assert!(CronExpr::parse(&expr).is_ok());
+    assert!(matches!(
+        CronExpr::parse_limited(&expr, &limits),
+        Err(CronError::TooComplex { .. })
+    ));
+
+    // leap days are more than a year apart, so they miss a one year horizon from most dates
+    let short = limits.with_search_horizon(Duration::days(30));
+    let now = "2025-03-01T00:00:00+00:00"
+        .parse::<DateTime<chrono::FixedOffset>>()
+        .unwrap()
+        .with_timezone(&chrono::Local);
+    let leap_day = CronExpr::parse("0 0 12 29 2 ? *").unwrap();
+    assert!(limits.check(&leap_day, &now).is_ok());
+    assert!(short.check(&leap_day, &now).is_err());
+    assert!(matches!(
+        CronExpr::parse_limited("0 0 0 31 2 ? *", &limits),
+        Err(CronError::TooComplex { .. })
+    ));
+}
@@ -0,0 +1,165 @@
+use crate::{CronError, IntoCronExpression};
+use cron::TimeUnitSpec;
+use std::fmt;
+
+/// A likely mistake in an expression, found by [`lint_expression`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpressionLint {
+    /// both the day of month and the day of week are restricted, so occurrences must match both,
+    /// unlike classic cron where matching either one suffices
+    DayOfMonthAndWeek {
+        /// the day of month field
+        day_of_month: String,
+        /// the day of week field
+        day_of_week: String,
+    },
+    /// fires more often per minute than allowed
+    TooFrequent {
+        /// fires per minute
+        per_minute: u32,
+        /// the allowed number of fires per minute
+        max: u32,
+    },
+    /// restricted to hours in which daylight saving time transitions happen in many timezones,
+    /// so occurrences may be skipped or fire twice on transition days
+    DaylightSavingHour {
+        /// the affected hours
+        hours: Vec<u32>,
+    },
+    /// never fires again
+    NeverFires,
+}
+
+impl fmt::Display for ExpressionLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpressionLint::DayOfMonthAndWeek {
+                day_of_month,
+                day_of_week,
+            } => write!(
+                f,
+                "day of month `{day_of_month}` and day of week `{day_of_week}` must both match"
+            ),
+            ExpressionLint::TooFrequent { per_minute, max } => write!(
+                f,
+                "fires {per_minute} times per minute, more than the allowed {max}"
+            ),
+            ExpressionLint::DaylightSavingHour { hours } => {
+                let hours = hours.iter().map(u32::to_string).collect::<Vec<_>>();
+                write!(
+                    f,
+                    "hours {} may be skipped or repeated on daylight saving time transitions",
+                    hours.join(", ")
+                )
+            }
+            ExpressionLint::NeverFires => write!(f, "never fires"),
+        }
+    }
+}
+
+/// Hours in which daylight saving time starts or ends in most timezones observing it
+const DAYLIGHT_SAVING_HOURS: std::ops::RangeInclusive<u32> = 1..=3;
+
+/// Day of month ranges generated for the nth weekday of a month, e.g. `TUE#2`, which restrict
+/// both day fields on purpose
+const MONTH_WEEK_DAYS: [&str; 5] = ["1-7", "8-14", "15-21", "22-28", "22-31"];
+
+/// Find likely mistakes in an expression, for editors and validators of schedule files.
+///
+/// Fails when the expression does not parse. Expressions firing more than once per minute are
+/// reported, see [`lint_expression_with`] to allow more.
+///
+/// ```rust
+/// use bevy_cronjob::prelude::*;
+///
+/// assert!(lint_expression("0 30 18 * * MON-FRI *").unwrap().is_empty());
+/// let lints = lint_expression("0 30 2 13 * FRI *").unwrap();
+/// assert!(matches!(lints[0], ExpressionLint::DayOfMonthAndWeek { .. }));
+/// assert!(matches!(lints[1], ExpressionLint::DaylightSavingHour { .. }));
+/// ```
+pub fn lint_expression(
+    expression: impl IntoCronExpression,
+) -> Result<Vec<ExpressionLint>, CronError> {
+    lint_expression_with(expression, 1)
+}
+
+/// [`lint_expression`], allowing up to `max_fires_per_minute` fires per minute
+pub fn lint_expression_with(
+    expression: impl IntoCronExpression,
+    max_fires_per_minute: u32,
+) -> Result<Vec<ExpressionLint>, CronError> {
+    let expr = expression.into_cron_expr()?;
+    let fields = expr.as_str().split_whitespace().collect::<Vec<_>>();
+    let restricted = |field: &str| !matches!(field, "*" | "?");
+    let mut lints = Vec::new();
+
+    let (day_of_month, day_of_week) = (fields[3], fields[5]);
+    if restricted(day_of_month)
+        && restricted(day_of_week)
+        && !MONTH_WEEK_DAYS.contains(&day_of_month)
+    {
+        lints.push(ExpressionLint::DayOfMonthAndWeek {
+            day_of_month: day_of_month.to_string(),
+            day_of_week: day_of_week.to_string(),
+        });
+    }
+
+    let schedule = expr.schedule();
+    let per_minute = schedule.seconds().count();
+    if per_minute > max_fires_per_minute {
+        lints.push(ExpressionLint::TooFrequent {
+            per_minute,
+            max: max_fires_per_minute,
+        });
+    }
+
+    let hours = schedule.hours();
+    if hours.count() < 24 {
+        let hours = DAYLIGHT_SAVING_HOURS
+            .filter(|hour| hours.includes(*hour))
+            .collect::<Vec<_>>();
+        if !hours.is_empty() {
+            lints.push(ExpressionLint::DaylightSavingHour { hours });
+        }
+    }
+
+    if expr.after(&chrono::Local::now()).next().is_none() {
+        lints.push(ExpressionLint::NeverFires);
+    }
+    Ok(lints)
+}
+
+#[test]
+fn test_lint_expression() {
+    assert!(lint_expression("every day at 9:00").unwrap().is_empty());
+    assert!(lint_expression("2nd tuesday of the month at 19:00")
+        .unwrap()
+        .is_empty());
+    assert!(lint_expression("every blue moon").is_err());
+
+    assert_eq!(
+        lint_expression("*/10 * * * * ? *").unwrap(),
+        [ExpressionLint::TooFrequent {
+            per_minute: 6,
+            max: 1
+        }]
+    );
+    assert!(lint_expression_with("*/10 * * * * ? *", 6)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        lint_expression("0 30 1-4 * * ? *").unwrap(),
+        [ExpressionLint::DaylightSavingHour {
+            hours: vec![1, 2, 3]
+        }]
+    );
+    assert_eq!(
+        lint_expression("0 0 12 31 2 ? *").unwrap(),
+        [ExpressionLint::NeverFires]
+    );
+    let lints = lint_expression("0 0 12 1 * MON *").unwrap();
+    assert_eq!(
+        lints[0].to_string(),
+        "day of month `1` and day of week `MON` must both match"
+    );
+}
@@ -0,0 +1,26 @@
+//! Metric names published with the `metrics` feature, scrape them with any `metrics` exporter
+//! (e.g. `metrics-exporter-prometheus`).
+
+use crate::{CronFrameStats, ScheduleTimer};
+use ::metrics::{counter, gauge, histogram};
+use bevy_ecs::prelude::*;
+
+/// total fires, labeled by timer label
+pub const FIRES_TOTAL: &str = "cron_fires_total";
+/// delay between the scheduled and the actual fire, labeled by timer label
+pub const DRIFT_SECONDS: &str = "cron_drift_seconds";
+/// number of live timers
+pub const ACTIVE_TIMERS: &str = "cron_active_timers";
+/// time spent in the timer check system
+pub const CHECK_SECONDS: &str = "cron_check_seconds";
+
+pub(crate) fn record_fire(label: Option<&str>, drift: std::time::Duration) {
+    let label = label.unwrap_or("unlabeled").to_string();
+    counter!(FIRES_TOTAL, "label" => label.clone()).increment(1);
+    histogram!(DRIFT_SECONDS, "label" => label).record(drift.as_secs_f64());
+}
+
+pub(crate) fn publish_gauges(stats: Res<CronFrameStats>, timers: Query<(), With<ScheduleTimer>>) {
+    gauge!(ACTIVE_TIMERS).set(timers.iter().count() as f64);
+    histogram!(CHECK_SECONDS).record(stats.check_duration.as_secs_f64());
+}
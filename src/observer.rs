@@ -0,0 +1,151 @@
+use crate::{AnyScheduleArrived, CronClock, CronJob, ScheduleTimer};
+use bevy_app::{App, Startup};
+use bevy_ecs::prelude::*;
+
+/// Extension methods on [`App`] for reacting to cron fires and driving them in tests
+pub trait CronAppExt {
+    /// Run `system` for every fire of a timer tagged with `tag`, see
+    /// [`ScheduleTimer::with_tag`]. Timers labeled `tag` match as well.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// fn pay_interest(In(fire): In<AnyScheduleArrived>) {
+    ///     info!("paying interest for {:?}", fire.label);
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(CronJobPlugin)
+    ///     .observe_schedule_tagged("economy", pay_interest);
+    /// app.world_mut()
+    ///     .spawn(ScheduleTimer::new("every hour").with_tag("economy"));
+    /// ```
+    fn observe_schedule_tagged<M>(
+        &mut self,
+        tag: impl Into<String>,
+        system: impl IntoSystem<In<AnyScheduleArrived>, (), M> + 'static,
+    ) -> &mut Self;
+
+    /// Spawn the [`CronJob`] `J` with its timer at startup
+    fn add_cron_job<J: CronJob + Default>(&mut self) -> &mut Self;
+
+    /// Move the [`CronClock`] forward by `by` and run one update, so tests see the fires due by
+    /// then without sleeping. A real time clock is replaced by a [manual](CronClock::manual) one
+    /// first, standing at its current time.
+    ///
+    /// Like a long frame, a jump over several occurrences of a timer fires it once, advance in
+    /// smaller steps to observe each of them.
+    fn advance_cron_time(&mut self, by: std::time::Duration) -> &mut Self;
+}
+
+impl CronAppExt for App {
+    fn observe_schedule_tagged<M>(
+        &mut self,
+        tag: impl Into<String>,
+        system: impl IntoSystem<In<AnyScheduleArrived>, (), M> + 'static,
+    ) -> &mut Self {
+        let tag = tag.into();
+        let system = self.world_mut().register_system(system);
+        self.add_observer(
+            move |trigger: Trigger<AnyScheduleArrived>,
+                  timers: Query<&ScheduleTimer>,
+                  mut commands: Commands| {
+                let matches = timers
+                    .get(trigger.entity)
+                    .is_ok_and(|timer| timer.has_tag(&tag) || timer.label() == Some(tag.as_str()));
+                if matches {
+                    commands.run_system_with_input(system, trigger.event().clone());
+                }
+            },
+        )
+    }
+
+    fn add_cron_job<J: CronJob + Default>(&mut self) -> &mut Self {
+        self.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(J::default().bundle());
+        })
+    }
+
+    fn advance_cron_time(&mut self, by: std::time::Duration) -> &mut Self {
+        let mut clock = self
+            .world_mut()
+            .get_resource_or_insert_with(CronClock::default);
+        if clock.is_real_time() {
+            let now = clock.now();
+            *clock = CronClock::manual(now);
+        }
+        clock.fast_forward(by);
+        self.update();
+        self
+    }
+}
+
+#[test]
+fn test_observe_schedule_tagged() {
+    use crate::testing::{cron_app, TestAppExt, Triggered};
+    use std::time::Duration;
+
+    let mut app = cron_app();
+    app.init_resource::<Triggered<AnyScheduleArrived>>()
+        .observe_schedule_tagged(
+            "economy",
+            |In(fire): In<AnyScheduleArrived>, mut fired: ResMut<Triggered<AnyScheduleArrived>>| {
+                fired.0.push((fire.entity, fire));
+            },
+        );
+    let mut spawn = |timer: ScheduleTimer| app.world_mut().spawn(timer).id();
+    let tagged = spawn(ScheduleTimer::new("every hour").with_tag("economy"));
+    let labeled = spawn(ScheduleTimer::new("every hour").with_label("economy"));
+    spawn(ScheduleTimer::new("every hour").with_tag("weather"));
+    app.update();
+    app.advance_cron_time(Duration::from_secs(3600));
+
+    let mut fired: Vec<_> = app
+        .triggered::<AnyScheduleArrived>()
+        .iter()
+        .map(|(entity, _)| *entity)
+        .collect();
+    fired.sort();
+    let mut expected = vec![tagged, labeled];
+    expected.sort();
+    assert_eq!(fired, expected);
+}
+
+#[test]
+fn test_advance_cron_time() {
+    use crate::testing::{cron_app, start, TestAppExt};
+    use crate::{schedule_passed, CronJobPlugin, ScheduleArrived};
+    use bevy_app::Update;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Runs(u32);
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>()
+        .init_resource::<Runs>()
+        .add_systems(
+            Update,
+            (|mut runs: ResMut<Runs>| runs.0 += 1).run_if(schedule_passed("every hour")),
+        );
+    app.world_mut().spawn(ScheduleTimer::new("every hour"));
+    app.update();
+    for _ in 0..3 {
+        app.advance_cron_time(Duration::from_secs(3600));
+    }
+    // the clock stands still between the updates
+    app.update();
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 3);
+    assert_eq!(app.world().resource::<Runs>().0, 3);
+    assert_eq!(
+        app.world().resource::<CronClock>().now(),
+        start() + chrono::Duration::hours(3)
+    );
+
+    // a real time clock is replaced
+    let mut app = App::new();
+    app.add_plugins(CronJobPlugin)
+        .advance_cron_time(Duration::from_secs(60));
+    assert!(!app.world().resource::<CronClock>().is_real_time());
+}
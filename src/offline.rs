@@ -0,0 +1,185 @@
+use crate::{CronClock, CronTimezone, CronTimezones, ScheduleTimer, TimerSchedule};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::time::Duration;
+
+/// Bounds of the offline progress computed by [`offline_progress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OfflinePolicy {
+    /// only the last `max_elapsed` of the absence count
+    pub max_elapsed: Option<Duration>,
+    /// most occurrences counted per timer
+    pub max_occurrences: u64,
+}
+
+impl Default for OfflinePolicy {
+    fn default() -> Self {
+        Self {
+            max_elapsed: None,
+            max_occurrences: 10_000,
+        }
+    }
+}
+
+impl OfflinePolicy {
+    /// Only count the last `max_elapsed` of the absence, e.g. 12 hours of offline rewards
+    pub fn with_max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Count at most `max_occurrences` per timer
+    pub fn with_max_occurrences(mut self, max_occurrences: u64) -> Self {
+        self.max_occurrences = max_occurrences;
+        self
+    }
+}
+
+/// Occurrences of one timer that elapsed while the app was closed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfflineProgress {
+    /// the timer entity
+    pub entity: Entity,
+    /// label of the timer
+    pub label: Option<String>,
+    /// occurrences that elapsed
+    pub occurrences: u64,
+    /// whether the count hit [`OfflinePolicy::max_occurrences`]
+    pub capped: bool,
+}
+
+/// Offline progress of every timer, see [`offline_progress`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OfflineReport {
+    /// progress of each timer
+    pub timers: Vec<OfflineProgress>,
+}
+
+impl OfflineReport {
+    /// Occurrences of all timers labeled `label`
+    pub fn for_label(&self, label: &str) -> u64 {
+        self.timers
+            .iter()
+            .filter(|progress| progress.label.as_deref() == Some(label))
+            .map(|progress| progress.occurrences)
+            .sum()
+    }
+}
+
+impl ScheduleTimer {
+    /// Count the occurrences in `(from, to]`, at most `limit`. Random schedules are sampled from a
+    /// copy of their generator, leaving the timer untouched.
+    pub fn count_occurrences(
+        &self,
+        from: DateTime<chrono::Local>,
+        to: DateTime<chrono::Local>,
+        timezone: CronTimezone,
+        limit: u64,
+    ) -> u64 {
+        if to <= from {
+            return 0;
+        }
+        if let (TimerSchedule::Interval(interval), true) =
+            (&self.schedule, self.excluded_dates.is_empty())
+        {
+            let elapsed = (to - from).to_std().unwrap_or_default();
+            let count = elapsed.as_nanos() / interval.as_nanos().max(1);
+            return count.min(limit as u128) as u64;
+        }
+        let mut schedule = self.schedule.clone();
+        let mut at = from;
+        let mut count = 0;
+        while count < limit {
+            match schedule.next_after_in(&at, timezone) {
+                Some(next) if next <= to => {
+                    if !self.is_excluded(timezone.date_of(&next)) {
+                        count += 1;
+                    }
+                    at = next;
+                }
+                _ => break,
+            }
+        }
+        count
+    }
+}
+
+/// How many occurrences of each [`ScheduleTimer`] elapsed since `last_seen`, the persisted time
+/// the app was last running, until now on the [`CronClock`].
+///
+/// Idle games grant offline rewards with the same schedules they use online:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use std::time::Duration;
+///
+/// let mut world = World::new();
+/// world.spawn(ScheduleTimer::new("every 1 minute").with_label("gold-mine"));
+/// let last_seen = chrono::Local::now() - chrono::Duration::days(2);
+/// let policy = OfflinePolicy::default().with_max_elapsed(Duration::from_secs(12 * 3600));
+/// let report = offline_progress(&mut world, last_seen, policy);
+/// assert!((719..=720).contains(&report.for_label("gold-mine")));
+/// ```
+pub fn offline_progress(
+    world: &mut World,
+    last_seen: DateTime<chrono::Local>,
+    policy: OfflinePolicy,
+) -> OfflineReport {
+    let now = world
+        .get_resource::<CronClock>()
+        .map_or_else(chrono::Local::now, CronClock::now);
+    let from = match policy
+        .max_elapsed
+        .and_then(|max| chrono::Duration::from_std(max).ok())
+    {
+        Some(max) => last_seen.max(now - max),
+        None => last_seen,
+    };
+    let timezones = world.get_resource::<CronTimezones>().cloned();
+    let timers = world
+        .query::<(Entity, &ScheduleTimer)>()
+        .iter(world)
+        .map(|(entity, timer)| {
+            let timezone = match &timezones {
+                Some(timezones) => timezones.resolve(timer),
+                None => timer.timezone.unwrap_or_default(),
+            };
+            let occurrences = timer.count_occurrences(from, now, timezone, policy.max_occurrences);
+            OfflineProgress {
+                entity,
+                label: timer.label.clone(),
+                occurrences,
+                capped: occurrences >= policy.max_occurrences,
+            }
+        })
+        .collect();
+    OfflineReport { timers }
+}
+
+#[test]
+fn test_offline_progress() {
+    use chrono::TimeZone;
+
+    let mut world = World::new();
+    let epoch = chrono::Local
+        .with_ymd_and_hms(2030, 1, 1, 12, 0, 0)
+        .unwrap();
+    world.insert_resource(CronClock::ticks(1).with_epoch(epoch));
+    world.spawn(ScheduleTimer::new("every hour").with_label("harvest"));
+    world.spawn(ScheduleTimer::every(Duration::from_secs(1)).with_label("coins"));
+    world.spawn(ScheduleTimer::new("every hour").with_label("harvest"));
+
+    let last_seen = epoch - chrono::Duration::hours(30) - chrono::Duration::minutes(30);
+    let report = offline_progress(&mut world, last_seen, OfflinePolicy::default());
+    assert_eq!(report.for_label("harvest"), 2 * 31);
+    assert_eq!(report.for_label("coins"), 10_000);
+    assert!(report.timers.iter().any(|progress| progress.capped));
+
+    let policy = OfflinePolicy::default()
+        .with_max_elapsed(Duration::from_secs(8 * 3600))
+        .with_max_occurrences(u64::MAX);
+    let report = offline_progress(&mut world, last_seen, policy);
+    assert_eq!(report.for_label("harvest"), 2 * 8);
+    assert_eq!(report.for_label("coins"), 8 * 3600);
+}
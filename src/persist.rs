@@ -0,0 +1,250 @@
+//! A versioned save format for the last-trigger state of timers.
+//!
+//! [`CronSave`] captures when every labeled job last fired and what its timer is waiting for,
+//! so a restarted game neither repeats nor forgets occurrences. Saves carry a
+//! [`version`](CronSave::version); older saves are upgraded through [`CronMigrations`] on load
+//! instead of silently resetting the state, and saves written by a newer version of the crate are
+//! rejected.
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_cronjob::persist::{CronMigrations, CronSave};
+//!
+//! fn load(world: &mut World, ron: &str) {
+//!     let save: CronSave = ron::from_str(ron).unwrap();
+//!     // version 0 called the job "backup", later versions "nightly-backup"
+//!     let migrations = CronMigrations::default().with_step(0, |save| save.rename("backup", "nightly-backup"));
+//!     save.migrate(&migrations).unwrap().restore(world);
+//! }
+//! ```
+
+use crate::{CronError, LastCronFire, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The format version written by this version of the crate
+pub const CRON_SAVE_VERSION: u32 = 1;
+
+/// The persisted last-trigger state, see the [module documentation](self)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CronSave {
+    /// format version, 0 for saves written before the format was versioned
+    #[serde(default)]
+    pub version: u32,
+    /// last fire of every label, see [`LastCronFire`]
+    #[serde(default)]
+    pub last_fires: BTreeMap<String, DateTime<chrono::Local>>,
+    /// state of every labeled timer
+    #[serde(default)]
+    pub timers: Vec<SavedTimer>,
+}
+
+impl Default for CronSave {
+    fn default() -> Self {
+        Self {
+            version: CRON_SAVE_VERSION,
+            last_fires: BTreeMap::new(),
+            timers: vec![],
+        }
+    }
+}
+
+/// The state of a labeled [`ScheduleTimer`] in a [`CronSave`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SavedTimer {
+    /// label of the timer
+    pub label: String,
+    /// the schedule when the save was written, a changed schedule does not get the pending
+    /// occurrence back
+    #[serde(default)]
+    pub schedule: Option<String>,
+    /// the pending occurrence
+    #[serde(default)]
+    pub next_fire: Option<DateTime<chrono::Local>>,
+    /// whether the timer was paused
+    #[serde(default)]
+    pub paused: bool,
+}
+
+type MigrationStep = Box<dyn Fn(&mut CronSave) + Send + Sync>;
+
+/// Upgrades of older [`CronSave`]s, one step per version
+#[derive(Default)]
+pub struct CronMigrations {
+    steps: Vec<(u32, MigrationStep)>,
+}
+
+impl CronMigrations {
+    /// Run `step` on saves of version `from`, before they are considered version `from + 1`.
+    /// Versions without step are upgraded as they are.
+    pub fn with_step(
+        mut self,
+        from: u32,
+        step: impl Fn(&mut CronSave) + Send + Sync + 'static,
+    ) -> Self {
+        self.steps.push((from, Box::new(step)));
+        self
+    }
+}
+
+impl CronSave {
+    /// Capture the last fires and the state of every labeled timer
+    pub fn capture(world: &mut World) -> Self {
+        let last_fires = world
+            .get_resource::<LastCronFire>()
+            .map(|last_fire| {
+                last_fire
+                    .iter()
+                    .map(|(label, time)| (label.to_string(), time))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut timers = world
+            .query::<&ScheduleTimer>()
+            .iter(world)
+            .filter_map(|timer| {
+                Some(SavedTimer {
+                    label: timer.label.clone()?,
+                    schedule: Some(timer.schedule.to_string()),
+                    next_fire: timer.local_schedule,
+                    paused: timer.paused,
+                })
+            })
+            .collect::<Vec<_>>();
+        timers.sort_by(|a, b| a.label.cmp(&b.label));
+        Self {
+            version: CRON_SAVE_VERSION,
+            last_fires,
+            timers,
+        }
+    }
+
+    /// Upgrade the save to [`CRON_SAVE_VERSION`]
+    pub fn migrate(mut self, migrations: &CronMigrations) -> Result<Self, CronError> {
+        if self.version > CRON_SAVE_VERSION {
+            return Err(CronError::UnsupportedSaveVersion {
+                version: self.version,
+                supported: CRON_SAVE_VERSION,
+            });
+        }
+        while self.version < CRON_SAVE_VERSION {
+            let version = self.version;
+            for (_, step) in migrations.steps.iter().filter(|(from, _)| *from == version) {
+                step(&mut self);
+            }
+            self.version += 1;
+        }
+        Ok(self)
+    }
+
+    /// Rename a label in the last fires and timers, for migration steps
+    pub fn rename(&mut self, from: &str, to: &str) {
+        if let Some(time) = self.last_fires.remove(from) {
+            self.last_fires.insert(to.to_string(), time);
+        }
+        for timer in self.timers.iter_mut().filter(|timer| timer.label == from) {
+            timer.label = to.to_string();
+        }
+    }
+
+    /// Restore the state onto the world: the last fires into [`LastCronFire`], inserted when
+    /// missing, and the pending occurrence and pause state onto the timers with the same label
+    /// and unchanged schedule. Returns how many timers were restored.
+    pub fn restore(&self, world: &mut World) -> usize {
+        if !self.last_fires.is_empty() {
+            let mut last_fire = world.get_resource_or_insert_with(LastCronFire::default);
+            for (label, time) in &self.last_fires {
+                last_fire.record(label, *time);
+            }
+        }
+        let mut restored = 0;
+        for mut timer in world.query::<&mut ScheduleTimer>().iter_mut(world) {
+            let Some(saved) = timer
+                .label
+                .as_deref()
+                .and_then(|label| self.timers.iter().find(|saved| saved.label == label))
+            else {
+                continue;
+            };
+            timer.paused = saved.paused;
+            if saved
+                .schedule
+                .as_ref()
+                .is_none_or(|schedule| *schedule == timer.schedule.to_string())
+            {
+                timer.local_schedule = saved.next_fire;
+            }
+            restored += 1;
+        }
+        restored
+    }
+}
+
+#[test]
+fn test_cron_save() {
+    use chrono::TimeZone;
+
+    let next = chrono::Local.with_ymd_and_hms(2030, 1, 1, 3, 0, 0).unwrap();
+    let mut world = World::new();
+    world.init_resource::<LastCronFire>();
+    world
+        .resource_mut::<LastCronFire>()
+        .record("backup", next - chrono::Duration::days(1));
+    let mut timer = ScheduleTimer::new("0 0 3 * * ? *").with_label("backup");
+    timer.local_schedule = Some(next);
+    world.spawn(timer);
+    world.spawn(ScheduleTimer::new("every hour"));
+
+    let save = CronSave::capture(&mut world);
+    assert_eq!(save.version, CRON_SAVE_VERSION);
+    assert_eq!(save.timers.len(), 1);
+    let ron = ron::to_string(&save).unwrap();
+
+    let mut restarted = World::new();
+    let entity = restarted
+        .spawn(ScheduleTimer::new("0 0 3 * * ? *").with_label("backup"))
+        .id();
+    let loaded: CronSave = ron::from_str(&ron).unwrap();
+    let loaded = loaded.migrate(&CronMigrations::default()).unwrap();
+    assert_eq!(loaded.restore(&mut restarted), 1);
+    assert_eq!(
+        restarted
+            .get::<ScheduleTimer>(entity)
+            .unwrap()
+            .local_schedule,
+        Some(next)
+    );
+    assert_eq!(
+        restarted.resource::<LastCronFire>().get("backup"),
+        Some(next - chrono::Duration::days(1))
+    );
+}
+
+#[test]
+fn test_cron_save_migration() {
+    // written before the format was versioned, with the old label
+    let old =
+        r#"(last_fires: {"backup": "2029-12-31T03:00:00+00:00"}, timers: [(label: "backup")])"#;
+    let save: CronSave = ron::from_str(old).unwrap();
+    assert_eq!(save.version, 0);
+    let migrations =
+        CronMigrations::default().with_step(0, |save| save.rename("backup", "nightly-backup"));
+    let save = save.migrate(&migrations).unwrap();
+    assert_eq!(save.version, CRON_SAVE_VERSION);
+    assert!(save.last_fires.contains_key("nightly-backup"));
+    assert_eq!(save.timers[0].label, "nightly-backup");
+
+    let newer = CronSave {
+        version: CRON_SAVE_VERSION + 1,
+        ..Default::default()
+    };
+    assert_eq!(
+        newer.migrate(&migrations),
+        Err(CronError::UnsupportedSaveVersion {
+            version: CRON_SAVE_VERSION + 1,
+            supported: CRON_SAVE_VERSION,
+        })
+    );
+}
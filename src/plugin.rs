@@ -0,0 +1,214 @@
+use crate::queue::CronTimerQueue;
+#[cfg(feature = "condition_registry")]
+use crate::CronConditionRegistry;
+use crate::{
+    calendar, clock, countdown, daily, edit, expire, forward, hybrid, idle, reset, scheduler,
+    selector, setter, slab, state, streak, system, timer, watchdog, window, CleanupOnSchedule,
+    CronClock, CronDispatch, CronFrameStats, CronIdle, CronJobConfig, CronScheduler, CronSlab,
+    CronSlabFired, CronWatchdog, DailySeed, GameClock, ScheduleExpression, ScheduleTimer,
+};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Time, Virtual};
+
+/// A Bevy plugin for running cron jobs
+///
+/// Adds [`CronConditionPlugin`], [`CronTimerPlugin`] and [`CronSlabPlugin`]. Apps that only need
+/// some of the subsystems, e.g. run conditions without the per-frame timer check, can add those
+/// plugins on their own instead. Sub-plugins that were already added are skipped.
+pub struct CronJobPlugin;
+
+impl Plugin for CronJobPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<CronConditionPlugin>() {
+            app.add_plugins(CronConditionPlugin);
+        }
+        if !app.is_plugin_added::<CronTimerPlugin>() {
+            app.add_plugins(CronTimerPlugin);
+        }
+        if !app.is_plugin_added::<CronSlabPlugin>() {
+            app.add_plugins(CronSlabPlugin);
+        }
+    }
+}
+
+/// Support for [`schedule_passed`](crate::schedule_passed) and the other run conditions.
+///
+/// The conditions work without any plugin; this one only inserts the
+/// [`CronConditionRegistry`](crate::CronConditionRegistry) with the `condition_registry` feature.
+pub struct CronConditionPlugin;
+
+impl Plugin for CronConditionPlugin {
+    fn build(&self, _app: &mut App) {
+        #[cfg(feature = "condition_registry")]
+        _app.init_resource::<CronConditionRegistry>();
+    }
+}
+
+/// Keeps the [`CronClock`] shared by [`CronTimerPlugin`] and [`CronSlabPlugin`], added by
+/// whichever of them comes first, and syncs a virtual clock with [`Time<Virtual>`].
+pub struct CronClockPlugin;
+
+impl Plugin for CronClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CronClock>().add_systems(
+            PreUpdate,
+            clock::sync_virtual_clock.run_if(resource_exists::<Time<Virtual>>),
+        );
+    }
+}
+
+/// Checks [`ScheduleTimer`] components and the helpers built on them, such as calendar, hybrid and
+/// reset timers, countdowns, state markers and the fire observers.
+pub struct CronTimerPlugin;
+
+impl Plugin for CronTimerPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<CronClockPlugin>() {
+            app.add_plugins(CronClockPlugin);
+        }
+        app.init_resource::<CronFrameStats>()
+            .init_resource::<CronDispatch>()
+            .init_resource::<CronJobConfig>()
+            .init_resource::<CronScheduler>()
+            .init_resource::<CronTimerQueue>()
+            .register_type::<ScheduleTimer>()
+            .register_type::<ScheduleExpression>()
+            .add_systems(
+                Update,
+                timer::check_schedule_timer.run_if(not(resource_equals(CronDispatch::Immediate))),
+            )
+            // ordered before the deferred check, so systems after it also follow this one
+            .add_systems(
+                Update,
+                timer::check_schedule_timer_immediate
+                    .before(timer::check_schedule_timer)
+                    .run_if(resource_equals(CronDispatch::Immediate)),
+            )
+            .add_systems(
+                Update,
+                edit::apply_expression_edits.before(timer::check_schedule_timer_immediate),
+            )
+            .add_systems(
+                Update,
+                (
+                    expire::resolve_expiry,
+                    expire::sweep_expired.run_if(resource_exists::<CleanupOnSchedule>),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                edit::report_schedule_fallbacks
+                    .after(edit::apply_expression_edits)
+                    .before(timer::check_schedule_timer_immediate),
+            )
+            .add_systems(
+                Update,
+                watchdog::watch_overdue_timers
+                    .after(timer::check_schedule_timer)
+                    .run_if(resource_exists::<CronWatchdog>),
+            )
+            .add_systems(
+                Update,
+                state::sync_state_markers.after(timer::check_schedule_timer),
+            )
+            .add_systems(
+                Update,
+                countdown::update_countdowns.after(timer::check_schedule_timer),
+            )
+            .add_systems(
+                Last,
+                idle::sleep_until_next_fire.run_if(resource_exists::<CronIdle>),
+            )
+            .add_systems(
+                Update,
+                calendar::check_game_timers.run_if(resource_exists::<GameClock>),
+            )
+            .add_systems(Update, hybrid::check_hybrid_timers)
+            .add_systems(
+                Update,
+                daily::rotate_daily_seed.run_if(resource_exists::<DailySeed>),
+            )
+            .add_systems(Update, reset::check_reset_periods)
+            .add_systems(Update, scheduler::run_scheduled_jobs)
+            .add_systems(Update, window::update_schedule_windows)
+            .add_systems(FixedUpdate, timer::count_fixed_ticks)
+            .add_observer(selector::trigger_weighted_event)
+            .add_observer(setter::set_resources_on_schedule)
+            .add_observer(streak::close_streak_periods)
+            .add_observer(forward::forward_fires)
+            .add_observer(system::run_cron_systems);
+        #[cfg(feature = "bevy_tweening")]
+        app.add_systems(Update, crate::tween::start_reverse_tweens)
+            .add_observer(crate::tween::start_scheduled_tween);
+        #[cfg(feature = "async")]
+        app.init_resource::<crate::future::CronFutures>()
+            .add_systems(
+                Update,
+                crate::future::wake_cron_futures.after(timer::check_schedule_timer),
+            )
+            .add_observer(crate::future::complete_timer_futures);
+        #[cfg(feature = "bevy_ui")]
+        app.add_systems(
+            Update,
+            crate::ui::update_countdown_texts.after(countdown::update_countdowns),
+        );
+        #[cfg(feature = "dev")]
+        app.add_observer(crate::screenshot::take_scheduled_screenshot);
+        #[cfg(feature = "metrics")]
+        app.add_systems(
+            Update,
+            crate::metrics::publish_gauges.after(timer::check_schedule_timer),
+        );
+        #[cfg(feature = "lifecycle")]
+        app.add_event::<bevy_window::AppLifecycle>().add_systems(
+            Update,
+            crate::lifecycle::handle_app_lifecycle
+                .before(timer::check_schedule_timer_immediate)
+                .run_if(resource_exists::<crate::CronLifecycle>),
+        );
+    }
+}
+
+/// Dispatches the jobs of a [`CronSlab`] resource as [`CronSlabFired`] events.
+///
+/// Servers that only schedule through the slab can add this plugin without [`CronTimerPlugin`].
+pub struct CronSlabPlugin;
+
+impl Plugin for CronSlabPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<CronClockPlugin>() {
+            app.add_plugins(CronClockPlugin);
+        }
+        app.add_event::<CronSlabFired>().add_systems(
+            Update,
+            slab::dispatch_slab_jobs.run_if(resource_exists::<CronSlab>),
+        );
+    }
+}
+
+#[test]
+fn test_sub_plugins() {
+    // conditions only: no timer check, so timers are never armed
+    let mut app = App::new();
+    app.add_plugins(CronConditionPlugin);
+    let timer = app.world_mut().spawn(ScheduleTimer::new("every hour")).id();
+    app.update();
+    assert!(!app.world().contains_resource::<CronClock>());
+    assert!(app.world().get::<crate::CronArmed>(timer).is_none());
+
+    // slab only
+    let mut app = App::new();
+    app.add_plugins(CronSlabPlugin).init_resource::<CronSlab>();
+    app.update();
+    assert!(app.world().contains_resource::<CronClock>());
+    assert!(!app.world().contains_resource::<CronFrameStats>());
+
+    // sub-plugins added up front are not added twice
+    let mut app = App::new();
+    app.add_plugins((CronTimerPlugin, CronJobPlugin));
+    app.update();
+    assert!(app.is_plugin_added::<CronSlabPlugin>());
+    assert!(app.is_plugin_added::<CronClockPlugin>());
+}
@@ -0,0 +1,160 @@
+use crate::ScheduleTimer;
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::entity::{EntityHashMap, EntityHashSet};
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::DeferredWorld;
+use chrono::DateTime;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The pending occurrences of the armed timers in a min-heap, so the timer check only visits the
+/// timers that are due instead of evaluating every timer each frame.
+///
+/// Timers that must be visited on every check, i.e. unarmed, step, frame and scaled timers, are
+/// kept aside. Inserted and removed timers are tracked through the hooks of [`ScheduleTimer`].
+/// Changed timers are visited again, paused and expired ones are only indexed again once they
+/// change.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct CronTimerQueue {
+    heap: BinaryHeap<Reverse<(DateTime<chrono::Local>, Entity)>>,
+    /// the occurrence each timer is queued for, heap entries not matching it are stale
+    queued: EntityHashMap<DateTime<chrono::Local>>,
+    every_check: EntityHashSet,
+}
+
+impl CronTimerQueue {
+    /// Visit a timer inserted on `entity` on the next check
+    pub(crate) fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        if let Some(mut queue) = world.get_resource_mut::<Self>() {
+            queue.revisit([entity]);
+        }
+    }
+
+    /// Forget the timer removed from `entity`
+    pub(crate) fn on_remove(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        if let Some(mut queue) = world.get_resource_mut::<Self>() {
+            queue.remove(entity);
+        }
+    }
+
+    /// Forget the timer of `entity`
+    pub(crate) fn remove(&mut self, entity: Entity) {
+        self.queued.remove(&entity);
+        self.every_check.remove(&entity);
+    }
+
+    /// Visit the timers of `entities` on the next check, e.g. because they changed
+    pub(crate) fn revisit(&mut self, entities: impl IntoIterator<Item = Entity>) {
+        self.every_check.extend(entities);
+    }
+
+    /// Take the timers to visit at `now`, ordered by entity: those visited on every check and
+    /// those whose occurrence passed
+    pub(crate) fn take_due(&mut self, now: DateTime<chrono::Local>) -> Vec<Entity> {
+        let mut due = std::mem::take(&mut self.every_check);
+        while let Some(&Reverse((at, entity))) = self.heap.peek() {
+            if at >= now {
+                break;
+            }
+            self.heap.pop();
+            if self.queued.get(&entity) == Some(&at) {
+                self.queued.remove(&entity);
+                due.insert(entity);
+            }
+        }
+        let mut due: Vec<_> = due.into_iter().collect();
+        due.sort_unstable();
+        due
+    }
+
+    /// Index `timer` of `entity` again after it was visited
+    pub(crate) fn index(&mut self, entity: Entity, timer: &ScheduleTimer) {
+        if timer.paused || timer.expired {
+            self.remove(entity);
+            return;
+        }
+        match timer.local_schedule.filter(|_| !timer.needs_every_check()) {
+            Some(at) => {
+                self.every_check.remove(&entity);
+                if self.queued.insert(entity, at) != Some(at) {
+                    self.heap.push(Reverse((at, entity)));
+                }
+            }
+            None => {
+                self.queued.remove(&entity);
+                self.every_check.insert(entity);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_timer_queue() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{CronAppExt, CronClock, CronFrameStats, ScheduleArrived};
+    use bevy_app::App;
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>();
+    let fired = |app: &App| {
+        app.triggered::<ScheduleArrived>()
+            .iter()
+            .filter_map(|(_, fire)| fire.label.clone())
+            .collect::<Vec<_>>()
+    };
+    for minutes in 1..=100 {
+        app.world_mut().spawn(
+            ScheduleTimer::every(std::time::Duration::from_secs(60 * minutes))
+                .with_label(minutes.to_string()),
+        );
+    }
+    app.update();
+    assert_eq!(
+        app.world().resource::<CronFrameStats>().timers_evaluated,
+        100
+    );
+
+    // only the timers due by 2 min 2 s are visited
+    app.advance_cron_time(std::time::Duration::from_secs(121));
+    let stats = app.world().resource::<CronFrameStats>();
+    assert_eq!((stats.timers_evaluated, stats.timers_total()), (2, 100));
+    assert_eq!(fired(&app), ["1", "2"]);
+
+    // changed timers are visited again
+    let now = app.world().resource::<CronClock>().now();
+    let mut timers = app.world_mut().query::<&mut ScheduleTimer>();
+    for mut timer in timers.iter_mut(app.world_mut()) {
+        if timer.label() == Some("100") {
+            timer.local_schedule = Some(now - chrono::Duration::seconds(1));
+        }
+    }
+    app.update();
+    assert_eq!(app.world().resource::<CronFrameStats>().timers_evaluated, 1);
+    assert_eq!(fired(&app), ["1", "2", "100"]);
+}
+
+#[test]
+fn test_timer_queue_hooks() {
+    let mut world = World::new();
+    world.init_resource::<CronTimerQueue>();
+    let entity = world.spawn(ScheduleTimer::new("every hour")).id();
+    assert!(world
+        .resource::<CronTimerQueue>()
+        .every_check
+        .contains(&entity));
+
+    let now = chrono::Local::now();
+    let mut timer = ScheduleTimer::new("every hour");
+    timer.local_schedule = Some(now);
+    world.resource_mut::<CronTimerQueue>().index(entity, &timer);
+    assert!(world
+        .resource::<CronTimerQueue>()
+        .queued
+        .contains_key(&entity));
+
+    // despawned timers are forgotten right away
+    world.despawn(entity);
+    let mut queue = world.resource_mut::<CronTimerQueue>();
+    assert!(queue.queued.is_empty() && queue.every_check.is_empty());
+    assert!(queue.take_due(now + chrono::Duration::hours(1)).is_empty());
+}
@@ -0,0 +1,125 @@
+use crate::CronTimezone;
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, NaiveTime};
+
+/// Daily windows during which no [`ScheduleTimer`](crate::ScheduleTimer) fires, e.g. a
+/// maintenance window from 02:00 to 04:00.
+///
+/// With this resource present, the windows override every timer: fires inside them are dropped
+/// or deferred according to the [`QuietPolicy`]. Windows whose end is not after their start wrap
+/// over midnight.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use chrono::NaiveTime;
+///
+/// App::new().add_plugins(CronJobPlugin).insert_resource(
+///     CronQuietHours::new(QuietPolicy::Defer)
+///         .with_window(
+///             NaiveTime::from_hms_opt(2, 0, 0).unwrap(),
+///             NaiveTime::from_hms_opt(4, 0, 0).unwrap(),
+///         )
+///         .with_timezone(CronTimezone::Utc),
+/// );
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CronQuietHours {
+    /// start and end of each window
+    pub windows: Vec<(NaiveTime, NaiveTime)>,
+    /// timezone the windows are evaluated in
+    pub timezone: CronTimezone,
+    /// what happens to fires inside a window
+    pub policy: QuietPolicy,
+}
+
+/// What happens to fires during [`CronQuietHours`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum QuietPolicy {
+    /// drop the fires, triggering [`ScheduleSkipped`](crate::ScheduleSkipped) instead
+    #[default]
+    Suppress,
+    /// hold the timers until the window ends, then fire the missed occurrences according to
+    /// their [alignment](crate::ScheduleAlignment)
+    Defer,
+}
+
+impl CronQuietHours {
+    /// Quiet hours without windows yet
+    pub fn new(policy: QuietPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    /// Add a window from `start` to `end`
+    pub fn with_window(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.windows.push((start, end));
+        self
+    }
+
+    /// Evaluate the windows in `timezone` instead of the local timezone
+    pub fn with_timezone(mut self, timezone: impl Into<CronTimezone>) -> Self {
+        self.timezone = timezone.into();
+        self
+    }
+
+    /// Whether `now` is inside one of the windows
+    pub fn is_quiet(&self, now: &DateTime<chrono::Local>) -> bool {
+        let time = self.timezone.time_of(now);
+        self.windows.iter().any(|&(start, end)| {
+            if start < end {
+                start <= time && time < end
+            } else {
+                start <= time || time < end
+            }
+        })
+    }
+}
+
+#[test]
+fn test_quiet_hours() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{CronAppExt, CronClock, ScheduleArrived, ScheduleSkipped, ScheduleTimer};
+
+    let hour = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+    let quiet = CronQuietHours::new(QuietPolicy::Suppress)
+        .with_window(hour(2), hour(4))
+        .with_window(hour(23), hour(1))
+        .with_timezone(CronTimezone::Utc);
+    let at = |hour: i64| {
+        CronClock::ticks(1).now() + chrono::Duration::hours(hour) + chrono::Duration::minutes(30)
+    };
+    assert!(quiet.is_quiet(&at(2)));
+    assert!(quiet.is_quiet(&at(3)));
+    assert!(!quiet.is_quiet(&at(4)));
+    assert!(quiet.is_quiet(&at(23)));
+    assert!(quiet.is_quiet(&at(24)));
+    assert!(!quiet.is_quiet(&at(25)));
+
+    for (policy, arrived, skipped) in [(QuietPolicy::Suppress, 4, 2), (QuietPolicy::Defer, 3, 0)] {
+        let mut app = cron_app();
+        app.insert_resource(CronQuietHours {
+            policy,
+            ..quiet.clone()
+        })
+        .record::<ScheduleArrived>()
+        .record::<ScheduleSkipped>();
+        app.world_mut()
+            .spawn(ScheduleTimer::new("every hour").with_timezone(CronTimezone::Utc));
+        // hourly from 00:00:01 to 06:00:01 UTC, deferred timers are only armed at 01:00:01 and
+        // fire 02:00 at 04:00:01
+        app.update();
+        for _ in 0..6 {
+            app.advance_cron_time(std::time::Duration::from_secs(3600));
+        }
+        assert_eq!(
+            (
+                app.triggered::<ScheduleArrived>().len(),
+                app.triggered::<ScheduleSkipped>().len()
+            ),
+            (arrived, skipped)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{CronTimezone, PeriodId, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, Datelike, NaiveDate};
+
+/// The period a [`FireQuota`] is counted over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuotaPeriod {
+    /// calendar days
+    Day,
+    /// ISO weeks, starting on Monday
+    Week,
+}
+
+impl QuotaPeriod {
+    /// The period `datetime` belongs to, with days evaluated in `timezone`
+    pub fn period_of(
+        &self,
+        datetime: &DateTime<chrono::Local>,
+        timezone: CronTimezone,
+    ) -> PeriodId {
+        let date = timezone.date_of(datetime);
+        match self {
+            QuotaPeriod::Day => PeriodId::Day((date - NaiveDate::default()).num_days()),
+            QuotaPeriod::Week => {
+                let week = date.iso_week();
+                PeriodId::Week {
+                    year: week.year(),
+                    week: week.week(),
+                }
+            }
+        }
+    }
+}
+
+/// Most fires of a timer per day or week, see [`ScheduleTimer::with_quota`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FireQuota {
+    /// fires allowed per period
+    pub limit: u32,
+    /// the period the fires are counted over
+    pub per: QuotaPeriod,
+    /// fires counted in [`FireQuota::period`]
+    pub used: u32,
+    /// the period of the last counted fire
+    pub period: Option<PeriodId>,
+}
+
+impl FireQuota {
+    /// Allow `limit` fires per period
+    pub fn new(limit: u32, per: QuotaPeriod) -> Self {
+        Self {
+            limit,
+            per,
+            used: 0,
+            period: None,
+        }
+    }
+
+    /// Fires left in the period of the last counted fire
+    pub fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.used)
+    }
+
+    /// Count a fire in `period`, starting over when it is a new one. Returns `false` without
+    /// counting when the quota of the period is used up.
+    pub(crate) fn take(&mut self, period: PeriodId) -> bool {
+        if self.period != Some(period) {
+            self.period = Some(period);
+            self.used = 0;
+        }
+        if self.used >= self.limit {
+            return false;
+        }
+        self.used += 1;
+        true
+    }
+}
+
+/// Triggered on a timer entity when a fire uses up its [quota](ScheduleTimer::with_quota), right
+/// before [`ScheduleArrived`](crate::ScheduleArrived). Further occurrences in the period trigger
+/// [`ScheduleSkipped`](crate::ScheduleSkipped) instead of firing.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct QuotaExhausted {
+    /// label of the timer
+    pub label: Option<String>,
+    /// fires allowed per period
+    pub limit: u32,
+    /// the period whose quota is used up
+    pub period: PeriodId,
+}
+
+impl ScheduleTimer {
+    /// Fire at most `limit` times per day or week, even if the schedule matches more often.
+    ///
+    /// Days are evaluated in the timezone of the timer, the count starts over on the first
+    /// occurrence of a new period.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// // a random event every 10 minutes, at most 3 times a day
+    /// let timer = ScheduleTimer::new("every 10 minutes").with_quota(3, QuotaPeriod::Day);
+    /// ```
+    pub fn with_quota(mut self, limit: u32, per: QuotaPeriod) -> Self {
+        self.quota = Some(FireQuota::new(limit, per));
+        self
+    }
+
+    /// Whether the quota allows an occurrence at `scheduled`, counting it
+    pub(crate) fn take_quota(
+        &mut self,
+        scheduled: &DateTime<chrono::Local>,
+        timezone: CronTimezone,
+    ) -> bool {
+        match &mut self.quota {
+            Some(quota) => {
+                let period = quota.per.period_of(scheduled, timezone);
+                quota.take(period)
+            }
+            None => true,
+        }
+    }
+}
+
+#[test]
+fn test_quota() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{CronAppExt, ScheduleArrived, ScheduleSkipped};
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>()
+        .record::<ScheduleSkipped>()
+        .record::<QuotaExhausted>();
+    app.world_mut().spawn(
+        ScheduleTimer::new("every hour")
+            .with_timezone(CronTimezone::Utc)
+            .with_quota(2, QuotaPeriod::Day),
+    );
+    // hourly for two days from 2000-01-01 00:00:01 UTC
+    for _ in 0..(2 * 24) {
+        app.advance_cron_time(std::time::Duration::from_secs(3600));
+    }
+
+    // armed at 01:00, 47 occurrences pass until 2000-01-03 00:00, the last one fires on a new day
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 2 + 2 + 1);
+    assert_eq!(app.triggered::<ScheduleSkipped>().len(), 47 - 5);
+    let exhausted = app
+        .triggered::<QuotaExhausted>()
+        .iter()
+        .map(|(_, exhausted)| exhausted.period)
+        .collect::<Vec<_>>();
+    assert_eq!(exhausted, [PeriodId::Day(10957), PeriodId::Day(10958)]);
+}
@@ -0,0 +1,113 @@
+//! Replicate timers to [`bevy_replicon`] clients.
+
+use crate::ScheduleTimer;
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+use bevy_replicon::prelude::{server_or_singleplayer, AppRuleExt};
+use chrono::{DateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Replicates the expression and next fire of server timers to clients.
+///
+/// The server keeps a [`ReplicatedSchedule`] next to every [`ScheduleTimer`], which is replicated
+/// for entities marked with [`Replicated`](bevy_replicon::prelude::Replicated), so clients can
+/// render countdowns for server-authoritative events. Add it after
+/// [`RepliconPlugins`](bevy_replicon::prelude::RepliconPlugins).
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use bevy_cronjob::replicon::{CronReplicationPlugin, ReplicatedSchedule};
+///
+/// fn countdown(schedules: Query<&ReplicatedSchedule>) {
+///     for schedule in &schedules {
+///         if let Some(next_fire) = schedule.next_fire() {
+///             info!("{:?} in {}", schedule.label, next_fire - chrono::Local::now());
+///         }
+///     }
+/// }
+/// ```
+pub struct CronReplicationPlugin;
+
+impl Plugin for CronReplicationPlugin {
+    fn build(&self, app: &mut App) {
+        app.replicate::<ReplicatedSchedule>().add_systems(
+            Update,
+            sync_replicated_schedules
+                .after(crate::timer::check_schedule_timer)
+                .run_if(server_or_singleplayer),
+        );
+    }
+}
+
+/// Network view of a [`ScheduleTimer`], kept up to date by [`CronReplicationPlugin`]
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplicatedSchedule {
+    /// label of the timer
+    pub label: Option<String>,
+    /// human readable schedule, e.g. the cron expression
+    pub schedule: String,
+    /// next planned fire as milliseconds since the unix epoch
+    pub next_fire_millis: Option<i64>,
+}
+
+impl ReplicatedSchedule {
+    /// Mirror the state of `timer`
+    pub fn from_timer(timer: &ScheduleTimer) -> Self {
+        Self {
+            label: timer.label.clone(),
+            schedule: timer.schedule.to_string(),
+            next_fire_millis: timer.local_schedule.map(|time| time.timestamp_millis()),
+        }
+    }
+
+    /// Next planned fire in the local timezone
+    pub fn next_fire(&self) -> Option<DateTime<chrono::Local>> {
+        self.next_fire_millis
+            .and_then(|millis| chrono::Local.timestamp_millis_opt(millis).single())
+    }
+}
+
+pub(crate) fn sync_replicated_schedules(
+    mut query: Query<
+        (Entity, &ScheduleTimer, Option<&mut ReplicatedSchedule>),
+        Changed<ScheduleTimer>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, timer, replicated) in query.iter_mut() {
+        let current = ReplicatedSchedule::from_timer(timer);
+        match replicated {
+            // only touch the component when something changed, to not resend it every frame
+            Some(mut replicated) => {
+                replicated.set_if_neq(current);
+            }
+            None => {
+                commands.entity(entity).insert(current);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_replicated_schedule() {
+    use crate::testing::cron_app;
+    use bevy_replicon::prelude::RepliconCorePlugin;
+
+    let mut app = cron_app();
+    app.add_plugins((RepliconCorePlugin, CronReplicationPlugin));
+    let entity = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_label("raid"))
+        .id();
+    app.update();
+    app.update();
+
+    let timer = app.world().get::<ScheduleTimer>(entity).unwrap();
+    let replicated = app.world().get::<ReplicatedSchedule>(entity).unwrap();
+    assert_eq!(replicated.label.as_deref(), Some("raid"));
+    assert_eq!(
+        replicated.next_fire().map(|time| time.timestamp()),
+        timer.local_schedule.map(|time| time.timestamp())
+    );
+}
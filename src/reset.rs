@@ -0,0 +1,222 @@
+use crate::{CronClock, CronTimezone};
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Weekday};
+use std::fmt;
+use std::str::FromStr;
+
+/// Identifies a daily or weekly reset period, stable across machines and restarts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PeriodId {
+    /// days since 1970-01-01, displayed as the number
+    Day(i64),
+    /// ISO year and week, displayed as `2025-W23`
+    Week {
+        /// the ISO year
+        year: i32,
+        /// the ISO week (1-53)
+        week: u32,
+    },
+}
+
+impl fmt::Display for PeriodId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodId::Day(day) => write!(f, "{day}"),
+            PeriodId::Week { year, week } => write!(f, "{year}-W{week:02}"),
+        }
+    }
+}
+
+impl FromStr for PeriodId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once("-W") {
+            Some((year, week)) => Ok(PeriodId::Week {
+                year: year.parse()?,
+                week: week.parse()?,
+            }),
+            None => Ok(PeriodId::Day(s.parse()?)),
+        }
+    }
+}
+
+/// Daily or weekly boundaries for quests and shop rotations.
+///
+/// [`CronJobPlugin`](crate::CronJobPlugin) triggers [`ResetPeriodElapsed`] on the entity when a
+/// boundary passes. Persist [`last_period`](Self::last_period) and restore it with
+/// [`with_last_period`](Self::with_last_period): after a restart in a later period the reset
+/// fires once on the first frame, while a fresh reset without a last period starts silently.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use chrono::Weekday;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(ResetPeriod::daily().at(4, 0).with_label("quests"));
+///     commands
+///         .spawn(ResetPeriod::weekly(Weekday::Mon).with_label("shop"))
+///         .observe(|trigger: Trigger<ResetPeriodElapsed>| {
+///             info!("shop rotation {}", trigger.event().current);
+///         });
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ResetPeriod {
+    /// start day of weekly periods, `None` for daily periods
+    pub weekday: Option<Weekday>,
+    /// time of day of the boundary
+    pub time: NaiveTime,
+    /// timezone of the boundary
+    pub timezone: CronTimezone,
+    /// name carried by [`ResetPeriodElapsed`]
+    pub label: Option<String>,
+    last_period: Option<PeriodId>,
+}
+
+impl ResetPeriod {
+    /// Reset every day at midnight UTC
+    pub fn daily() -> Self {
+        Self {
+            weekday: None,
+            time: NaiveTime::MIN,
+            timezone: CronTimezone::Utc,
+            label: None,
+            last_period: None,
+        }
+    }
+
+    /// Reset every week on `weekday` at midnight UTC
+    pub fn weekly(weekday: Weekday) -> Self {
+        Self {
+            weekday: Some(weekday),
+            ..Self::daily()
+        }
+    }
+
+    /// Move the boundary to `hour:minute`
+    pub fn at(mut self, hour: u32, minute: u32) -> Self {
+        self.time = NaiveTime::from_hms_opt(hour, minute, 0).expect("invalid reset time");
+        self
+    }
+
+    /// Evaluate the boundary in `timezone` instead of UTC
+    pub fn with_timezone(mut self, timezone: impl Into<CronTimezone>) -> Self {
+        self.timezone = timezone.into();
+        self
+    }
+
+    /// Set the label carried by [`ResetPeriodElapsed`]
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Resume from a persisted period
+    pub fn with_last_period(mut self, period: PeriodId) -> Self {
+        self.last_period = Some(period);
+        self
+    }
+
+    /// The period seen last, `None` before the first update
+    pub fn last_period(&self) -> Option<PeriodId> {
+        self.last_period
+    }
+
+    /// The period `now` belongs to
+    pub fn period_at(&self, now: &DateTime<chrono::Local>) -> PeriodId {
+        let shifted = *now - self.time.signed_duration_since(NaiveTime::MIN);
+        let date = self.timezone.date_of(&shifted);
+        match self.weekday {
+            None => PeriodId::Day((date - NaiveDate::default()).num_days()),
+            Some(start) => {
+                // ISO weeks start on Monday, align the start day with it
+                let date = date - chrono::Duration::days(start.num_days_from_monday() as i64);
+                let week = date.iso_week();
+                PeriodId::Week {
+                    year: week.year(),
+                    week: week.week(),
+                }
+            }
+        }
+    }
+}
+
+/// Triggered on a [`ResetPeriod`] entity when a boundary passes
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ResetPeriodElapsed {
+    /// label of the reset
+    pub label: Option<String>,
+    /// the period that ended
+    pub previous: PeriodId,
+    /// the period that started
+    pub current: PeriodId,
+}
+
+pub(crate) fn check_reset_periods(
+    clock: Res<CronClock>,
+    mut resets: Query<(Entity, &mut ResetPeriod)>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    for (entity, mut reset) in resets.iter_mut() {
+        let current = reset.period_at(&now);
+        if reset.last_period == Some(current) {
+            continue;
+        }
+        if let Some(previous) = reset.last_period {
+            commands.trigger_targets(
+                ResetPeriodElapsed {
+                    label: reset.label.clone(),
+                    previous,
+                    current,
+                },
+                entity,
+            );
+        }
+        reset.last_period = Some(current);
+    }
+}
+
+#[test]
+fn test_reset_period() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    // 2000-01-01 00:00 UTC is a Saturday
+    let mut app = cron_app();
+    app.record::<ResetPeriodElapsed>();
+    app.world_mut()
+        .spawn(ResetPeriod::daily().at(4, 0).with_label("quests"));
+    app.world_mut().spawn(
+        ResetPeriod::weekly(Weekday::Mon)
+            .with_label("shop")
+            .with_last_period("1999-W50".parse().unwrap()),
+    );
+    app.update();
+    for _ in 1..(3 * 24) {
+        app.advance_cron_time(std::time::Duration::from_secs(3600));
+    }
+
+    let elapsed = app.triggered::<ResetPeriodElapsed>();
+    let labeled = |label: &str| {
+        elapsed
+            .iter()
+            .map(|(_, event)| event)
+            .filter(|event| event.label.as_deref() == Some(label))
+            .map(|event| (event.previous.to_string(), event.current.to_string()))
+            .collect::<Vec<_>>()
+    };
+    // restored in a later week: fires on the first frame, then on Monday
+    assert_eq!(
+        labeled("shop"),
+        [("1999-W50", "1999-W52"), ("1999-W52", "2000-W01")].map(|(a, b)| (a.into(), b.into()))
+    );
+    // starts silently at 1999-12-31, resets at 04:00 on Jan 1st, 2nd and 3rd
+    assert_eq!(
+        labeled("quests"),
+        [("10956", "10957"), ("10957", "10958"), ("10958", "10959")]
+            .map(|(a, b)| (a.into(), b.into()))
+    );
+}
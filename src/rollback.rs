@@ -0,0 +1,183 @@
+use crate::{PeriodId, ScheduleTimer, TimerSchedule};
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, NaiveDate};
+
+/// The runtime state of a [`ScheduleTimer`] as plain data.
+///
+/// Everything that changes while a timer runs is captured, including the state of the random
+/// number generator of random schedules, so restoring a snapshot and replaying the same frames
+/// fires the same occurrences. This makes cron-driven gameplay compatible with rollback
+/// networking: save a snapshot every frame and restore the one of the frame to roll back to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimerSnapshot {
+    /// the pending occurrence
+    pub local_schedule: Option<DateTime<chrono::Local>>,
+    /// whether the timer is paused
+    pub paused: bool,
+    /// whether the schedule had no occurrence left
+    pub expired: bool,
+    /// state of the random number generator of random schedules
+    pub rng_state: Option<u64>,
+    /// day of the last window of [`TimerSchedule::RandomDailyBetween`]
+    pub last_window: Option<NaiveDate>,
+    /// the clock time and the time of the timer at its last check, for timers running at another
+    /// [speed](ScheduleTimer::with_speed)
+    pub speed_anchor: Option<(DateTime<chrono::Local>, DateTime<chrono::Local>)>,
+    /// fires counted against the [quota](ScheduleTimer::with_quota) and their period
+    pub quota_used: Option<(u32, Option<PeriodId>)>,
+    /// frames or fixed ticks counted by [step schedules](ScheduleTimer::every_frames), and the
+    /// fixed ticks not yet checked
+    pub steps: (u64, u64),
+    /// missed occurrences counted by the [catch-up policy](ScheduleTimer::with_catch_up)
+    pub caught_up: u64,
+    /// fires so far
+    pub runs: u32,
+    /// when the timer last fired
+    pub last_run: Option<DateTime<chrono::Local>>,
+    /// offset of the pending fire and state of the random number generator of the
+    /// [jitter](ScheduleTimer::with_jitter), if any
+    pub jitter: Option<(chrono::Duration, u64)>,
+}
+
+impl ScheduleTimer {
+    /// Capture the runtime state of the timer
+    pub fn snapshot(&self) -> TimerSnapshot {
+        let (rng_state, last_window) = match &self.schedule {
+            TimerSchedule::Cron(_)
+            | TimerSchedule::Interval(_)
+            | TimerSchedule::Frames(_)
+            | TimerSchedule::FixedTicks(_)
+            | TimerSchedule::Once(_) => (None, None),
+            TimerSchedule::RandomDailyBetween {
+                rng, last_window, ..
+            } => (Some(rng.get_seed()), *last_window),
+            TimerSchedule::Poisson { rng, .. } => (Some(rng.get_seed()), None),
+        };
+        TimerSnapshot {
+            local_schedule: self.local_schedule,
+            paused: self.paused,
+            expired: self.expired,
+            rng_state,
+            last_window,
+            speed_anchor: self.speed_anchor,
+            quota_used: self.quota.as_ref().map(|quota| (quota.used, quota.period)),
+            steps: (self.steps, self.pending_ticks),
+            caught_up: self.caught_up,
+            runs: self.runs,
+            last_run: self.last_run,
+            jitter: self
+                .jitter
+                .map(|_| (self.jitter_offset, self.jitter_rng.get_seed())),
+        }
+    }
+
+    /// Return to the runtime state captured by [`ScheduleTimer::snapshot`]
+    pub fn restore(&mut self, snapshot: &TimerSnapshot) {
+        self.local_schedule = snapshot.local_schedule;
+        self.paused = snapshot.paused;
+        self.expired = snapshot.expired;
+        self.speed_anchor = snapshot.speed_anchor;
+        (self.steps, self.pending_ticks) = snapshot.steps;
+        self.caught_up = snapshot.caught_up;
+        self.runs = snapshot.runs;
+        self.last_run = snapshot.last_run;
+        if let Some((offset, rng_state)) = snapshot.jitter {
+            self.jitter_offset = offset;
+            self.jitter_rng.seed(rng_state);
+        }
+        if let (Some(quota), Some((used, period))) = (&mut self.quota, snapshot.quota_used) {
+            quota.used = used;
+            quota.period = period;
+        }
+        match &mut self.schedule {
+            TimerSchedule::Cron(_)
+            | TimerSchedule::Interval(_)
+            | TimerSchedule::Frames(_)
+            | TimerSchedule::FixedTicks(_)
+            | TimerSchedule::Once(_) => {}
+            TimerSchedule::RandomDailyBetween {
+                rng, last_window, ..
+            } => {
+                if let Some(state) = snapshot.rng_state {
+                    rng.seed(state);
+                }
+                *last_window = snapshot.last_window;
+            }
+            TimerSchedule::Poisson { rng, .. } => {
+                if let Some(state) = snapshot.rng_state {
+                    rng.seed(state);
+                }
+            }
+        }
+    }
+}
+
+/// The runtime state of all timers of a world, see [`TimerSnapshot`]
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// // save every frame
+/// fn save(world: &mut World) -> CronSnapshot {
+///     CronSnapshot::save(world)
+/// }
+///
+/// // and restore the frame to roll back to before resimulating
+/// fn rollback(world: &mut World, saved: &CronSnapshot) {
+///     saved.restore(world);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CronSnapshot {
+    /// the state of each timer entity
+    pub timers: Vec<(Entity, TimerSnapshot)>,
+}
+
+impl CronSnapshot {
+    /// Capture the state of every [`ScheduleTimer`] in the world
+    pub fn save(world: &mut World) -> Self {
+        let timers = world
+            .query::<(Entity, &ScheduleTimer)>()
+            .iter(world)
+            .map(|(entity, timer)| (entity, timer.snapshot()))
+            .collect();
+        Self { timers }
+    }
+
+    /// Restore the captured state onto the timers that still exist
+    pub fn restore(&self, world: &mut World) {
+        for (entity, snapshot) in &self.timers {
+            if let Some(mut timer) = world.get_mut::<ScheduleTimer>(*entity) {
+                timer.restore(snapshot);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_snapshot_restore() {
+    use crate::CronTimezone;
+    use std::time::Duration;
+
+    let mut world = World::new();
+    let now = chrono::Local::now();
+    let mut timer = ScheduleTimer::poisson(Duration::from_secs(60)).with_seed(3);
+    timer.poll(now, CronTimezone::Local);
+    let entity = world.spawn(timer).id();
+
+    let saved = CronSnapshot::save(&mut world);
+    let replay = |world: &mut World| {
+        let mut timer = world.get_mut::<ScheduleTimer>(entity).unwrap();
+        let mut fires = vec![];
+        let mut at = now;
+        for _ in 0..5 {
+            at += chrono::Duration::minutes(10);
+            fires.extend(timer.poll(at, CronTimezone::Local));
+        }
+        (fires, timer.local_schedule)
+    };
+    let first = replay(&mut world);
+    saved.restore(&mut world);
+    assert_eq!(replay(&mut world), first);
+}
@@ -0,0 +1,103 @@
+use crate::{ScheduleTimer, TimerSchedule};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+
+/// What happens to a timer once it finished, i.e. fired its [last run](ScheduleTimer::with_max_runs)
+/// or its schedule has no occurrence left
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum FinishAction {
+    /// keep the timer as an [expired](ScheduleTimer::is_expired) component
+    #[default]
+    Deactivate,
+    /// remove the [`ScheduleTimer`] component from its entity
+    RemoveTimer,
+    /// despawn the entity of the timer, with its children
+    Despawn,
+}
+
+/// Triggered on a timer entity after its last [`ScheduleArrived`](crate::ScheduleArrived), right
+/// before the [`FinishAction`] is applied
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleFinished {
+    /// label of the timer
+    pub label: Option<String>,
+    /// fires of the timer
+    pub runs: u32,
+}
+
+impl ScheduleTimer {
+    /// A timer firing once at `at`
+    ///
+    /// ```rust
+    /// use bevy_cronjob::prelude::*;
+    /// use chrono::TimeZone;
+    ///
+    /// let new_year = chrono::Local.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
+    /// let fireworks = ScheduleTimer::once_at(new_year).with_finish_action(FinishAction::Despawn);
+    /// ```
+    pub fn once_at(at: DateTime<chrono::Local>) -> Self {
+        Self::from_schedule(TimerSchedule::Once(at))
+    }
+
+    /// Finish after `max_runs` fires
+    pub fn with_max_runs(mut self, max_runs: u32) -> Self {
+        self.max_runs = Some(max_runs);
+        self
+    }
+
+    /// Apply `action` once the timer finished
+    pub fn with_finish_action(mut self, action: FinishAction) -> Self {
+        self.on_finish = action;
+        self
+    }
+
+    /// Count a fire, returning whether the timer finished with it
+    pub(crate) fn count_run(&mut self) -> bool {
+        self.runs = self.runs.saturating_add(1);
+        if self.max_runs.is_some_and(|max_runs| self.runs >= max_runs) {
+            self.local_schedule = None;
+            self.expired = true;
+        }
+        self.expired
+    }
+}
+
+#[test]
+fn test_run_limit() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::{CronAppExt, CronClock, ScheduleArrived};
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>().record::<ScheduleFinished>();
+    let epoch = CronClock::ticks(1).now();
+    let limited = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every 10 seconds").with_max_runs(2))
+        .id();
+    let once = app
+        .world_mut()
+        .spawn(
+            ScheduleTimer::once_at(epoch + chrono::Duration::seconds(15))
+                .with_finish_action(FinishAction::Despawn),
+        )
+        .id();
+    app.update();
+    for _ in 0..5 {
+        app.advance_cron_time(std::time::Duration::from_secs(10));
+    }
+
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 3);
+    let mut finished = app
+        .triggered::<ScheduleFinished>()
+        .iter()
+        .map(|(_, finished)| finished.runs)
+        .collect::<Vec<_>>();
+    // both finished at 21 s
+    finished.sort_unstable();
+    assert_eq!(finished, [1, 2]);
+    let limited = app.world().get::<ScheduleTimer>(limited).unwrap();
+    assert!(limited.is_expired());
+    assert_eq!(limited.runs, 2);
+    assert!(app.world().get_entity(once).is_err());
+}
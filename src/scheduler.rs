@@ -0,0 +1,270 @@
+use crate::timer::Dispatch;
+use crate::{
+    CronClock, CronError, CronQuietHours, CronTimezones, FinishAction, IntoCronExpression,
+    ScheduleTimer,
+};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemId;
+use bevy_utils::tracing::debug;
+use chrono::DateTime;
+use std::collections::BTreeMap;
+
+/// Named jobs running registered one-shot systems, for background tasks that have no business
+/// being entities.
+///
+/// Each job is a [`ScheduleTimer`] kept inside the resource, so it honors the [`CronClock`] and
+/// [`CronTimezones`] like timer entities. When it fires, its system is run through
+/// [`Commands::run_system`]. Observers of [`ScheduleArrived`](crate::ScheduleArrived) are not
+/// triggered for these jobs, but they follow the same policies as timer entities: run limits,
+/// [quiet hours](CronQuietHours) and fire expiry. Finished jobs are removed unless they
+/// [deactivate](FinishAction::Deactivate).
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn backup() {
+///     info!("backing up");
+/// }
+///
+/// fn setup(world: &mut World) {
+///     let backup = world.register_system(backup);
+///     world
+///         .resource_mut::<CronScheduler>()
+///         .add_job("backup", "every day at 3 am", backup)
+///         .unwrap();
+/// }
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .add_systems(Startup, setup);
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct CronScheduler {
+    jobs: BTreeMap<String, SchedulerJob>,
+}
+
+#[derive(Debug)]
+struct SchedulerJob {
+    timer: ScheduleTimer,
+    system: SystemId,
+}
+
+impl CronScheduler {
+    /// Run `system` on the cron or english `expression`, replacing the job named `name`
+    pub fn add_job(
+        &mut self,
+        name: impl Into<String>,
+        expression: impl IntoCronExpression,
+        system: SystemId,
+    ) -> Result<(), CronError> {
+        let name = name.into();
+        let timer = ScheduleTimer::new(expression.into_cron_expr()?).with_label(name.clone());
+        self.jobs.insert(name, SchedulerJob { timer, system });
+        Ok(())
+    }
+
+    /// Cancel the job named `name`, returning its system so it can be unregistered
+    pub fn remove_job(&mut self, name: &str) -> Option<SystemId> {
+        self.jobs.remove(name).map(|job| job.system)
+    }
+
+    /// Whether a job named `name` exists
+    pub fn contains(&self, name: &str) -> bool {
+        self.jobs.contains_key(name)
+    }
+
+    /// The timer of the job named `name`, e.g. to read its schedule or history
+    pub fn timer(&self, name: &str) -> Option<&ScheduleTimer> {
+        self.jobs.get(name).map(|job| &job.timer)
+    }
+
+    /// The timer of the job named `name`, e.g. to pause it
+    pub fn timer_mut(&mut self, name: &str) -> Option<&mut ScheduleTimer> {
+        self.jobs.get_mut(name).map(|job| &mut job.timer)
+    }
+
+    /// Next planned run of the job named `name`, `None` before it was armed by the first check
+    /// or when it has no occurrence left
+    pub fn next_run(&self, name: &str) -> Option<DateTime<chrono::Local>> {
+        self.jobs.get(name)?.timer.local_schedule
+    }
+
+    /// The names of the jobs with their next planned runs, ordered by name
+    pub fn jobs(&self) -> impl Iterator<Item = (&str, Option<DateTime<chrono::Local>>)> {
+        self.jobs
+            .iter()
+            .map(|(name, job)| (name.as_str(), job.timer.local_schedule))
+    }
+
+    /// Number of jobs
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Whether there is no job
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// The timers of all jobs
+    pub(crate) fn timers(&self) -> impl Iterator<Item = &ScheduleTimer> {
+        self.jobs.values().map(|job| &job.timer)
+    }
+}
+
+pub(crate) fn run_scheduled_jobs(
+    mut scheduler: ResMut<CronScheduler>,
+    clock: Res<CronClock>,
+    timezones: Option<Res<CronTimezones>>,
+    quiet: Option<Res<CronQuietHours>>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    let quiet = quiet
+        .filter(|quiet| quiet.is_quiet(&now))
+        .map(|quiet| quiet.policy);
+    // only take the resource mutably when a job is due, to keep `Res::is_changed` meaningful
+    let due = scheduler
+        .jobs
+        .iter()
+        .filter(|(_, job)| !job.timer.is_held(quiet) && job.timer.is_due(now))
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    for name in due {
+        let Some(job) = scheduler.jobs.get_mut(&name) else {
+            continue;
+        };
+        let timezone = match &timezones {
+            Some(timezones) => timezones.resolve(&job.timer),
+            None => job.timer.timezone.unwrap_or_default(),
+        };
+        let pending = job.timer.local_schedule;
+        let fired = job.timer.poll(now, timezone);
+        match job.timer.dispatch(fired, pending, quiet, now) {
+            Dispatch::Fire(_) => {
+                debug!(name = name.as_str(), "scheduled job fired");
+                commands.run_system(job.system);
+                if job.timer.count_run() && job.timer.on_finish != FinishAction::Deactivate {
+                    debug!(name = name.as_str(), "scheduled job finished");
+                    scheduler.jobs.remove(&name);
+                }
+            }
+            Dispatch::Skip(scheduled) => {
+                debug!(name = name.as_str(), %scheduled, "scheduled job skipped");
+            }
+            Dispatch::Drop(scheduled) => {
+                debug!(name = name.as_str(), %scheduled, "scheduled job dropped");
+            }
+            Dispatch::Idle => {}
+        }
+    }
+}
+
+#[test]
+fn test_cron_scheduler() {
+    use crate::testing::cron_app;
+    use crate::CronAppExt;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Backups(u32);
+
+    let mut app = cron_app();
+    app.init_resource::<Backups>();
+    let backup = app
+        .world_mut()
+        .register_system(|mut backups: ResMut<Backups>| backups.0 += 1);
+    let mut scheduler = app.world_mut().resource_mut::<CronScheduler>();
+    scheduler
+        .add_job("backup", "every 10 seconds", backup)
+        .unwrap();
+    scheduler.add_job("report", "every hour", backup).unwrap();
+    assert!(scheduler
+        .add_job("broken", "every blue moon", backup)
+        .is_err());
+    assert_eq!(scheduler.len(), 2);
+
+    app.update();
+    let epoch = CronClock::ticks(1).now();
+    let scheduler = app.world().resource::<CronScheduler>();
+    assert_eq!(
+        scheduler.jobs().collect::<Vec<_>>(),
+        [
+            ("backup", Some(epoch + chrono::Duration::seconds(10))),
+            ("report", Some(epoch + chrono::Duration::hours(1))),
+        ]
+    );
+
+    for _ in 0..3 {
+        app.advance_cron_time(Duration::from_secs(10));
+    }
+    assert_eq!(app.world().resource::<Backups>().0, 3);
+
+    let mut scheduler = app.world_mut().resource_mut::<CronScheduler>();
+    assert_eq!(scheduler.remove_job("backup"), Some(backup));
+    assert!(!scheduler.contains("backup"));
+    app.advance_cron_time(Duration::from_secs(10));
+    assert_eq!(app.world().resource::<Backups>().0, 3);
+}
+
+#[test]
+fn test_cron_scheduler_policies() {
+    use crate::testing::cron_app;
+    use crate::{CronAppExt, CronTimezone, QuietPolicy};
+    use chrono::NaiveTime;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Runs(u32);
+
+    let epoch = CronClock::ticks(1).now();
+    let quiet = CronQuietHours::new(QuietPolicy::Suppress)
+        .with_window(
+            NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(1, 0, 0).unwrap(),
+        )
+        .with_timezone(CronTimezone::Utc);
+    for (policy, runs, next_run) in [
+        // both jobs finish after one run, `kept` deactivated and `removed` removed
+        (None, 2, None),
+        // suppressed jobs keep polling, deferred ones are held until the window ends
+        (Some(QuietPolicy::Suppress), 0, Some(60)),
+        (Some(QuietPolicy::Defer), 0, None),
+    ] {
+        let mut app = cron_app();
+        app.init_resource::<Runs>();
+        if let Some(policy) = policy {
+            app.insert_resource(CronQuietHours {
+                policy,
+                ..quiet.clone()
+            });
+        }
+        let run = app
+            .world_mut()
+            .register_system(|mut runs: ResMut<Runs>| runs.0 += 1);
+        let mut scheduler = app.world_mut().resource_mut::<CronScheduler>();
+        scheduler.add_job("kept", "every 10 seconds", run).unwrap();
+        scheduler
+            .add_job("removed", "every 10 seconds", run)
+            .unwrap();
+        scheduler.timer_mut("kept").unwrap().max_runs = Some(1);
+        let removed = scheduler.timer_mut("removed").unwrap();
+        removed.max_runs = Some(1);
+        removed.on_finish = FinishAction::RemoveTimer;
+
+        app.update();
+        for _ in 0..5 {
+            app.advance_cron_time(Duration::from_secs(10));
+        }
+        assert_eq!(app.world().resource::<Runs>().0, runs);
+        let scheduler = app.world().resource::<CronScheduler>();
+        let next_run = next_run.map(|seconds| epoch + chrono::Duration::seconds(seconds));
+        assert_eq!(scheduler.next_run("kept"), next_run);
+        assert_eq!(
+            scheduler.timer("kept").unwrap().is_expired(),
+            policy.is_none()
+        );
+        assert_eq!(scheduler.contains("removed"), policy.is_some());
+    }
+}
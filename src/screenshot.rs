@@ -0,0 +1,110 @@
+use crate::{CronClock, ScheduleArrived};
+use bevy_ecs::prelude::*;
+use bevy_render::view::screenshot::{save_to_disk, Screenshot};
+
+/// Saves a screenshot whenever the [`ScheduleTimer`](crate::ScheduleTimer) on the same entity
+/// fires, for soak tests and long-running visual regression captures.
+///
+/// The path template may contain `{n}` (the capture count, starting at zero), `{time}` (the
+/// fire time as `YYYYmmdd-HHMMSS`) and `{label}` (the label of the timer). Requires the `dev`
+/// feature and a renderer with Bevy's screenshot plugin.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ScheduleTimer::new("every 10 minutes").with_label("soak"),
+///         ScheduledScreenshot::new("captures/{label}-{n}-{time}.png"),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledScreenshot {
+    /// where screenshots are saved, with placeholders
+    pub path: String,
+    /// the window to capture, the primary window when `None`
+    pub window: Option<Entity>,
+    /// screenshots taken so far
+    pub count: u64,
+}
+
+impl ScheduledScreenshot {
+    /// Capture the primary window to `path`
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            window: None,
+            count: 0,
+        }
+    }
+
+    /// Capture `window` instead of the primary window
+    pub fn with_window(mut self, window: Entity) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// The path of the next capture, with the placeholders filled in
+    pub fn next_path(&self, time: &chrono::DateTime<chrono::Local>, label: Option<&str>) -> String {
+        self.path
+            .replace("{n}", &self.count.to_string())
+            .replace("{time}", &time.format("%Y%m%d-%H%M%S").to_string())
+            .replace("{label}", label.unwrap_or("cron"))
+    }
+}
+
+pub(crate) fn take_scheduled_screenshot(
+    trigger: Trigger<ScheduleArrived>,
+    mut query: Query<&mut ScheduledScreenshot>,
+    clock: Res<CronClock>,
+    mut commands: Commands,
+) {
+    let Ok(mut capture) = query.get_mut(trigger.entity()) else {
+        return;
+    };
+    let path = capture.next_path(&clock.now(), trigger.event().label.as_deref());
+    capture.count += 1;
+    let screenshot = match capture.window {
+        Some(window) => Screenshot::window(window),
+        None => Screenshot::primary_window(),
+    };
+    commands.spawn(screenshot).observe(save_to_disk(path));
+}
+
+#[test]
+fn test_scheduled_screenshot() {
+    use crate::testing::cron_app;
+    use crate::{CronAppExt, ScheduleTimer};
+    use chrono::TimeZone;
+
+    let time = chrono::Local
+        .with_ymd_and_hms(2025, 6, 1, 8, 30, 5)
+        .unwrap();
+    let capture = ScheduledScreenshot::new("captures/{label}-{n}-{time}.png");
+    assert_eq!(
+        capture.next_path(&time, Some("soak")),
+        "captures/soak-0-20250601-083005.png"
+    );
+
+    let mut app = cron_app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            ScheduleTimer::new("every minute"),
+            ScheduledScreenshot::new("{n}.png"),
+        ))
+        .id();
+    app.update();
+    app.advance_cron_time(std::time::Duration::from_secs(60));
+    assert_eq!(
+        app.world()
+            .get::<ScheduledScreenshot>(entity)
+            .unwrap()
+            .count,
+        1
+    );
+    let mut screenshots = app.world_mut().query::<&Screenshot>();
+    assert_eq!(screenshots.iter(app.world()).count(), 1);
+}
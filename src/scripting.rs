@@ -0,0 +1,162 @@
+//! Cron registration for scripts through [`bevy_mod_scripting`].
+//!
+//! [`CronScriptingPlugin`] registers the following global script functions:
+//!
+//! | function | description |
+//! |------|------|
+//! | `cron_register(expression, name)` | schedule a job, returns its id |
+//! | `cron_list()` | list the scheduled jobs as maps of `id`, `name`, `schedule` and `next_fire` |
+//! | `cron_cancel(id)` | cancel a job, returns whether it existed |
+//!
+//! Each time a job fires, the `on_cron` callback of all scripts is called with the job id and
+//! name. Route it to your language with the [`OnCron`] label:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_cronjob::prelude::*;
+//! use bevy_cronjob::scripting::CronScriptingPlugin;
+//!
+//! App::new().add_plugins((CronJobPlugin, CronScriptingPlugin));
+//! // with e.g. the `lua` feature of bevy_mod_scripting:
+//! // app.add_systems(Update, event_handler::<OnCron, LuaScriptingPlugin>);
+//! ```
+//!
+//! ```lua
+//! cron_register("every hour", "restock")
+//!
+//! function on_cron(id, name)
+//!     if name == "restock" then
+//!         -- ...
+//!     end
+//! end
+//! ```
+
+use crate::{CronError, ScheduleArrived, ScheduleTimer};
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_mod_scripting::core::{
+    bindings::{
+        function::{
+            namespace::{GlobalNamespace, NamespaceBuilder},
+            script_function::FunctionCallContext,
+        },
+        script_value::ScriptValue,
+    },
+    callback_labels,
+    error::InteropError,
+    event::ScriptCallbackEvent,
+};
+use std::collections::HashMap;
+
+callback_labels!(OnCron => "on_cron");
+
+/// A job scheduled from a script, fired through the [`OnCron`] callback
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ScriptCronJob {
+    /// name passed to the callback, chosen by the script
+    pub name: String,
+}
+
+/// Exposes cron registration to scripts, see the [module docs](self)
+pub struct CronScriptingPlugin;
+
+impl Plugin for CronScriptingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ScriptCallbackEvent>()
+            .add_observer(call_script_callback);
+        NamespaceBuilder::<GlobalNamespace>::new_unregistered(app.world_mut())
+            .register(
+                "cron_register",
+                |ctxt: FunctionCallContext, expression: String, name: String| {
+                    ctxt.world()?
+                        .with_global_access(|world| register_job(world, &expression, name))?
+                        .map_err(|err| InteropError::external_error(Box::new(err)))
+                },
+            )
+            .register("cron_list", |ctxt: FunctionCallContext| {
+                ctxt.world()?.with_global_access(list_jobs)
+            })
+            .register("cron_cancel", |ctxt: FunctionCallContext, id: u64| {
+                ctxt.world()?
+                    .with_global_access(|world| cancel_job(world, id))
+            });
+    }
+}
+
+fn register_job(world: &mut World, expression: &str, name: String) -> Result<u64, CronError> {
+    let expression = crate::CronExpr::parse(expression)?;
+    let entity = world
+        .spawn((
+            ScheduleTimer::new(expression).with_label(name.clone()),
+            ScriptCronJob { name },
+        ))
+        .id();
+    Ok(entity.to_bits())
+}
+
+fn list_jobs(world: &mut World) -> Vec<ScriptValue> {
+    world
+        .query::<(Entity, &ScheduleTimer, &ScriptCronJob)>()
+        .iter(world)
+        .map(|(entity, timer, job)| {
+            let next_fire = timer
+                .local_schedule
+                .map_or(ScriptValue::Unit, |time| time.to_rfc3339().into());
+            ScriptValue::Map(HashMap::from([
+                ("id".to_string(), (entity.to_bits() as i64).into()),
+                ("name".to_string(), job.name.clone().into()),
+                ("schedule".to_string(), timer.schedule.to_string().into()),
+                ("next_fire".to_string(), next_fire),
+            ]))
+        })
+        .collect()
+}
+
+fn cancel_job(world: &mut World, id: u64) -> bool {
+    let Ok(entity) = Entity::try_from_bits(id) else {
+        return false;
+    };
+    if world.get::<ScriptCronJob>(entity).is_none() {
+        return false;
+    }
+    world.despawn(entity)
+}
+
+fn call_script_callback(
+    trigger: Trigger<ScheduleArrived>,
+    jobs: Query<&ScriptCronJob>,
+    mut callbacks: EventWriter<ScriptCallbackEvent>,
+) {
+    let entity = trigger.entity();
+    if let Ok(job) = jobs.get(entity) {
+        callbacks.send(ScriptCallbackEvent::new_for_all(
+            OnCron,
+            vec![(entity.to_bits() as i64).into(), job.name.clone().into()],
+        ));
+    }
+}
+
+#[test]
+fn test_script_jobs() {
+    use crate::testing::{cron_app, start};
+
+    let mut app = cron_app();
+    app.add_plugins(CronScriptingPlugin);
+    let world = app.world_mut();
+    assert!(register_job(world, "not a schedule", "broken".to_string()).is_err());
+    let id = register_job(world, "every hour", "restock".to_string()).unwrap();
+    assert_eq!(list_jobs(world).len(), 1);
+
+    let entity = Entity::from_bits(id);
+    world
+        .get_mut::<ScheduleTimer>(entity)
+        .unwrap()
+        .local_schedule = Some(start() - chrono::Duration::seconds(1));
+    app.update();
+    let events = app.world().resource::<Events<ScriptCallbackEvent>>();
+    let event = events.iter_current_update_events().next().unwrap();
+    assert_eq!(event.args[1], ScriptValue::from("restock"));
+
+    assert!(cancel_job(app.world_mut(), id));
+    assert!(!cancel_job(app.world_mut(), id));
+}
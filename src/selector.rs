@@ -0,0 +1,118 @@
+use crate::ScheduleArrived;
+use bevy_ecs::prelude::*;
+
+type Outcome = Box<dyn Fn(&mut Commands, Entity) + Send + Sync>;
+
+/// A random event table ticked by the [`ScheduleTimer`](crate::ScheduleTimer) on the same entity.
+///
+/// Each time the schedule fires, one outcome is picked by weight and its event is triggered on
+/// the entity.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct MerchantArrives;
+///
+/// #[derive(Event, Clone)]
+/// struct Storm;
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn((
+///             ScheduleTimer::new("every hour"),
+///             WeightedEventTable::default()
+///                 .with(3.0, MerchantArrives)
+///                 .with(1.0, Storm),
+///         ))
+///         .observe(|_: Trigger<Storm>| info!("a storm is coming"));
+/// }
+/// ```
+#[derive(Component, Default)]
+pub struct WeightedEventTable {
+    outcomes: Vec<(f32, Outcome)>,
+    rng: fastrand::Rng,
+}
+
+impl WeightedEventTable {
+    /// Register an event triggered with the given relative weight
+    pub fn with<E: Event + Clone>(mut self, weight: f32, event: E) -> Self {
+        self.outcomes.push((
+            weight.max(0.0),
+            Box::new(move |commands: &mut Commands, entity| {
+                commands.trigger_targets(event.clone(), entity);
+            }),
+        ));
+        self
+    }
+
+    /// Seed the random number generator, for reproducible runs
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng.seed(seed);
+        self
+    }
+
+    /// Number of registered outcomes
+    pub fn len(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    /// Whether no outcome is registered
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Pick the index of an outcome by weight
+    pub fn pick(&mut self) -> Option<usize> {
+        let total: f32 = self.outcomes.iter().map(|(weight, _)| weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = self.rng.f32() * total;
+        for (index, (weight, _)) in self.outcomes.iter().enumerate() {
+            if roll < *weight {
+                return Some(index);
+            }
+            roll -= weight;
+        }
+        self.outcomes.iter().rposition(|(weight, _)| *weight > 0.0)
+    }
+}
+
+pub(crate) fn trigger_weighted_event(
+    trigger: Trigger<ScheduleArrived>,
+    mut query: Query<&mut WeightedEventTable>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    let Ok(mut table) = query.get_mut(entity) else {
+        return;
+    };
+    if let Some(index) = table.pick() {
+        (table.outcomes[index].1)(&mut commands, entity);
+    }
+}
+
+#[test]
+fn test_weighted_pick() {
+    #[derive(Event, Clone)]
+    struct Common;
+    #[derive(Event, Clone)]
+    struct Rare;
+    #[derive(Event, Clone)]
+    struct Never;
+
+    let mut table = WeightedEventTable::default()
+        .with(9.0, Common)
+        .with(1.0, Rare)
+        .with(0.0, Never)
+        .with_seed(11);
+    let mut counts = [0; 3];
+    for _ in 0..1000 {
+        counts[table.pick().unwrap()] += 1;
+    }
+    assert_eq!(counts[2], 0);
+    assert!(counts[0] > counts[1] * 4);
+    assert!(counts[1] > 0);
+}
@@ -0,0 +1,379 @@
+//! Serde support for [`ScheduleTimer`], used by scenes through reflection.
+//!
+//! A timer is written as its expression as written and settings plus, once it runs, its pending
+//! occurrence and last fire, never as the parsed schedule:
+//!
+//! ```ron
+//! "bevy_cronjob::timer::ScheduleTimer": (
+//!     schedule: Cron(expression: "every hour"),
+//!     label: Some("hourly"),
+//!     next_fire: Some("2024-03-01T13:00:00+01:00"),
+//!     last_run: Some("2024-03-01T12:00:00.012+01:00"),
+//! ),
+//! ```
+//!
+//! The same representation is used by other serde formats, e.g. to keep timers in save files.
+
+use crate::{
+    CatchUpPolicy, CronExpr, FinishAction, FireHistory, FireQuota, QuotaPeriod, ScheduleAlignment,
+    ScheduleTimer, TimerSchedule,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ScheduleTimer")]
+struct TimerData {
+    schedule: ScheduleData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    history: Option<HistoryData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    late_threshold: Option<Duration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fire_expiry: Option<Duration>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    alignment: ScheduleAlignment,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    catch_up: Option<CatchUpPolicy>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    runs: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_runs: Option<u32>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    on_finish: FinishAction,
+    #[serde(default, skip_serializing_if = "is_default")]
+    paused: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    excluded_dates: Vec<(NaiveDate, NaiveDate)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    quota: Option<QuotaData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    jitter: Option<JitterData>,
+    #[serde(default = "normal_speed", skip_serializing_if = "is_normal_speed")]
+    speed: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next_fire: Option<DateTime<chrono::Local>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_run: Option<DateTime<chrono::Local>>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    expired: bool,
+}
+
+// fields of enum variants are never skipped, scene serializers do not support it
+#[derive(Serialize, Deserialize)]
+enum ScheduleData {
+    Cron {
+        expression: String,
+        #[serde(default)]
+        clamp_to_month_end: bool,
+        #[serde(default)]
+        last_week_of_month: bool,
+    },
+    RandomDailyBetween {
+        start: NaiveTime,
+        end: NaiveTime,
+        rng_state: u64,
+        #[serde(default)]
+        last_window: Option<NaiveDate>,
+    },
+    Poisson {
+        mean: Duration,
+        rng_state: u64,
+    },
+    Interval {
+        interval: Duration,
+    },
+    Frames {
+        frames: u64,
+    },
+    FixedTicks {
+        ticks: u64,
+    },
+    Once {
+        at: DateTime<chrono::Local>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct QuotaData {
+    limit: u32,
+    per: QuotaPeriod,
+    #[serde(default)]
+    used: u32,
+    #[serde(default)]
+    period: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JitterData {
+    max: Duration,
+    /// offset of the pending fire in nanoseconds
+    #[serde(default)]
+    offset: i64,
+    #[serde(default)]
+    rng_state: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HistoryData {
+    capacity: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_age: Option<Duration>,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn normal_speed() -> f32 {
+    1.0
+}
+
+fn is_normal_speed(speed: &f32) -> bool {
+    *speed == 1.0
+}
+
+impl Serialize for ScheduleTimer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let schedule = match &self.schedule {
+            TimerSchedule::Cron(expr) => ScheduleData::Cron {
+                expression: expr.source().to_string(),
+                clamp_to_month_end: expr.clamps_to_month_end(),
+                last_week_of_month: expr.in_last_week_of_month(),
+            },
+            TimerSchedule::RandomDailyBetween {
+                start,
+                end,
+                rng,
+                last_window,
+            } => ScheduleData::RandomDailyBetween {
+                start: *start,
+                end: *end,
+                rng_state: rng.get_seed(),
+                last_window: *last_window,
+            },
+            TimerSchedule::Poisson { mean, rng } => ScheduleData::Poisson {
+                mean: *mean,
+                rng_state: rng.get_seed(),
+            },
+            TimerSchedule::Interval(interval) => ScheduleData::Interval {
+                interval: *interval,
+            },
+            TimerSchedule::Frames(frames) => ScheduleData::Frames { frames: *frames },
+            TimerSchedule::FixedTicks(ticks) => ScheduleData::FixedTicks { ticks: *ticks },
+            TimerSchedule::Once(at) => ScheduleData::Once { at: *at },
+        };
+        TimerData {
+            schedule,
+            label: self.label.clone(),
+            tags: self.tags.clone(),
+            history: self.history.as_ref().map(|history| HistoryData {
+                capacity: history.capacity(),
+                max_age: history.max_age(),
+            }),
+            late_threshold: self.late_threshold,
+            fire_expiry: self.fire_expiry,
+            alignment: self.alignment,
+            catch_up: self.catch_up,
+            runs: self.runs,
+            max_runs: self.max_runs,
+            on_finish: self.on_finish,
+            paused: self.paused,
+            timezone: self.timezone.map(|timezone| timezone.to_string()),
+            excluded_dates: self
+                .excluded_dates
+                .iter()
+                .map(|range| (*range.start(), *range.end()))
+                .collect(),
+            quota: self.quota.as_ref().map(|quota| QuotaData {
+                limit: quota.limit,
+                per: quota.per,
+                used: quota.used,
+                period: quota.period.map(|period| period.to_string()),
+            }),
+            jitter: self.jitter.map(|max| JitterData {
+                max,
+                offset: self.jitter_offset.num_nanoseconds().unwrap_or_default(),
+                rng_state: self.jitter_rng.get_seed(),
+            }),
+            speed: self.speed,
+            next_fire: self.local_schedule,
+            last_run: self.last_run,
+            expired: self.expired,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleTimer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = TimerData::deserialize(deserializer)?;
+        let schedule = match data.schedule {
+            ScheduleData::Cron {
+                expression,
+                clamp_to_month_end,
+                last_week_of_month,
+            } => {
+                let mut expr = CronExpr::parse(&expression).map_err(serde::de::Error::custom)?;
+                if clamp_to_month_end {
+                    expr = expr.clamp_to_month_end();
+                }
+                if last_week_of_month {
+                    expr = expr.in_last_week();
+                }
+                TimerSchedule::Cron(expr)
+            }
+            ScheduleData::RandomDailyBetween {
+                start,
+                end,
+                rng_state,
+                last_window,
+            } => TimerSchedule::RandomDailyBetween {
+                start,
+                end,
+                rng: fastrand::Rng::with_seed(rng_state),
+                last_window,
+            },
+            ScheduleData::Poisson { mean, rng_state } => TimerSchedule::Poisson {
+                mean,
+                rng: fastrand::Rng::with_seed(rng_state),
+            },
+            ScheduleData::Interval { interval } => TimerSchedule::Interval(interval),
+            ScheduleData::Frames { frames } => TimerSchedule::Frames(frames.max(1)),
+            ScheduleData::FixedTicks { ticks } => TimerSchedule::FixedTicks(ticks.max(1)),
+            ScheduleData::Once { at } => TimerSchedule::Once(at),
+        };
+        let mut timer = ScheduleTimer::from_schedule(schedule);
+        timer.label = data.label;
+        timer.tags = data.tags;
+        timer.history = data.history.map(|history| {
+            let fires = FireHistory::new(history.capacity);
+            match history.max_age {
+                Some(max_age) => fires.with_max_age(max_age),
+                None => fires,
+            }
+        });
+        timer.late_threshold = data.late_threshold;
+        timer.fire_expiry = data.fire_expiry;
+        timer.alignment = data.alignment;
+        timer.catch_up = data.catch_up;
+        timer.runs = data.runs;
+        timer.max_runs = data.max_runs;
+        timer.on_finish = data.on_finish;
+        timer.paused = data.paused;
+        timer.timezone = data
+            .timezone
+            .map(|timezone| timezone.parse())
+            .transpose()
+            .map_err(serde::de::Error::custom)?;
+        timer.excluded_dates = data
+            .excluded_dates
+            .into_iter()
+            .map(|(start, end)| start..=end)
+            .collect();
+        timer.quota = data
+            .quota
+            .map(|quota| {
+                let period = quota
+                    .period
+                    .map(|period| period.parse())
+                    .transpose()
+                    .map_err(serde::de::Error::custom)?;
+                Ok::<_, D::Error>(FireQuota {
+                    used: quota.used,
+                    period,
+                    ..FireQuota::new(quota.limit, quota.per)
+                })
+            })
+            .transpose()?;
+        if let Some(jitter) = data.jitter {
+            timer.jitter = Some(jitter.max);
+            timer.jitter_offset = chrono::Duration::nanoseconds(jitter.offset);
+            timer.jitter_rng.seed(jitter.rng_state);
+        }
+        timer.speed = data.speed;
+        timer.local_schedule = data.next_fire;
+        timer.last_run = data.last_run;
+        timer.expired = data.expired;
+        Ok(timer)
+    }
+}
+
+#[test]
+fn test_scene_round_trip() {
+    use crate::testing::cron_app;
+    use crate::MonthDay;
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_scene::serde::SceneDeserializer;
+    use bevy_scene::DynamicScene;
+    use serde::de::DeserializeSeed;
+
+    let mut app = cron_app();
+    app.world_mut().spawn(
+        ScheduleTimer::new(CronExpr::monthly_on(MonthDay::Last, 9, 0).unwrap())
+            .with_label("rent")
+            .with_tag("economy")
+            .with_history(4),
+    );
+    app.world_mut()
+        .spawn(ScheduleTimer::poisson(Duration::from_secs(90)).with_seed(5));
+    app.update();
+
+    let scene = DynamicScene::from_world(app.world());
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let ron = scene.serialize(&registry.read()).unwrap();
+    assert!(ron.contains("clamp_to_month_end: true"));
+    assert!(ron.contains("next_fire"));
+
+    let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+    let scene = SceneDeserializer {
+        type_registry: &registry.read(),
+    }
+    .deserialize(&mut deserializer)
+    .unwrap();
+    let mut world = World::new();
+    world.insert_resource(registry.clone());
+    scene
+        .write_to_world(&mut world, &mut Default::default())
+        .unwrap();
+
+    let mut original = app.world_mut().query::<&ScheduleTimer>();
+    let mut loaded = world.query::<&ScheduleTimer>();
+    let mut original: Vec<_> = original
+        .iter(app.world())
+        .map(ScheduleTimer::snapshot)
+        .collect();
+    let mut loaded: Vec<_> = loaded.iter(&world).map(ScheduleTimer::snapshot).collect();
+    original.sort_by_key(|snapshot| snapshot.rng_state);
+    loaded.sort_by_key(|snapshot| snapshot.rng_state);
+    assert_eq!(original, loaded);
+    let rent = world
+        .query::<&ScheduleTimer>()
+        .iter(&world)
+        .find(|timer| timer.label() == Some("rent"))
+        .unwrap();
+    assert!(rent.has_tag("economy"));
+    assert_eq!(rent.history.as_ref().map(FireHistory::capacity), Some(4));
+}
+
+#[test]
+fn test_save_round_trip() {
+    let mut timer = ScheduleTimer::new("every hour").with_label("hourly");
+    let now = chrono::Local::now();
+    timer.local_schedule = Some(now + chrono::Duration::minutes(30));
+    timer.last_run = Some(now - chrono::Duration::minutes(30));
+
+    let ron = ron::to_string(&timer).unwrap();
+    assert!(ron.contains("\"every hour\""));
+    let loaded: ScheduleTimer = ron::from_str(&ron).unwrap();
+    assert_eq!(loaded.expression(), Some("every hour"));
+    assert_eq!(loaded.snapshot(), timer.snapshot());
+}
@@ -0,0 +1,90 @@
+use crate::ScheduleArrived;
+use bevy_ecs::prelude::*;
+
+type Setter = Box<dyn Fn(&mut Commands) + Send + Sync>;
+
+/// Writes resource values each time the [`ScheduleTimer`](crate::ScheduleTimer) on the same
+/// entity fires, inserting the resources when missing.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct ShopInventoryDirty(bool);
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         ScheduleTimer::new("0 0 6 * * ? *"),
+///         SetResourceOnSchedule::new(ShopInventoryDirty(true)),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct SetResourceOnSchedule {
+    setters: Vec<Setter>,
+}
+
+impl SetResourceOnSchedule {
+    /// Write `value` into the resource `R` on every fire
+    pub fn new<R: Resource + Clone>(value: R) -> Self {
+        Self { setters: vec![] }.and(value)
+    }
+
+    /// Write another resource value on the same fires
+    pub fn and<R: Resource + Clone>(mut self, value: R) -> Self {
+        self.setters.push(Box::new(move |commands: &mut Commands| {
+            commands.insert_resource(value.clone());
+        }));
+        self
+    }
+
+    /// Number of resources written on every fire
+    pub fn len(&self) -> usize {
+        self.setters.len()
+    }
+
+    /// Whether no resource is written
+    pub fn is_empty(&self) -> bool {
+        self.setters.is_empty()
+    }
+}
+
+pub(crate) fn set_resources_on_schedule(
+    trigger: Trigger<ScheduleArrived>,
+    query: Query<&SetResourceOnSchedule>,
+    mut commands: Commands,
+) {
+    let Ok(setter) = query.get(trigger.entity()) else {
+        return;
+    };
+    for set in &setter.setters {
+        set(&mut commands);
+    }
+}
+
+#[test]
+fn test_set_resource_on_schedule() {
+    use crate::testing::cron_app;
+    use crate::{CronAppExt, ScheduleTimer};
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct ShopInventoryDirty(bool);
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct Weather(&'static str);
+
+    let mut app = cron_app();
+    app.insert_resource(ShopInventoryDirty(false));
+    app.world_mut().spawn((
+        ScheduleTimer::new("every hour"),
+        SetResourceOnSchedule::new(ShopInventoryDirty(true)).and(Weather("rain")),
+    ));
+    app.update();
+    app.advance_cron_time(std::time::Duration::from_secs(3600));
+
+    assert_eq!(
+        app.world().resource::<ShopInventoryDirty>(),
+        &ShopInventoryDirty(true)
+    );
+    assert_eq!(app.world().resource::<Weather>(), &Weather("rain"));
+}
@@ -0,0 +1,248 @@
+use crate::{CronClock, CronError, CronExpr, IntoCronExpression};
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Entity-less scheduler for very large numbers of lightweight jobs.
+///
+/// Jobs live in a slab inside the resource and are addressed by [`CronJobHandle`]s. Pending
+/// occurrences are kept in a min-heap, so the single dispatch system only touches the jobs that
+/// are due and sends one [`CronSlabFired`] event per fire. This trades the ergonomics of
+/// [`ScheduleTimer`](crate::ScheduleTimer) entities (observers, labels, markers) for throughput
+/// with 100k+ jobs.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn setup(mut slab: ResMut<CronSlab>) {
+///     let growth = CronExpr::parse("every 30 seconds").unwrap();
+///     for crop in 0..100_000 {
+///         slab.insert(&growth, crop).unwrap();
+///     }
+/// }
+///
+/// fn grow(mut fired: EventReader<CronSlabFired>) {
+///     for fire in fired.read() {
+///         let _crop = fire.data;
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .init_resource::<CronSlab>()
+///     .add_systems(Startup, setup)
+///     .add_systems(Update, grow);
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct CronSlab {
+    slots: Vec<Slot>,
+    free: Vec<u32>,
+    unarmed: Vec<CronJobHandle>,
+    queue: BinaryHeap<Reverse<(DateTime<chrono::Local>, CronJobHandle)>>,
+    len: usize,
+}
+
+#[derive(Debug)]
+struct Slot {
+    generation: u32,
+    job: Option<SlabJob>,
+}
+
+#[derive(Debug)]
+struct SlabJob {
+    schedule: CronExpr,
+    data: u64,
+    next_fire: Option<DateTime<chrono::Local>>,
+}
+
+/// Handle of a job in the [`CronSlab`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CronJobHandle {
+    index: u32,
+    generation: u32,
+}
+
+/// Sent for every fire of a [`CronSlab`] job
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronSlabFired {
+    /// the job that fired
+    pub handle: CronJobHandle,
+    /// user data given on insertion
+    pub data: u64,
+    /// the occurrence that passed
+    pub scheduled: DateTime<chrono::Local>,
+}
+
+impl CronSlab {
+    /// Schedule a job, `data` is passed along with its fires
+    pub fn insert(
+        &mut self,
+        expression: impl IntoCronExpression,
+        data: u64,
+    ) -> Result<CronJobHandle, CronError> {
+        let job = SlabJob {
+            schedule: expression.into_cron_expr()?,
+            data,
+            next_fire: None,
+        };
+        let handle = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.job = Some(job);
+                CronJobHandle {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    job: Some(job),
+                });
+                CronJobHandle {
+                    index: self.slots.len() as u32 - 1,
+                    generation: 0,
+                }
+            }
+        };
+        self.unarmed.push(handle);
+        self.len += 1;
+        Ok(handle)
+    }
+
+    /// Cancel a job, returns whether it existed
+    pub fn remove(&mut self, handle: CronJobHandle) -> bool {
+        if self.get(handle).is_none() {
+            return false;
+        }
+        let slot = &mut self.slots[handle.index as usize];
+        slot.job = None;
+        // invalidates the handle and its queued occurrence
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(handle.index);
+        self.len -= 1;
+        true
+    }
+
+    /// Whether the job exists
+    pub fn contains(&self, handle: CronJobHandle) -> bool {
+        self.get(handle).is_some()
+    }
+
+    /// Next planned fire of the job, `None` before it was armed or when it has no occurrence left
+    pub fn next_fire(&self, handle: CronJobHandle) -> Option<DateTime<chrono::Local>> {
+        self.get(handle)?.next_fire
+    }
+
+    /// The earliest pending occurrence of all jobs, `None` when no job is armed. May belong to a
+    /// job removed since, so it is never later than the actual next fire.
+    pub fn earliest_fire(&self) -> Option<DateTime<chrono::Local>> {
+        self.queue.peek().map(|Reverse((scheduled, _))| *scheduled)
+    }
+
+    /// Whether jobs inserted since the last dispatch wait to be armed
+    pub(crate) fn has_unarmed(&self) -> bool {
+        !self.unarmed.is_empty()
+    }
+
+    /// Number of jobs
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there is no job
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn get(&self, handle: CronJobHandle) -> Option<&SlabJob> {
+        let slot = self.slots.get(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+        slot.job.as_ref()
+    }
+
+    fn get_mut(&mut self, handle: CronJobHandle) -> Option<&mut SlabJob> {
+        let slot = self.slots.get_mut(handle.index as usize)?;
+        if slot.generation != handle.generation {
+            return None;
+        }
+        slot.job.as_mut()
+    }
+
+    fn schedule(&mut self, handle: CronJobHandle, after: DateTime<chrono::Local>) {
+        let Some(job) = self.get_mut(handle) else {
+            return;
+        };
+        job.next_fire = job.schedule.after(&after).next();
+        if let Some(next_fire) = job.next_fire {
+            self.queue.push(Reverse((next_fire, handle)));
+        }
+    }
+
+    /// Arm new jobs and collect the fires up to `now`
+    fn dispatch(&mut self, now: DateTime<chrono::Local>, fired: &mut Vec<CronSlabFired>) {
+        for handle in std::mem::take(&mut self.unarmed) {
+            self.schedule(handle, now);
+        }
+        while let Some(Reverse((scheduled, handle))) = self.queue.peek().copied() {
+            if scheduled >= now {
+                break;
+            }
+            self.queue.pop();
+            let Some(job) = self.get(handle) else {
+                continue;
+            };
+            if job.next_fire != Some(scheduled) {
+                continue;
+            }
+            fired.push(CronSlabFired {
+                handle,
+                data: job.data,
+                scheduled,
+            });
+            self.schedule(handle, now);
+        }
+    }
+}
+
+pub(crate) fn dispatch_slab_jobs(
+    mut slab: ResMut<CronSlab>,
+    clock: Res<CronClock>,
+    mut events: EventWriter<CronSlabFired>,
+    mut fired: Local<Vec<CronSlabFired>>,
+) {
+    slab.dispatch(clock.now(), &mut fired);
+    events.send_batch(fired.drain(..));
+}
+
+#[test]
+fn test_slab() {
+    let mut slab = CronSlab::default();
+    let now = chrono::Local::now();
+    let mut fired = vec![];
+    let every_second = slab.insert("every second", 1).unwrap();
+    let hourly = slab.insert("every hour", 2).unwrap();
+    assert!(slab.insert("not a schedule", 3).is_err());
+    slab.dispatch(now, &mut fired);
+    assert!(fired.is_empty());
+    assert!(slab.next_fire(hourly).is_some());
+
+    slab.dispatch(now + chrono::Duration::seconds(2), &mut fired);
+    assert_eq!(fired.len(), 1);
+    assert_eq!(fired[0].data, 1);
+
+    assert!(slab.remove(every_second));
+    assert!(!slab.remove(every_second));
+    let reused = slab.insert("every second", 4).unwrap();
+    assert_ne!(reused, every_second);
+    assert_eq!(slab.len(), 2);
+    fired.clear();
+    slab.dispatch(now + chrono::Duration::seconds(4), &mut fired);
+    slab.dispatch(now + chrono::Duration::seconds(6), &mut fired);
+    assert_eq!(fired.len(), 1);
+    assert_eq!(fired[0].data, 4);
+}
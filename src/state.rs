@@ -0,0 +1,99 @@
+use crate::ScheduleTimer;
+use bevy_ecs::prelude::*;
+
+/// Marker kept on [`ScheduleTimer`] entities that have a pending occurrence and will fire once it
+/// passes, see [`ScheduleTimer::is_armed`]
+///
+/// The state markers are maintained by [`CronJobPlugin`](crate::CronJobPlugin) so other systems
+/// can filter on timer state instead of inspecting every timer:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn patrol(enemies: Query<&Enemy, With<CronArmed>>) {
+///     for _enemy in &enemies {
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CronArmed;
+
+/// Marker kept on paused [`ScheduleTimer`] entities, see [`ScheduleTimer::pause`]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CronPausedState;
+
+/// Marker kept on [`ScheduleTimer`] entities whose schedule has no occurrence left, see
+/// [`ScheduleTimer::is_expired`]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CronExpiredState;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync_state_markers(
+    query: Query<
+        (
+            Entity,
+            &ScheduleTimer,
+            Has<CronArmed>,
+            Has<CronPausedState>,
+            Has<CronExpiredState>,
+        ),
+        Changed<ScheduleTimer>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, timer, armed, paused, expired) in query.iter() {
+        let mut entity = commands.entity(entity);
+        sync_marker::<CronArmed>(&mut entity, armed, timer.is_armed());
+        sync_marker::<CronPausedState>(&mut entity, paused, timer.is_paused());
+        sync_marker::<CronExpiredState>(&mut entity, expired, timer.is_expired());
+    }
+}
+
+fn sync_marker<M: Component + Default>(entity: &mut EntityCommands, present: bool, wanted: bool) {
+    match (present, wanted) {
+        (false, true) => {
+            entity.insert(M::default());
+        }
+        (true, false) => {
+            entity.remove::<M>();
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn test_state_markers() {
+    use crate::testing::cron_app;
+
+    let mut app = cron_app();
+    let running = app.world_mut().spawn(ScheduleTimer::new("every hour")).id();
+    let mut paused = ScheduleTimer::new("every hour");
+    paused.pause();
+    let paused = app.world_mut().spawn(paused).id();
+    // a single occurrence in the past
+    let expired = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("0 0 0 1 1 ? 1999"))
+        .id();
+    app.update();
+
+    let world = app.world();
+    assert!(world.get::<CronArmed>(running).is_some());
+    assert!(world.get::<CronPausedState>(paused).is_some());
+    assert!(world.get::<CronArmed>(paused).is_none());
+    assert!(world.get::<CronExpiredState>(expired).is_some());
+    assert!(world.get::<CronArmed>(expired).is_none());
+
+    app.world_mut()
+        .get_mut::<ScheduleTimer>(paused)
+        .unwrap()
+        .resume();
+    app.update();
+    assert!(app.world().get::<CronPausedState>(paused).is_none());
+    assert!(app.world().get::<CronArmed>(paused).is_some());
+}
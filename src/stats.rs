@@ -0,0 +1,54 @@
+use bevy_ecs::prelude::*;
+use std::time::Duration;
+
+/// Lightweight per-frame statistics about cron evaluation.
+///
+/// The resource is reset and refilled by [`CronJobPlugin`](crate::CronJobPlugin) every time the
+/// timer check runs, so it can be read from any system (e.g. a profiling HUD) to quantify how much
+/// the scheduler costs per frame.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct CronFrameStats {
+    /// number of [`ScheduleTimer`](crate::ScheduleTimer)s whose schedule was evaluated this frame
+    pub timers_evaluated: usize,
+    /// number of [`ScheduleTimer`](crate::ScheduleTimer)s that exist but were not evaluated this
+    /// frame, because they are paused or none of their occurrences passed
+    pub timers_skipped: usize,
+    /// number of [`ScheduleArrived`](crate::ScheduleArrived) events dispatched this frame
+    pub fires_dispatched: usize,
+    /// number of [`ScheduleArrived`](crate::ScheduleArrived) triggers queued this frame, lower
+    /// than `fires_dispatched` when fires were batched
+    pub triggers_queued: usize,
+    /// worst delay between the scheduled and the actual fire time among this frame's fires
+    pub worst_drift: Duration,
+    /// label of the timer with the worst drift, if it has one
+    pub worst_drift_label: Option<String>,
+    /// wall time spent inside the check system this frame
+    pub check_duration: Duration,
+}
+
+impl CronFrameStats {
+    /// total number of timers seen by the check system this frame
+    pub fn timers_total(&self) -> usize {
+        self.timers_evaluated + self.timers_skipped
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[test]
+fn test_frame_stats() {
+    use crate::testing::cron_app;
+    use crate::ScheduleTimer;
+
+    let mut app = cron_app();
+    app.world_mut().spawn(ScheduleTimer::new("every 5 seconds"));
+    app.world_mut().spawn(ScheduleTimer::new("every hour"));
+    app.update();
+
+    let stats = app.world().resource::<CronFrameStats>();
+    assert_eq!(stats.timers_evaluated, 2);
+    assert_eq!(stats.timers_total(), 2);
+    assert_eq!(stats.fires_dispatched, 0);
+}
@@ -0,0 +1,140 @@
+use crate::AnyScheduleArrived;
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// The streak of one labeled schedule, see [`CronStreaks`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Streak {
+    /// consecutive periods acknowledged up to the last fire
+    pub current: u32,
+    /// longest streak so far
+    pub best: u32,
+    /// whether the running period was acknowledged
+    pub acknowledged: bool,
+}
+
+/// Streaks of consecutive acknowledged periods, for daily-login style mechanics.
+///
+/// The occurrences of a labeled schedule split time into periods. Acknowledge the running
+/// period, e.g. when the player logs in, and every fire of a timer with that label closes it:
+/// an acknowledged period extends the streak, an unacknowledged one breaks it. Timers should use
+/// [`ScheduleAlignment::Schedule`](crate::ScheduleAlignment::Schedule), so periods missed while
+/// the app was not running still break the streak. The fields of [`Streak`] are public to be
+/// persisted.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn setup(mut commands: Commands, mut streaks: ResMut<CronStreaks>) {
+///     streaks.track("login");
+///     commands.spawn(
+///         ScheduleTimer::new("0 0 0 * * ? *")
+///             .with_label("login")
+///             .with_alignment(ScheduleAlignment::Schedule),
+///     );
+/// }
+///
+/// fn on_login(mut streaks: ResMut<CronStreaks>) {
+///     streaks.acknowledge("login");
+/// }
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .init_resource::<CronStreaks>()
+///     .add_systems(Startup, setup);
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CronStreaks {
+    streaks: HashMap<String, Streak>,
+}
+
+impl CronStreaks {
+    /// Start tracking the schedules labeled `label`, keeping a streak already tracked
+    pub fn track(&mut self, label: impl Into<String>) -> &mut Streak {
+        self.streaks.entry(label.into()).or_default()
+    }
+
+    /// Resume tracking `label` from a persisted streak
+    pub fn restore(&mut self, label: impl Into<String>, streak: Streak) {
+        self.streaks.insert(label.into(), streak);
+    }
+
+    /// Mark the running period of `label` as acknowledged, does nothing for untracked labels
+    pub fn acknowledge(&mut self, label: &str) {
+        if let Some(streak) = self.streaks.get_mut(label) {
+            streak.acknowledged = true;
+        }
+    }
+
+    /// The streak of `label`
+    pub fn get(&self, label: &str) -> Option<&Streak> {
+        self.streaks.get(label)
+    }
+
+    /// The current streak of `label`, 0 when untracked
+    pub fn current(&self, label: &str) -> u32 {
+        self.get(label).map_or(0, |streak| streak.current)
+    }
+
+    /// The best streak of `label`, 0 when untracked
+    pub fn best(&self, label: &str) -> u32 {
+        self.get(label).map_or(0, |streak| streak.best)
+    }
+
+    /// Every tracked label and its streak
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Streak)> {
+        self.streaks
+            .iter()
+            .map(|(label, streak)| (label.as_str(), streak))
+    }
+}
+
+pub(crate) fn close_streak_periods(
+    trigger: Trigger<AnyScheduleArrived>,
+    streaks: Option<ResMut<CronStreaks>>,
+) {
+    let (Some(mut streaks), Some(label)) = (streaks, &trigger.event().label) else {
+        return;
+    };
+    let Some(streak) = streaks.streaks.get_mut(label) else {
+        return;
+    };
+    if streak.acknowledged {
+        streak.current += 1;
+        streak.best = streak.best.max(streak.current);
+    } else {
+        streak.current = 0;
+    }
+    streak.acknowledged = false;
+}
+
+#[test]
+fn test_streaks() {
+    use crate::testing::cron_app;
+    use crate::{CronAppExt, ScheduleTimer};
+
+    let mut app = cron_app();
+    app.init_resource::<CronStreaks>();
+    app.world_mut().resource_mut::<CronStreaks>().track("login");
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_label("login"));
+    app.update();
+    let mut period = |acknowledged: bool| {
+        if acknowledged {
+            app.world_mut()
+                .resource_mut::<CronStreaks>()
+                .acknowledge("login");
+        }
+        app.advance_cron_time(std::time::Duration::from_secs(3600));
+        app.world().resource::<CronStreaks>().current("login")
+    };
+    assert_eq!(period(true), 1);
+    assert_eq!(period(true), 2);
+    assert_eq!(period(true), 3);
+    assert_eq!(period(false), 0);
+    assert_eq!(period(true), 1);
+    let streaks = app.world().resource::<CronStreaks>();
+    assert_eq!(streaks.best("login"), 3);
+    assert_eq!(streaks.current("other"), 0);
+}
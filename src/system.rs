@@ -0,0 +1,57 @@
+use crate::ScheduleArrived;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemId;
+
+/// Runs a registered one-shot system each time the [`ScheduleTimer`](crate::ScheduleTimer) on
+/// the same entity fires, without writing an observer.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// fn restock_shop() {
+///     info!("restocking");
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     let restock = commands.register_system(restock_shop);
+///     commands.spawn((ScheduleTimer::new("every hour"), CronSystem(restock)));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronSystem(pub SystemId);
+
+pub(crate) fn run_cron_systems(
+    trigger: Trigger<ScheduleArrived>,
+    query: Query<&CronSystem>,
+    mut commands: Commands,
+) {
+    if let Ok(system) = query.get(trigger.entity()) {
+        commands.run_system(system.0);
+    }
+}
+
+#[test]
+fn test_cron_system() {
+    use crate::testing::cron_app;
+    use crate::{CronAppExt, ScheduleTimer};
+
+    #[derive(Resource, Default)]
+    struct Restocks(u32);
+
+    let mut app = cron_app();
+    app.init_resource::<Restocks>();
+    let restock = app
+        .world_mut()
+        .register_system(|mut restocks: ResMut<Restocks>| restocks.0 += 1);
+    for system in [Some(CronSystem(restock)), None] {
+        let mut entity = app.world_mut().spawn(ScheduleTimer::new("every hour"));
+        if let Some(system) = system {
+            entity.insert(system);
+        }
+    }
+    app.update();
+    app.advance_cron_time(std::time::Duration::from_secs(3600));
+
+    assert_eq!(app.world().resource::<Restocks>().0, 1);
+}
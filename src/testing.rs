@@ -0,0 +1,55 @@
+//! Helpers shared by the tests of the modules
+
+use crate::{CronClock, CronJobPlugin};
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use chrono::DateTime;
+
+/// One second past 2000-01-01 00:00:00 UTC, where the clock of [`cron_app`] stands
+pub(crate) fn start() -> DateTime<chrono::Local> {
+    CronClock::ticks(1).now() + chrono::Duration::seconds(1)
+}
+
+/// An app with the [`CronJobPlugin`] on a manual clock standing at [`start`]. Timers are armed on
+/// the first update and fire once [`advance_cron_time`](crate::CronAppExt::advance_cron_time)
+/// passes their occurrence, e.g. by an hour for `"every hour"`.
+pub(crate) fn cron_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(CronJobPlugin)
+        .insert_resource(CronClock::manual(start()));
+    app
+}
+
+/// The events `E` triggered so far, with the entity they targeted
+#[derive(Resource)]
+pub(crate) struct Triggered<E: Event>(pub(crate) Vec<(Entity, E)>);
+
+impl<E: Event> Default for Triggered<E> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+pub(crate) trait TestAppExt {
+    /// Record the events `E` into [`Triggered`]
+    fn record<E: Event + Clone>(&mut self) -> &mut Self;
+
+    /// The events `E` recorded since [`record`](Self::record)
+    fn triggered<E: Event>(&self) -> &[(Entity, E)];
+}
+
+impl TestAppExt for App {
+    fn record<E: Event + Clone>(&mut self) -> &mut Self {
+        self.init_resource::<Triggered<E>>().add_observer(
+            |trigger: Trigger<E>, mut triggered: ResMut<Triggered<E>>| {
+                triggered
+                    .0
+                    .push((trigger.entity(), trigger.event().clone()));
+            },
+        )
+    }
+
+    fn triggered<E: Event>(&self) -> &[(Entity, E)] {
+        &self.world().resource::<Triggered<E>>().0
+    }
+}
@@ -0,0 +1,2042 @@
+use crate::queue::CronTimerQueue;
+use crate::{
+    AnyScheduleArrived, CatchUpPolicy, CronClock, CronError, CronExpr, CronFrameStats, CronHistory,
+    CronHistoryEntry, CronQuietHours, CronTimezone, CronTimezones, FinishAction, FireHistory,
+    FireQuota, FireRecord, IntoCronExpression, LastCronFire, MonthEndPolicy, QuietPolicy,
+    QuotaExhausted, ScheduleArrived, ScheduleCaughtUp, ScheduleChanged, ScheduleFinished,
+};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemState;
+use bevy_hierarchy::DespawnRecursiveExt;
+use bevy_reflect::Reflect;
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use bevy_time::{Time, Timer, TimerMode};
+use bevy_utils::tracing::debug;
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, TimeZone};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+/// What drives a [`ScheduleTimer`]
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum TimerSchedule {
+    /// a cron or english expression
+    Cron(CronExpr),
+    /// a random instant inside a daily window, picked anew every day
+    ///
+    /// When `end` is not after `start` the window wraps over midnight.
+    RandomDailyBetween {
+        start: NaiveTime,
+        end: NaiveTime,
+        rng: fastrand::Rng,
+        /// the day of the last window an instant was picked in
+        last_window: Option<chrono::NaiveDate>,
+    },
+    /// exponentially distributed gaps around a mean interval, i.e. a Poisson process
+    Poisson { mean: Duration, rng: fastrand::Rng },
+    /// a fixed interval with sub-second precision, e.g. every 250 ms
+    Interval(Duration),
+    /// every `n` frames, i.e. runs of the timer check, independently of any clock
+    Frames(u64),
+    /// every `n` runs of the [`FixedUpdate`](bevy_app::FixedUpdate) schedule, independently of
+    /// any clock
+    FixedTicks(u64),
+    /// a single occurrence at a fixed time
+    Once(DateTime<chrono::Local>),
+}
+
+impl TimerSchedule {
+    /// The next occurrence strictly after `after`
+    pub fn next_after(
+        &mut self,
+        after: &DateTime<chrono::Local>,
+    ) -> Option<DateTime<chrono::Local>> {
+        self.next_after_in(after, CronTimezone::Local)
+    }
+
+    /// The next occurrence strictly after `after`, with cron expressions evaluated in `timezone`
+    pub fn next_after_in(
+        &mut self,
+        after: &DateTime<chrono::Local>,
+        timezone: CronTimezone,
+    ) -> Option<DateTime<chrono::Local>> {
+        match self {
+            TimerSchedule::Cron(expr) => timezone.next_after(expr, after),
+            TimerSchedule::RandomDailyBetween {
+                start,
+                end,
+                rng,
+                last_window,
+            } => {
+                let length = match (*end - *start).num_seconds() {
+                    secs if secs > 0 => secs,
+                    secs => secs + 24 * 60 * 60,
+                };
+                let today = after.date_naive();
+                // a window opened yesterday may still be running when it wraps over midnight
+                let days = [
+                    today.pred_opt(),
+                    Some(today),
+                    today.succ_opt(),
+                    today.checked_add_days(Days::new(2)),
+                ];
+                for day in days.into_iter().flatten() {
+                    if last_window.is_some_and(|last| last >= day) {
+                        continue;
+                    }
+                    let Some(opens) = chrono::Local
+                        .from_local_datetime(&day.and_time(*start))
+                        .earliest()
+                    else {
+                        continue;
+                    };
+                    let closes = opens + chrono::Duration::seconds(length);
+                    if closes <= *after {
+                        continue;
+                    }
+                    let lower = opens.max(*after + chrono::Duration::seconds(1));
+                    let span = (closes - lower).num_seconds().max(1);
+                    *last_window = Some(day);
+                    return Some(lower + chrono::Duration::seconds(rng.i64(0..span)));
+                }
+                None
+            }
+            TimerSchedule::Poisson { mean, rng } => {
+                // inverse transform sampling, `1 - f64()` is never zero
+                let gap = -(1.0 - rng.f64()).ln() * mean.as_secs_f64();
+                let gap = chrono::Duration::from_std(Duration::from_secs_f64(gap)).ok()?;
+                after.checked_add_signed(gap)
+            }
+            TimerSchedule::Interval(interval) => {
+                after.checked_add_signed(chrono::Duration::from_std(*interval).ok()?)
+            }
+            // counted steps have no occurrences in time
+            TimerSchedule::Frames(_) | TimerSchedule::FixedTicks(_) => None,
+            TimerSchedule::Once(at) => Some(*at).filter(|at| at > after),
+        }
+    }
+
+    /// The step count of frame and tick schedules, and whether the steps are fixed ticks
+    fn steps(&self) -> Option<(u64, bool)> {
+        match self {
+            TimerSchedule::Frames(frames) => Some((*frames, false)),
+            TimerSchedule::FixedTicks(ticks) => Some((*ticks, true)),
+            _ => None,
+        }
+    }
+
+    /// Reseed the random number generator of random schedules
+    pub fn seed(&mut self, seed: u64) {
+        match self {
+            TimerSchedule::Cron(_)
+            | TimerSchedule::Interval(_)
+            | TimerSchedule::Frames(_)
+            | TimerSchedule::FixedTicks(_)
+            | TimerSchedule::Once(_) => {}
+            TimerSchedule::RandomDailyBetween { rng, .. } | TimerSchedule::Poisson { rng, .. } => {
+                rng.seed(seed)
+            }
+        }
+    }
+}
+
+impl fmt::Display for TimerSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimerSchedule::Cron(expr) => write!(f, "{expr}"),
+            TimerSchedule::RandomDailyBetween { start, end, .. } => {
+                write!(f, "random daily between {start} and {end}")
+            }
+            TimerSchedule::Poisson { mean, .. } => write!(f, "poisson every {mean:?} on average"),
+            TimerSchedule::Interval(interval) => write!(f, "every {interval:?}"),
+            TimerSchedule::Frames(frames) => write!(f, "every {frames} frames"),
+            TimerSchedule::FixedTicks(ticks) => write!(f, "every {ticks} ticks"),
+            TimerSchedule::Once(at) => write!(f, "once at {at}"),
+        }
+    }
+}
+
+/// A component that holds a cron expression
+///
+/// The timer reflects as an opaque value. With the `serialize` feature it can be saved in scenes,
+/// see [`serialize`](crate::serialize). Inspectors edit its expression through a
+/// [`ScheduleExpression`](crate::ScheduleExpression) on the same entity, see
+/// [`ScheduleExpression::from_timer`](crate::ScheduleExpression::from_timer).
+#[derive(Debug, Clone, Component, Reflect)]
+#[component(on_insert = CronTimerQueue::on_insert, on_remove = CronTimerQueue::on_remove)]
+#[reflect(opaque, Component, Debug)]
+#[cfg_attr(feature = "serialize", reflect(Serialize, Deserialize))]
+pub struct ScheduleTimer {
+    pub schedule: TimerSchedule,
+    pub local_schedule: Option<DateTime<chrono::Local>>,
+    /// name identifying the job, e.g. `"autosave"`
+    pub label: Option<String>,
+    /// groups the timer belongs to, e.g. `"economy"`
+    pub tags: Vec<String>,
+    /// recent fires, when enabled with [`ScheduleTimer::with_history`]
+    pub history: Option<FireHistory>,
+    /// fires later than this trigger [`ScheduleLate`]
+    pub late_threshold: Option<Duration>,
+    /// fires later than this are dropped with [`ScheduleFireDropped`]
+    pub fire_expiry: Option<Duration>,
+    /// where the next occurrence is searched from after a fire
+    pub alignment: ScheduleAlignment,
+    /// what happens to missed occurrences, overriding the alignment, see
+    /// [`ScheduleTimer::with_catch_up`]
+    pub catch_up: Option<CatchUpPolicy>,
+    /// paused timers are not evaluated and never fire
+    pub paused: bool,
+    /// timezone the expression is evaluated in, overriding [`CronTimezones`]
+    pub timezone: Option<CronTimezone>,
+    /// dates on which occurrences are skipped, see [`ScheduleTimer::with_excluded_dates`]
+    pub excluded_dates: Vec<RangeInclusive<NaiveDate>>,
+    /// most fires per day or week, see [`ScheduleTimer::with_quota`]
+    pub quota: Option<FireQuota>,
+    /// fires so far
+    pub runs: u32,
+    /// the timer finishes after this many fires, see [`ScheduleTimer::with_max_runs`]
+    pub max_runs: Option<u32>,
+    /// what happens to the timer once it finished, see [`ScheduleFinished`]
+    pub on_finish: FinishAction,
+    /// when the timer last fired
+    pub(crate) last_run: Option<DateTime<chrono::Local>>,
+    /// most offset of the fires from their occurrences, see [`ScheduleTimer::with_jitter`]
+    pub jitter: Option<Duration>,
+    /// offset of the pending fire from its occurrence
+    pub(crate) jitter_offset: chrono::Duration,
+    /// draws the jitter offsets
+    pub(crate) jitter_rng: fastrand::Rng,
+    /// how fast the schedule progresses relative to a tick [`CronClock`], see
+    /// [`ScheduleTimer::with_speed`]
+    pub speed: f32,
+    /// the clock time and the time of the timer at the last check of a scaled timer
+    pub(crate) speed_anchor: Option<(DateTime<chrono::Local>, DateTime<chrono::Local>)>,
+    pub(crate) expired: bool,
+    /// why the primary expression of [`ScheduleTimer::new_or`] was rejected
+    pub(crate) fallback_error: Option<CronError>,
+    /// backs intervals of at most [`FRAME_TIMER_THRESHOLD`]
+    pub(crate) frame_timer: Option<Timer>,
+    /// frames or ticks counted since the last fire of a step schedule
+    pub(crate) steps: u64,
+    /// fixed ticks not yet counted by the timer check
+    pub(crate) pending_ticks: u64,
+    /// missed occurrences collapsed into the last fire, or fired so far, by the catch-up policy
+    pub(crate) caught_up: u64,
+    /// drop the pending occurrence on the next check if it passed, see
+    /// [`ResumePolicy::SkipMissed`]
+    pub(crate) skip_missed: bool,
+    /// the schedule replaced since the last check, reported with [`ScheduleChanged`]
+    pub(crate) changed_from: Option<String>,
+}
+
+/// Intervals up to this length are driven by a [`Timer`] ticked with the frame delta instead of
+/// date math, see [`ScheduleTimer::every`]
+pub const FRAME_TIMER_THRESHOLD: Duration = Duration::from_millis(50);
+
+/// Where the search for the next occurrence starts after a fire
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScheduleAlignment {
+    /// search from the actual fire time: occurrences missed during a stall are skipped and the
+    /// timer resumes with the next upcoming one
+    #[default]
+    ActualFire,
+    /// search from the scheduled time of the occurrence that fired: every occurrence is serviced,
+    /// missed ones fire on consecutive checks until the timer has caught up
+    Schedule,
+}
+
+/// What happens to the occurrences that passed while a timer was paused, see
+/// [`ScheduleTimer::resume_with`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ResumePolicy {
+    /// fire the pending occurrence on the next check, and the missed ones according to the
+    /// [alignment](ScheduleTimer::with_alignment) or [catch-up policy](ScheduleTimer::with_catch_up)
+    #[default]
+    FireMissed,
+    /// drop the pending occurrence if it passed, triggering [`ScheduleSkipped`], and resume
+    /// with the next upcoming one
+    SkipMissed,
+}
+
+/// Triggered on a timer entity when it is armed with its first deadline, i.e. the frame after it
+/// was spawned with a valid schedule
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleRegistered {
+    /// label of the timer
+    pub label: Option<String>,
+    /// the first occurrence the timer will fire for
+    pub first_fire: DateTime<chrono::Local>,
+}
+
+/// Triggered on a timer entity instead of [`ScheduleArrived`] when an occurrence falls on one of
+/// its [excluded dates](ScheduleTimer::with_excluded_dates) or exceeds its
+/// [quota](ScheduleTimer::with_quota)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleSkipped {
+    /// label of the timer
+    pub label: Option<String>,
+    /// the skipped occurrence
+    pub scheduled: DateTime<chrono::Local>,
+}
+
+/// Triggered on a timer entity instead of [`ScheduleArrived`] when an occurrence could not be
+/// dispatched within its [fire expiry](ScheduleTimer::with_fire_expiry)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleFireDropped {
+    /// label of the timer
+    pub label: Option<String>,
+    /// the dropped occurrence
+    pub scheduled: DateTime<chrono::Local>,
+    /// how late the occurrence would have fired
+    pub lateness: Duration,
+}
+
+/// Triggered on a timer entity when a fire was dispatched later than its
+/// [late threshold](ScheduleTimer::with_late_threshold), right before [`ScheduleArrived`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleLate {
+    /// label of the late timer
+    pub label: Option<String>,
+    /// the occurrence the timer was scheduled for
+    pub scheduled: DateTime<chrono::Local>,
+    /// when the fire was dispatched
+    pub actual: DateTime<chrono::Local>,
+    /// `actual - scheduled`
+    pub lateness: Duration,
+}
+
+impl ScheduleTimer {
+    /// A timer on a cron or english expression, panicking when it is invalid. Use
+    /// [`ScheduleTimer::builder`] to handle the error.
+    pub fn new(expression: impl IntoCronExpression) -> Self {
+        Self::builder(expression)
+            .build()
+            .expect("Failed to parse cron expression")
+    }
+
+    /// Parse `expression`, falling back to `fallback` when it is invalid, for schedules loaded
+    /// from data files.
+    ///
+    /// A timer spawned with the fallback triggers [`ScheduleFallbackUsed`](crate::ScheduleFallbackUsed) on its entity.
+    /// Panics when the fallback is invalid as well.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    ///
+    /// let timer = ScheduleTimer::new_or("every blue moon", "every hour");
+    /// assert_eq!(timer.schedule.to_string(), "0 0 * * * ? *");
+    /// ```
+    pub fn new_or(expression: impl IntoCronExpression, fallback: impl IntoCronExpression) -> Self {
+        match expression.into_cron_expr() {
+            Ok(schedule) => Self::from_schedule(TimerSchedule::Cron(schedule)),
+            Err(error) => {
+                let mut timer = Self::new(fallback);
+                timer.fallback_error = Some(error);
+                timer
+            }
+        }
+    }
+
+    /// Why the primary expression was rejected, when the timer runs on the fallback of
+    /// [`ScheduleTimer::new_or`]
+    pub fn fallback_error(&self) -> Option<&CronError> {
+        self.fallback_error.as_ref()
+    }
+
+    /// The cron or english expression of a cron schedule as it was written, e.g. to seed a
+    /// [`ScheduleExpression`](crate::ScheduleExpression) editor
+    pub fn expression(&self) -> Option<&str> {
+        match &self.schedule {
+            TimerSchedule::Cron(expr) => Some(expr.source()),
+            _ => None,
+        }
+    }
+
+    /// Fire once a day at a random instant between `start` and `end`, local time
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    /// use chrono::NaiveTime;
+    ///
+    /// // a wandering merchant who shows up some time in the afternoon
+    /// let merchant = ScheduleTimer::random_daily_between(
+    ///     NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+    /// )
+    /// .with_seed(42);
+    /// ```
+    pub fn random_daily_between(start: NaiveTime, end: NaiveTime) -> Self {
+        Self::from_schedule(TimerSchedule::RandomDailyBetween {
+            start,
+            end,
+            rng: fastrand::Rng::new(),
+            last_window: None,
+        })
+    }
+
+    /// Fire at irregular, exponentially distributed intervals averaging `mean`
+    ///
+    /// Handy for naturally irregular world events such as ambient encounters.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    /// use std::time::Duration;
+    ///
+    /// let encounters = ScheduleTimer::poisson(Duration::from_secs(90)).with_seed(1);
+    /// ```
+    pub fn poisson(mean: Duration) -> Self {
+        Self::from_schedule(TimerSchedule::Poisson {
+            mean,
+            rng: fastrand::Rng::new(),
+        })
+    }
+
+    /// Fire every `interval`, with sub-second precision, e.g. every 250 ms
+    ///
+    /// Interval timers are aligned to their [schedule](ScheduleAlignment::Schedule), so the
+    /// cadence does not drift with the frame rate. Occurrences missed during a long frame are
+    /// skipped rather than fired in a burst.
+    ///
+    /// Intervals up to [`FRAME_TIMER_THRESHOLD`], only a couple of frames long, are transparently
+    /// driven by a bevy [`Timer`] ticked with the [`Time`] delta, avoiding date math every frame.
+    /// This requires the `Time` resource and the wall [`CronClock`].
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    /// use std::time::Duration;
+    ///
+    /// let burst = ScheduleTimer::every(Duration::from_millis(250));
+    /// ```
+    pub fn every(interval: Duration) -> Self {
+        Self::from_schedule(TimerSchedule::Interval(
+            interval.max(Duration::from_millis(1)),
+        ))
+        .with_alignment(ScheduleAlignment::Schedule)
+    }
+
+    /// A timer firing every `frames` frames, for deterministic simulations where wall clock time
+    /// is the wrong clock. Step timers fire [`ScheduleArrived`] like any other timer but have no
+    /// pending occurrence in time, so they are never [armed](Self::is_armed).
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    ///
+    /// let spawner = ScheduleTimer::every_frames(1000);
+    /// ```
+    pub fn every_frames(frames: u64) -> Self {
+        Self::from_schedule(TimerSchedule::Frames(frames.max(1)))
+    }
+
+    /// A timer firing every `ticks` runs of [`FixedUpdate`](bevy_app::FixedUpdate), see
+    /// [`ScheduleTimer::every_frames`]. The fire is dispatched by the next timer check in
+    /// [`Update`](bevy_app::Update).
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    ///
+    /// let weather = ScheduleTimer::every_fixed_ticks(600);
+    /// ```
+    pub fn every_fixed_ticks(ticks: u64) -> Self {
+        Self::from_schedule(TimerSchedule::FixedTicks(ticks.max(1)))
+    }
+
+    /// Create a timer from any [`TimerSchedule`]
+    pub fn from_schedule(schedule: TimerSchedule) -> Self {
+        Self {
+            schedule,
+            local_schedule: None,
+            label: None,
+            tags: Vec::new(),
+            history: None,
+            late_threshold: None,
+            fire_expiry: None,
+            alignment: ScheduleAlignment::default(),
+            catch_up: None,
+            runs: 0,
+            max_runs: None,
+            on_finish: FinishAction::default(),
+            last_run: None,
+            jitter: None,
+            jitter_offset: chrono::Duration::zero(),
+            jitter_rng: fastrand::Rng::new(),
+            paused: false,
+            timezone: None,
+            excluded_dates: Vec::new(),
+            quota: None,
+            speed: 1.0,
+            speed_anchor: None,
+            expired: false,
+            fallback_error: None,
+            frame_timer: None,
+            steps: 0,
+            pending_ticks: 0,
+            caught_up: 0,
+            skip_missed: false,
+            changed_from: None,
+        }
+    }
+
+    /// Stop evaluating the timer until [`ScheduleTimer::resume`] is called
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Evaluate the timer again after [`ScheduleTimer::pause`]. Occurrences that passed while
+    /// the timer was paused fire, see [`ResumePolicy::FireMissed`].
+    pub fn resume(&mut self) {
+        self.resume_with(ResumePolicy::FireMissed);
+    }
+
+    /// Evaluate the timer again after [`ScheduleTimer::pause`], handling the occurrences that
+    /// passed while it was paused according to `policy`
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// fn close_menu(mut timers: Query<&mut ScheduleTimer>) {
+    ///     for mut timer in timers.iter_mut() {
+    ///         timer.resume_with(ResumePolicy::SkipMissed);
+    ///     }
+    /// }
+    /// ```
+    pub fn resume_with(&mut self, policy: ResumePolicy) {
+        self.skip_missed = self.paused && policy == ResumePolicy::SkipMissed;
+        self.paused = false;
+    }
+
+    /// Whether the timer is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Whether the timer has a pending occurrence and will fire once it passes
+    pub fn is_armed(&self) -> bool {
+        !self.paused && self.local_schedule.is_some()
+    }
+
+    /// Whether the schedule has no occurrence left, e.g. a cron expression restricted to past years
+    pub fn is_expired(&self) -> bool {
+        self.expired
+    }
+
+    /// The pending occurrence the timer fires for next, `None` before it was armed by the first
+    /// check, while paused or once expired
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// fn show_next_backup(timers: Query<&ScheduleTimer>, clock: Res<CronClock>) {
+    ///     for timer in timers.iter() {
+    ///         if let Some(next) = timer.next_run() {
+    ///             info!("next backup in {}s", (next - clock.now()).num_seconds());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn next_run(&self) -> Option<DateTime<chrono::Local>> {
+        self.local_schedule
+            .filter(|_| !self.paused && !self.expired)
+    }
+
+    /// When the timer last fired, `None` before its first fire
+    pub fn last_run(&self) -> Option<DateTime<chrono::Local>> {
+        self.last_run
+    }
+
+    /// The next `n` occurrences, starting with the pending one. Unarmed timers start from the
+    /// system clock. Cron expressions are evaluated in the timezone of the timer, ignoring
+    /// [`CronTimezones`], and random schedules are sampled without advancing the timer. Step
+    /// schedules have no occurrences in time.
+    pub fn upcoming(&self, n: usize) -> Vec<DateTime<chrono::Local>> {
+        if self.expired {
+            return vec![];
+        }
+        let timezone = self.timezone.unwrap_or_default();
+        let mut schedule = self.schedule.clone();
+        let first = match self.local_schedule {
+            Some(pending) => Some(pending),
+            None => schedule.next_after_in(&chrono::Local::now(), timezone),
+        };
+        std::iter::successors(first, |after| schedule.next_after_in(after, timezone))
+            .take(n)
+            .collect()
+    }
+
+    /// Give the timer a label identifying the job
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The label of the timer, if any
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Add the timer to a group, see [`CronAppExt::observe_schedule_tagged`](crate::CronAppExt::observe_schedule_tagged)
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Whether the timer has the given tag
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// Keep the scheduled and actual times of the last `capacity` fires
+    pub fn with_history(mut self, capacity: usize) -> Self {
+        self.history = Some(FireHistory::new(capacity));
+        self
+    }
+
+    /// Keep a fire history with a custom retention, e.g. bounded by age as well as count
+    pub fn with_fire_history(mut self, history: FireHistory) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// The most recent fires, oldest first. Empty unless enabled with
+    /// [`ScheduleTimer::with_history`].
+    pub fn history(&self) -> impl Iterator<Item = &FireRecord> {
+        self.history.iter().flat_map(FireHistory::iter)
+    }
+
+    /// Trigger [`ScheduleLate`] when a fire is dispatched more than `threshold` after its
+    /// scheduled time, so latency-sensitive jobs can react or alert
+    pub fn with_late_threshold(mut self, threshold: Duration) -> Self {
+        self.late_threshold = Some(threshold);
+        self
+    }
+
+    /// Drop occurrences that could not be dispatched within `expiry` of their scheduled time,
+    /// e.g. because of a hitch, a pause or quiet hours, with a [`ScheduleFireDropped`] event
+    /// instead of firing very late. Suits time-sensitive gameplay pulses.
+    pub fn with_fire_expiry(mut self, expiry: Duration) -> Self {
+        self.fire_expiry = Some(expiry);
+        self
+    }
+
+    /// Whether the timer is held back, while paused or deferred by quiet hours
+    pub(crate) fn is_held(&self, quiet: Option<QuietPolicy>) -> bool {
+        self.paused || quiet == Some(QuietPolicy::Defer)
+    }
+
+    /// How to dispatch the occurrence `fired` by a poll at `now`, `missed` being the pending
+    /// occurrence when it passed, under the current `quiet` hours policy
+    pub(crate) fn dispatch(
+        &self,
+        fired: Option<DateTime<chrono::Local>>,
+        missed: Option<DateTime<chrono::Local>>,
+        quiet: Option<QuietPolicy>,
+        now: DateTime<chrono::Local>,
+    ) -> Dispatch {
+        match (fired.filter(|_| quiet.is_none()), missed) {
+            (Some(scheduled), _) => Dispatch::Fire(scheduled),
+            (None, Some(scheduled)) if self.is_stale(&scheduled, now) => Dispatch::Drop(scheduled),
+            (None, Some(scheduled)) => Dispatch::Skip(scheduled),
+            (None, None) => Dispatch::Idle,
+        }
+    }
+
+    /// Whether an occurrence at `scheduled` is too late to fire at `now`
+    fn is_stale(&self, scheduled: &DateTime<chrono::Local>, now: DateTime<chrono::Local>) -> bool {
+        self.fire_expiry.is_some_and(|expiry| {
+            (now - *scheduled)
+                .to_std()
+                .is_ok_and(|lateness| lateness > expiry)
+        })
+    }
+
+    /// Choose whether the next occurrence follows the scheduled or the actual fire time
+    pub fn with_alignment(mut self, alignment: ScheduleAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Evaluate the cron expression in `timezone`, regardless of the [`CronTimezones`] groups
+    pub fn with_timezone(mut self, timezone: impl Into<CronTimezone>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Choose what happens to days 29-31 of a cron schedule in shorter months, see
+    /// [`MonthEndPolicy`]. Other schedules are left unchanged.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::{MonthEndPolicy, ScheduleTimer};
+    ///
+    /// // pays out on Feb 28th (or 29th), Apr 30th, ...
+    /// let payday = ScheduleTimer::new("0 0 12 31 * ? *").with_month_end_policy(MonthEndPolicy::Clamp);
+    /// ```
+    pub fn with_month_end_policy(mut self, policy: MonthEndPolicy) -> Self {
+        if let TimerSchedule::Cron(expr) = &mut self.schedule {
+            *expr = expr.clone().with_month_end_policy(policy);
+        }
+        self
+    }
+
+    /// Skip occurrences on the given dates, e.g. maintenance days. Dates are taken in the timezone
+    /// the timer is evaluated in.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    /// use chrono::NaiveDate;
+    ///
+    /// let daily_reward = ScheduleTimer::new("0 0 12 * * ? *")
+    ///     .with_excluded_dates([NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()]);
+    /// ```
+    pub fn with_excluded_dates(mut self, dates: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.excluded_dates
+            .extend(dates.into_iter().map(|date| date..=date));
+        self
+    }
+
+    /// Skip occurrences within the given inclusive date ranges, e.g. a tournament week
+    pub fn with_excluded_ranges(
+        mut self,
+        ranges: impl IntoIterator<Item = RangeInclusive<NaiveDate>>,
+    ) -> Self {
+        self.excluded_dates.extend(ranges);
+        self
+    }
+
+    /// Whether occurrences on `date` are skipped
+    pub fn is_excluded(&self, date: NaiveDate) -> bool {
+        self.excluded_dates
+            .iter()
+            .any(|range| range.contains(&date))
+    }
+
+    /// Let the schedule progress `speed` times as fast as a tick [`CronClock`], e.g. `2.0` for a
+    /// production timer under a haste buff. `0.0` freezes the timer.
+    ///
+    /// The expression and the clock stay untouched: the timer keeps its own time, which advances
+    /// by the scaled clock delta on every check, and fire times are reported in it. The speed can
+    /// be changed at runtime through [`ScheduleTimer::speed`]. Timers on a real time clock ignore
+    /// it.
+    ///
+    /// ```rust
+    /// use bevy_cronjob::ScheduleTimer;
+    ///
+    /// let production = ScheduleTimer::new("every 10 seconds").with_speed(2.0);
+    /// ```
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// Whether the timer keeps its own time, i.e. runs at another speed or did before
+    fn is_scaled(&self) -> bool {
+        self.speed != 1.0 || self.speed_anchor.is_some()
+    }
+
+    /// Advance the time of the timer to the clock time `now`, returning it
+    fn scaled_now(&mut self, now: DateTime<chrono::Local>) -> DateTime<chrono::Local> {
+        let local = match self.speed_anchor {
+            Some((clock, local)) => {
+                let elapsed = (now - clock).num_nanoseconds().unwrap_or_default() as f64
+                    * self.speed.max(0.0) as f64;
+                local + chrono::Duration::nanoseconds(elapsed as i64)
+            }
+            None => now,
+        };
+        self.speed_anchor = Some((now, local));
+        local
+    }
+
+    /// Seed the random number generators used by random schedules and the jitter, for
+    /// reproducible runs
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.schedule.seed(seed);
+        self.jitter_rng.seed(seed);
+        self
+    }
+
+    /// The interval when it is short enough for a frame timer
+    fn frame_interval(&self) -> Option<Duration> {
+        match self.schedule {
+            TimerSchedule::Interval(interval) if interval <= FRAME_TIMER_THRESHOLD => {
+                Some(interval)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the timer must be visited by every check rather than when its occurrence passed
+    pub(crate) fn needs_every_check(&self) -> bool {
+        self.schedule.steps().is_some() || self.frame_interval().is_some() || self.is_scaled()
+    }
+
+    /// Count `steps` frames or ticks of a step schedule firing every `every`, returning `now` when
+    /// the count passed it like [`poll`](Self::poll). Fires at most once per check.
+    fn tick_steps(
+        &mut self,
+        every: u64,
+        steps: u64,
+        now: DateTime<chrono::Local>,
+        timezone: CronTimezone,
+    ) -> Option<DateTime<chrono::Local>> {
+        if self.expired {
+            return None;
+        }
+        self.steps += steps;
+        if self.steps < every {
+            return None;
+        }
+        self.steps %= every;
+        if self.is_excluded(timezone.date_of(&now)) || !self.take_quota(&now, timezone) {
+            return None;
+        }
+        if let Some(history) = &mut self.history {
+            history.push(FireRecord {
+                scheduled: now,
+                actual: now,
+            });
+        }
+        Some(now)
+    }
+
+    /// Tick the frame timer by `delta`, arming it first if needed, returning the scheduled time
+    /// of the occurrence that passed like [`poll`](Self::poll)
+    fn tick_frame_timer(
+        &mut self,
+        interval: Duration,
+        now: DateTime<chrono::Local>,
+        delta: Duration,
+    ) -> Option<DateTime<chrono::Local>> {
+        if self.expired {
+            return None;
+        }
+        let Some(frame_timer) = &mut self.frame_timer else {
+            self.frame_timer = Some(Timer::new(interval, TimerMode::Repeating));
+            self.local_schedule =
+                now.checked_add_signed(chrono::Duration::from_std(interval).ok()?);
+            return None;
+        };
+        if !frame_timer.tick(delta).just_finished() {
+            return None;
+        }
+        let scheduled = self.local_schedule.unwrap_or(now);
+        let remaining = chrono::Duration::from_std(frame_timer.remaining()).unwrap_or_default();
+        self.local_schedule = now.checked_add_signed(remaining);
+        let timezone = self.timezone.unwrap_or_default();
+        if self.is_excluded(timezone.date_of(&scheduled)) || !self.take_quota(&scheduled, timezone)
+        {
+            return None;
+        }
+        if let Some(history) = &mut self.history {
+            history.push(FireRecord {
+                scheduled,
+                actual: now,
+            });
+        }
+        Some(scheduled)
+    }
+
+    /// Whether [`poll`](Self::poll) would change the timer at `now`, i.e. it needs arming or its
+    /// pending occurrence passed
+    pub(crate) fn is_due(&self, now: DateTime<chrono::Local>) -> bool {
+        !self.expired && self.local_schedule.is_none_or(|local| now > local)
+    }
+
+    /// Advance the timer to `now`, returning the scheduled time of the occurrence that passed.
+    /// Cron expressions are evaluated in `timezone`. An occurrence on an excluded date advances
+    /// the timer without being returned.
+    pub(crate) fn poll(
+        &mut self,
+        now: DateTime<chrono::Local>,
+        timezone: CronTimezone,
+    ) -> Option<DateTime<chrono::Local>> {
+        if self.expired {
+            return None;
+        }
+        match self.local_schedule {
+            Some(local) => {
+                if now > local {
+                    // the occurrence of a jittered fire, the next one is searched after it
+                    let occurrence = local - std::mem::take(&mut self.jitter_offset);
+                    let (from, fires) = match self.catch_up {
+                        _ if std::mem::take(&mut self.skip_missed) => (now, false),
+                        Some(policy) => self.catch_up_from(policy, occurrence, now, timezone),
+                        None => (self.aligned_from(occurrence, now), true),
+                    };
+                    self.local_schedule = self.next_jittered(&from.max(occurrence), timezone);
+                    self.expired = self.local_schedule.is_none();
+                    if !fires
+                        || self.is_stale(&local, now)
+                        || self.is_excluded(timezone.date_of(&occurrence))
+                        || !self.take_quota(&occurrence, timezone)
+                    {
+                        return None;
+                    }
+                    if let Some(history) = &mut self.history {
+                        history.push(FireRecord {
+                            scheduled: local,
+                            actual: now,
+                        });
+                    }
+                    return Some(local);
+                }
+            }
+
+            None => {
+                self.local_schedule = self.next_jittered(&now, timezone);
+                self.expired = self.local_schedule.is_none();
+            }
+        }
+        self.skip_missed = false;
+
+        None
+    }
+
+    /// Where the search for the next occurrence starts after the pending occurrence `local`
+    /// passed at `now`, according to the alignment
+    fn aligned_from(
+        &self,
+        local: DateTime<chrono::Local>,
+        now: DateTime<chrono::Local>,
+    ) -> DateTime<chrono::Local> {
+        match (self.alignment, &self.schedule) {
+            (ScheduleAlignment::ActualFire, _) => now,
+            // skip the intervals missed since `local`, keeping the phase
+            (ScheduleAlignment::Schedule, TimerSchedule::Interval(interval)) => {
+                chrono::Duration::from_std(*interval)
+                    .ok()
+                    .and_then(|interval| {
+                        let missed =
+                            (now - local).num_nanoseconds()? / interval.num_nanoseconds()?.max(1);
+                        let skipped = interval.checked_mul(i32::try_from(missed).ok()?)?;
+                        local.checked_add_signed(skipped)
+                    })
+                    .unwrap_or(now)
+            }
+            (ScheduleAlignment::Schedule, _) => local,
+        }
+    }
+}
+
+/// How [`CronJobPlugin`](crate::CronJobPlugin) dispatches the events of fired timers
+///
+/// With [`CronDispatch::Deferred`] the events are queued as commands and observers run at the
+/// next sync point. [`CronDispatch::Immediate`] checks the timers in an exclusive system that runs
+/// the observers right away, so reactions happen in the same phase of the frame. This trades
+/// parallelism for latency and suits headless servers.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(CronDispatch::Immediate);
+/// ```
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CronDispatch {
+    /// queue the events through [`Commands`]
+    #[default]
+    Deferred,
+    /// trigger the observers from an exclusive system
+    Immediate,
+}
+
+/// The timers, and the changed ones to visit them again
+type TimerQueries<'w, 's> = ParamSet<
+    'w,
+    's,
+    (
+        Query<'static, 'static, (Entity, &'static mut ScheduleTimer)>,
+        Query<'static, 'static, Entity, Changed<ScheduleTimer>>,
+    ),
+>;
+
+/// Settings of the timer check of [`CronJobPlugin`](crate::CronJobPlugin)
+///
+/// Cron expressions resolve to the second, so checking every frame is wasteful. The timers are
+/// checked once `check_interval` of [`Time`] elapsed since the last check, 250 ms by default;
+/// frame timers are ticked with the whole elapsed time, and [`CronFrameStats`] are reset on the
+/// frames in between. Without a [`Time`] resource, the timers are checked every frame.
+///
+/// A throttle delays fires by up to the interval, so apps relying on sub-second
+/// [`ScheduleTimer::every`] timers, and tests stepping a [`CronClock`] frame by frame, should
+/// check [every frame](CronJobConfig::every_frame).
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use std::time::Duration;
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(CronJobConfig::default().with_check_interval(Duration::from_secs(1)));
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CronJobConfig {
+    /// least [`Time`] between two checks, zero to check every frame
+    pub check_interval: Duration,
+}
+
+impl Default for CronJobConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_millis(250),
+        }
+    }
+}
+
+impl CronJobConfig {
+    /// Check the timers on every frame
+    pub fn every_frame() -> Self {
+        Self {
+            check_interval: Duration::ZERO,
+        }
+    }
+
+    /// Check the timers at most once per `interval`
+    pub fn with_check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+}
+
+type CheckParams = (
+    TimerQueries<'static, 'static>,
+    ResMut<'static, CronTimerQueue>,
+    Res<'static, CronClock>,
+    ResMut<'static, CronFrameStats>,
+    Option<ResMut<'static, LastCronFire>>,
+    Option<ResMut<'static, CronHistory>>,
+    Option<Res<'static, CronTimezones>>,
+    Option<Res<'static, CronQuietHours>>,
+    Option<Res<'static, Time>>,
+    Res<'static, CronJobConfig>,
+    Local<'static, Duration>,
+    Commands<'static, 'static>,
+);
+
+/// [`check_schedule_timer`] for [`CronDispatch::Immediate`], applying the queued events before
+/// returning
+pub(crate) fn check_schedule_timer_immediate(
+    world: &mut World,
+    state: &mut SystemState<CheckParams>,
+) {
+    let (
+        timers,
+        queue,
+        clock,
+        stats,
+        last_fire,
+        history,
+        timezones,
+        quiet,
+        time,
+        config,
+        since_check,
+        commands,
+    ) = state.get_mut(world);
+    check_schedule_timer(
+        timers,
+        queue,
+        clock,
+        stats,
+        last_fire,
+        history,
+        timezones,
+        quiet,
+        time,
+        config,
+        since_check,
+        commands,
+    );
+    state.apply(world);
+}
+
+type TickParams = (
+    Query<'static, 'static, (Entity, &'static mut ScheduleTimer)>,
+    ResMut<'static, CronFrameStats>,
+    Option<ResMut<'static, LastCronFire>>,
+    Option<ResMut<'static, CronHistory>>,
+    Option<Res<'static, CronTimezones>>,
+    Option<Res<'static, CronQuietHours>>,
+    Commands<'static, 'static>,
+);
+
+/// Evaluate every [`ScheduleTimer`] against `now` and dispatch the events of the fires before
+/// returning, independently of [`CronJobPlugin`](crate::CronJobPlugin) and its [`CronClock`].
+///
+/// Lets tests, turn-based games and offline tools drive the timers deterministically, e.g. one
+/// call per turn. Frame timers of short intervals are polled like other timers.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use chrono::TimeZone;
+///
+/// let mut world = World::new();
+/// world.spawn(ScheduleTimer::new("every hour"));
+/// let start = chrono::Local.with_ymd_and_hms(2030, 1, 1, 9, 30, 0).unwrap();
+/// // the first call arms the timers, the next ones fire what passed
+/// tick_cron(&mut world, start);
+/// tick_cron(&mut world, start + chrono::Duration::hours(1));
+/// ```
+pub fn tick_cron(world: &mut World, now: DateTime<chrono::Local>) {
+    world.init_resource::<CronFrameStats>();
+    let mut state = SystemState::<TickParams>::new(world);
+    let (query, stats, last_fire, history, timezones, quiet, commands) = state.get_mut(world);
+    check_timers(
+        now, None, true, None, query, stats, last_fire, history, timezones, quiet, commands,
+    );
+    state.apply(world);
+}
+
+/// Counts the [`FixedUpdate`](bevy_app::FixedUpdate) runs of fixed tick timers, for the next
+/// timer check
+pub(crate) fn count_fixed_ticks(mut query: Query<&mut ScheduleTimer>) {
+    for mut timer in query.iter_mut() {
+        if matches!(timer.schedule, TimerSchedule::FixedTicks(_)) && !timer.paused {
+            timer.pending_ticks += 1;
+        }
+    }
+}
+
+/// A system that checks if the cron expression has passed
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn check_schedule_timer(
+    mut timers: TimerQueries,
+    mut queue: ResMut<CronTimerQueue>,
+    clock: Res<CronClock>,
+    mut stats: ResMut<CronFrameStats>,
+    last_fire: Option<ResMut<LastCronFire>>,
+    history: Option<ResMut<CronHistory>>,
+    timezones: Option<Res<CronTimezones>>,
+    quiet: Option<Res<CronQuietHours>>,
+    time: Option<Res<Time>>,
+    config: Res<CronJobConfig>,
+    mut since_check: Local<Duration>,
+    commands: Commands,
+) {
+    // inserted and removed timers are tracked by the hooks of the queue, edited ones through
+    // change detection, every frame as it does not wait for the next check
+    queue.revisit(timers.p1().iter());
+    if let Some(time) = &time {
+        *since_check += time.delta();
+        if *since_check < config.check_interval {
+            // nothing is checked on the frames in between
+            stats.reset();
+            return;
+        }
+    }
+    let elapsed = std::mem::take(&mut *since_check);
+    let now = clock.now();
+    // frame timers follow the frame delta, which only matches clocks advancing with the frames
+    let delta = time.filter(|_| clock.follows_frames()).map(|_| elapsed);
+    let due = queue.take_due(now);
+    check_timers(
+        now,
+        delta,
+        !clock.is_real_time(),
+        Some((queue.into_inner(), due)),
+        timers.p0(),
+        stats,
+        last_fire,
+        history,
+        timezones,
+        quiet,
+        commands,
+    );
+}
+
+/// What a checked timer does with its occurrences, shared by timer entities and
+/// [`CronScheduler`](crate::CronScheduler) jobs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Dispatch {
+    /// fire the occurrence
+    Fire(DateTime<chrono::Local>),
+    /// the occurrence passed without firing, e.g. in quiet hours
+    Skip(DateTime<chrono::Local>),
+    /// the occurrence passed later than its fire expiry
+    Drop(DateTime<chrono::Local>),
+    /// nothing passed
+    Idle,
+}
+
+/// Evaluate the timers against `now` and queue the events of the fires. Frame timers are
+/// ticked with `delta`, or polled like other timers without it. With `scaled`, timers running
+/// at another [speed](ScheduleTimer::with_speed) are evaluated against their own time.
+///
+/// With a `queue`, only the given due timers are visited and indexed again afterwards, otherwise
+/// every timer is.
+#[allow(clippy::too_many_arguments)]
+fn check_timers(
+    clock_now: DateTime<chrono::Local>,
+    delta: Option<Duration>,
+    scaled: bool,
+    queue: Option<(&mut CronTimerQueue, Vec<Entity>)>,
+    mut query: Query<(Entity, &mut ScheduleTimer)>,
+    mut stats: ResMut<CronFrameStats>,
+    mut last_fire: Option<ResMut<LastCronFire>>,
+    mut history: Option<ResMut<CronHistory>>,
+    timezones: Option<Res<CronTimezones>>,
+    quiet: Option<Res<CronQuietHours>>,
+    mut commands: Commands,
+) {
+    let started = Instant::now();
+    stats.reset();
+    let quiet = quiet
+        .filter(|quiet| quiet.is_quiet(&clock_now))
+        .map(|quiet| quiet.policy);
+    // fires with an identical payload are batched into one trigger targeting all their entities
+    let mut batches: Vec<(ScheduleArrived, Vec<Entity>)> = vec![];
+    let mut batch_index: HashMap<ScheduleArrived, usize> = HashMap::new();
+    let mut finished = vec![];
+    let (queue, visited) = match queue {
+        Some((queue, due)) => (Some(queue), due),
+        None => (None, query.iter().map(|(entity, _)| entity).collect()),
+    };
+
+    for &entity in &visited {
+        let Ok((_, mut schedule_timer)) = query.get_mut(entity) else {
+            continue;
+        };
+        if let Some(previous) = schedule_timer.changed_from.clone() {
+            schedule_timer.changed_from = None;
+            debug!(label = schedule_timer.label(), ?entity, %previous, "cron job changed");
+            commands.trigger_targets(
+                ScheduleChanged {
+                    label: schedule_timer.label.clone(),
+                    previous,
+                    schedule: schedule_timer.schedule.to_string(),
+                },
+                entity,
+            );
+        }
+        if schedule_timer.is_held(quiet) {
+            continue;
+        }
+        stats.timers_evaluated += 1;
+        let now = if scaled && schedule_timer.is_scaled() {
+            schedule_timer.scaled_now(clock_now)
+        } else {
+            clock_now
+        };
+        let pending = schedule_timer.local_schedule;
+        let mut polled = false;
+        let fired = match (
+            schedule_timer.schedule.steps(),
+            schedule_timer.frame_interval(),
+            delta,
+        ) {
+            (Some((every, fixed)), ..) => {
+                let steps = if fixed {
+                    std::mem::take(&mut schedule_timer.pending_ticks)
+                } else {
+                    1
+                };
+                if steps == 0 {
+                    continue;
+                }
+                let timezone = match &timezones {
+                    Some(timezones) => timezones.resolve(&schedule_timer),
+                    None => schedule_timer.timezone.unwrap_or_default(),
+                };
+                schedule_timer.tick_steps(every, steps, now, timezone)
+            }
+            (None, Some(interval), Some(delta)) => {
+                schedule_timer.tick_frame_timer(interval, now, delta)
+            }
+            _ => {
+                // only take the timer mutably when it changes, to keep `Changed<ScheduleTimer>`
+                // meaningful
+                if !schedule_timer.is_due(now) {
+                    // resumed before the pending occurrence passed, nothing was missed
+                    if schedule_timer.skip_missed {
+                        schedule_timer.skip_missed = false;
+                    }
+                    continue;
+                }
+                let timezone = match &timezones {
+                    Some(timezones) => timezones.resolve(&schedule_timer),
+                    None => schedule_timer.timezone.unwrap_or_default(),
+                };
+                polled = true;
+                schedule_timer.poll(now, timezone)
+            }
+        };
+        // frame and step timers only report the fires suppressed by quiet hours as skipped, not
+        // every frame they count without firing
+        let missed = pending.filter(|_| polled || fired.is_some());
+        let dispatch = schedule_timer.dispatch(fired, missed, quiet, now);
+        if let (None, Some(first_fire)) = (pending, schedule_timer.local_schedule) {
+            debug!(label = schedule_timer.label(), ?entity, %first_fire, "cron job registered");
+            commands.trigger_targets(
+                ScheduleRegistered {
+                    label: schedule_timer.label.clone(),
+                    first_fire,
+                },
+                entity,
+            );
+        }
+        if let Dispatch::Drop(scheduled) = dispatch {
+            debug!(label = schedule_timer.label(), ?entity, %scheduled, "cron job dropped");
+            commands.trigger_targets(
+                ScheduleFireDropped {
+                    label: schedule_timer.label.clone(),
+                    scheduled,
+                    lateness: (now - scheduled).to_std().unwrap_or_default(),
+                },
+                entity,
+            );
+        }
+        if let Dispatch::Skip(scheduled) = dispatch {
+            debug!(label = schedule_timer.label(), ?entity, %scheduled, "cron job skipped");
+            commands.trigger_targets(
+                ScheduleSkipped {
+                    label: schedule_timer.label.clone(),
+                    scheduled,
+                },
+                entity,
+            );
+        }
+        if let Dispatch::Fire(scheduled) = dispatch {
+            if schedule_timer.catch_up == Some(CatchUpPolicy::FireOnce) {
+                let missed = std::mem::take(&mut schedule_timer.caught_up);
+                if missed > 0 {
+                    commands.trigger_targets(
+                        ScheduleCaughtUp {
+                            label: schedule_timer.label.clone(),
+                            missed,
+                        },
+                        entity,
+                    );
+                }
+            }
+            let exhausted = schedule_timer.quota.as_ref().and_then(|quota| {
+                let period = quota.period.filter(|_| quota.remaining() == 0)?;
+                Some((quota.limit, period))
+            });
+            if let Some((limit, period)) = exhausted {
+                commands.trigger_targets(
+                    QuotaExhausted {
+                        label: schedule_timer.label.clone(),
+                        limit,
+                        period,
+                    },
+                    entity,
+                );
+            }
+            schedule_timer.last_run = Some(now);
+            if schedule_timer.count_run() {
+                finished.push((entity, schedule_timer.on_finish));
+            }
+            let drift = (now - scheduled).to_std().unwrap_or_default();
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_fire(schedule_timer.label(), drift);
+            if schedule_timer
+                .late_threshold
+                .is_some_and(|threshold| drift > threshold)
+            {
+                commands.trigger_targets(
+                    ScheduleLate {
+                        label: schedule_timer.label.clone(),
+                        scheduled,
+                        actual: now,
+                        lateness: drift,
+                    },
+                    entity,
+                );
+            }
+            if drift >= stats.worst_drift {
+                stats.worst_drift = drift;
+                stats.worst_drift_label.clone_from(&schedule_timer.label);
+            }
+            if let (Some(last_fire), Some(label)) = (last_fire.as_mut(), &schedule_timer.label) {
+                last_fire.record(label, now);
+            }
+            if let Some(history) = history.as_mut() {
+                history.push(CronHistoryEntry {
+                    label: schedule_timer.label.clone(),
+                    entity,
+                    record: FireRecord {
+                        scheduled,
+                        actual: now,
+                    },
+                });
+            }
+            debug!(label = schedule_timer.label(), ?entity, "cron job fired");
+            commands.trigger(AnyScheduleArrived {
+                entity,
+                label: schedule_timer.label.clone(),
+                scheduled,
+                actual: now,
+            });
+            stats.fires_dispatched += 1;
+            let event = ScheduleArrived {
+                label: schedule_timer.label.clone(),
+                scheduled,
+                actual: now,
+                lateness: drift,
+            };
+            match batch_index.get(&event) {
+                Some(&index) => batches[index].1.push(entity),
+                None => {
+                    batch_index.insert(event.clone(), batches.len());
+                    batches.push((event, vec![entity]));
+                }
+            }
+        }
+    }
+
+    if let Some(queue) = queue {
+        for &entity in &visited {
+            if let Ok((_, timer)) = query.get(entity) {
+                queue.index(entity, timer);
+            }
+        }
+    }
+    stats.timers_skipped = query.iter().len() - stats.timers_evaluated;
+    stats.triggers_queued = batches.len();
+    for (event, targets) in batches {
+        commands.trigger_targets(event, targets);
+    }
+    // after the fires, so their observers still see the timers
+    for (entity, action) in finished {
+        if let Ok((_, timer)) = query.get(entity) {
+            debug!(label = timer.label(), ?entity, "cron job finished");
+            commands.trigger_targets(
+                ScheduleFinished {
+                    label: timer.label.clone(),
+                    runs: timer.runs,
+                },
+                entity,
+            );
+        }
+        match action {
+            FinishAction::Deactivate => {}
+            FinishAction::RemoveTimer => {
+                commands.entity(entity).remove::<ScheduleTimer>();
+            }
+            FinishAction::Despawn => {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+    stats.check_duration = started.elapsed();
+}
+
+#[test]
+fn test_random_daily_between() {
+    use chrono::Timelike;
+
+    let start = NaiveTime::from_hms_opt(22, 0, 0).unwrap();
+    let end = NaiveTime::from_hms_opt(2, 0, 0).unwrap();
+    let mut schedule = ScheduleTimer::random_daily_between(start, end)
+        .with_seed(7)
+        .schedule;
+    let mut after = chrono::Local
+        .with_ymd_and_hms(2024, 3, 1, 12, 0, 0)
+        .unwrap();
+    let mut previous = None;
+    for _ in 0..5 {
+        let next = schedule.next_after(&after).unwrap();
+        assert!(next > after);
+        assert!(next.hour() >= 22 || next.hour() < 2);
+        if let Some(previous) = previous {
+            // one fire per window
+            assert!(next - previous >= chrono::Duration::hours(20));
+        }
+        previous = Some(next);
+        after = next;
+    }
+}
+
+#[test]
+fn test_poisson() {
+    let mut schedule = ScheduleTimer::poisson(Duration::from_secs(60))
+        .with_seed(3)
+        .schedule;
+    let start = chrono::Local::now();
+    let mut after = start;
+    for _ in 0..1000 {
+        let next = schedule.next_after(&after).unwrap();
+        assert!(next >= after);
+        after = next;
+    }
+    let average = (after - start).num_seconds() / 1000;
+    assert!((50..70).contains(&average), "average gap {average}s");
+}
+
+#[test]
+fn test_label_in_event() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>();
+    for label in [Some("autosave"), None] {
+        let mut timer = ScheduleTimer::new("every hour");
+        if let Some(label) = label {
+            timer = timer.with_label(label);
+        }
+        app.world_mut().spawn(timer);
+    }
+    app.update();
+    app.advance_cron_time(Duration::from_secs(3600));
+
+    let mut received = app
+        .triggered::<ScheduleArrived>()
+        .iter()
+        .map(|(_, arrived)| arrived.label.clone())
+        .collect::<Vec<_>>();
+    received.sort();
+    assert_eq!(received, vec![None, Some("autosave".to_string())]);
+}
+
+#[test]
+fn test_fire_times_in_event() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>();
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every 10 seconds"));
+    app.update();
+    app.advance_cron_time(Duration::from_secs(12));
+
+    let epoch = CronClock::ticks(1).now();
+    let received = app
+        .triggered::<ScheduleArrived>()
+        .iter()
+        .map(|(_, arrived)| arrived)
+        .collect::<Vec<_>>();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].scheduled, epoch + chrono::Duration::seconds(10));
+    assert_eq!(received[0].actual, epoch + chrono::Duration::seconds(13));
+    assert_eq!(received[0].lateness, Duration::from_secs(3));
+}
+
+#[test]
+fn test_batched_trigger() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>();
+    for index in 0..50 {
+        let label = if index % 2 == 0 { "even" } else { "odd" };
+        app.world_mut()
+            .spawn(ScheduleTimer::new("every hour").with_label(label));
+    }
+    app.update();
+    app.advance_cron_time(Duration::from_secs(3600));
+
+    let stats = app.world().resource::<CronFrameStats>();
+    assert_eq!(stats.fires_dispatched, 50);
+    assert_eq!(stats.triggers_queued, 2);
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 50);
+}
+
+#[test]
+fn test_immediate_dispatch() {
+    use crate::testing::{cron_app, TestAppExt, Triggered};
+    use crate::CronAppExt;
+    use bevy_app::prelude::*;
+
+    let mut app = cron_app();
+    app.insert_resource(CronDispatch::Immediate)
+        .record::<ScheduleArrived>();
+    app.world_mut().spawn(ScheduleTimer::new("every hour"));
+    app.update();
+    app.add_systems(
+        Update,
+        // no sync point between the check and this system, yet the observer already ran
+        (|triggered: Res<Triggered<ScheduleArrived>>| assert_eq!(triggered.0.len(), 1))
+            .after(check_schedule_timer_immediate),
+    );
+    app.advance_cron_time(Duration::from_secs(3600));
+
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 1);
+    assert_eq!(app.world().resource::<CronFrameStats>().fires_dispatched, 1);
+}
+
+#[test]
+fn test_fire_history() {
+    let mut timer = ScheduleTimer::new("every second").with_history(2);
+    let start = chrono::Local::now();
+    timer.poll(start, CronTimezone::Local);
+    for seconds in 1..=3 {
+        let now = start + chrono::Duration::seconds(seconds);
+        assert!(timer.poll(now, CronTimezone::Local).is_some());
+    }
+    let history = timer.history().collect::<Vec<_>>();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].actual, start + chrono::Duration::seconds(3));
+    assert!(history[0].scheduled <= history[0].actual);
+}
+
+#[test]
+fn test_late_event() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<ScheduleLate>();
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_late_threshold(Duration::from_secs(10)));
+    app.update();
+    // one second past the first hour, then 30 seconds past the second one
+    app.advance_cron_time(Duration::from_secs(3600));
+    app.advance_cron_time(Duration::from_secs(3629));
+
+    let late = app.triggered::<ScheduleLate>();
+    assert_eq!(late.len(), 1);
+    assert_eq!(late[0].1.lateness, Duration::from_secs(30));
+}
+
+#[test]
+fn test_excluded_dates() {
+    use crate::testing::{cron_app, start, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<ScheduleSkipped>();
+    let due = start() + chrono::Duration::hours(1) - chrono::Duration::seconds(1);
+    let today = due.date_naive();
+    let excluded = app
+        .world_mut()
+        .spawn(
+            ScheduleTimer::new("every hour")
+                .with_history(4)
+                .with_excluded_ranges([today.pred_opt().unwrap()..=today]),
+        )
+        .id();
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_excluded_dates([today.succ_opt().unwrap()]));
+    app.update();
+    app.advance_cron_time(Duration::from_secs(3600));
+
+    assert_eq!(app.triggered::<ScheduleSkipped>().len(), 1);
+    assert_eq!(app.world().resource::<CronFrameStats>().fires_dispatched, 1);
+    let timer = app.world().get::<ScheduleTimer>(excluded).unwrap();
+    assert_eq!(timer.history().count(), 0);
+    assert!(timer.local_schedule.is_some_and(|next| next > due));
+}
+
+#[test]
+fn test_interval() {
+    let mut timer = ScheduleTimer::every(Duration::from_millis(250));
+    let start = chrono::Local::now();
+    timer.poll(start, CronTimezone::Local);
+    let first = start + chrono::Duration::milliseconds(250);
+    assert_eq!(timer.local_schedule, Some(first));
+
+    // a 16 ms late frame keeps the cadence
+    let frame = first + chrono::Duration::milliseconds(16);
+    assert_eq!(timer.poll(frame, CronTimezone::Local), Some(first));
+    assert_eq!(
+        timer.local_schedule,
+        Some(first + chrono::Duration::milliseconds(250))
+    );
+
+    // a one second stall skips the missed occurrences instead of bursting
+    let stall = first + chrono::Duration::milliseconds(1_100);
+    assert!(timer.poll(stall, CronTimezone::Local).is_some());
+    assert_eq!(
+        timer.local_schedule,
+        Some(first + chrono::Duration::milliseconds(1_250))
+    );
+    assert_eq!(timer.poll(stall, CronTimezone::Local), None);
+}
+
+#[test]
+fn test_frame_timer() {
+    use bevy_app::prelude::*;
+
+    let mut app = App::new();
+    app.add_plugins(crate::CronJobPlugin)
+        .insert_resource(CronJobConfig::every_frame())
+        .init_resource::<Time>();
+    let entity = app
+        .world_mut()
+        .spawn(ScheduleTimer::every(Duration::from_millis(20)))
+        .id();
+    let mut fires = 0;
+    for _ in 0..10 {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        app.update();
+        fires += app.world().resource::<CronFrameStats>().fires_dispatched;
+    }
+
+    // armed on the first frame, then 9 frames of 16 ms cross the 20 ms interval 7 times
+    assert_eq!(fires, 7);
+    let timer = app.world().get::<ScheduleTimer>(entity).unwrap();
+    assert!(timer.frame_timer.is_some());
+}
+
+#[test]
+fn test_frame_timer_not_skipped() {
+    use crate::testing::TestAppExt;
+    use bevy_app::prelude::*;
+
+    // on the wall clock of a plain app, as frame timers do not follow the manual clock
+    let mut app = App::new();
+    app.add_plugins(crate::CronJobPlugin)
+        .insert_resource(CronJobConfig::every_frame())
+        .init_resource::<Time>()
+        .record::<ScheduleSkipped>();
+    app.world_mut()
+        .spawn(ScheduleTimer::every(Duration::from_millis(16)));
+    let mut fires = 0;
+    for _ in 0..10 {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(10));
+        app.update();
+        fires += app.world().resource::<CronFrameStats>().fires_dispatched;
+    }
+
+    // the frames between the fires are not reported as skipped occurrences
+    assert!(fires > 0);
+    assert!(app.triggered::<ScheduleSkipped>().is_empty());
+}
+
+#[test]
+fn test_alignment() {
+    let start = chrono::Local::now();
+    let stall = start + chrono::Duration::seconds(10);
+    for (alignment, fires) in [
+        (ScheduleAlignment::ActualFire, 1),
+        (ScheduleAlignment::Schedule, 10),
+    ] {
+        let mut timer = ScheduleTimer::new("every second").with_alignment(alignment);
+        timer.poll(start, CronTimezone::Local);
+        let mut count = 0;
+        while timer.poll(stall, CronTimezone::Local).is_some() {
+            count += 1;
+        }
+        assert!(
+            (fires..=fires + 1).contains(&count),
+            "{alignment:?} fired {count} times"
+        );
+    }
+}
+
+#[test]
+fn test_any_schedule_arrived() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<AnyScheduleArrived>();
+    let entity = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_label("hourly"))
+        .id();
+    app.update();
+    app.advance_cron_time(Duration::from_secs(3600));
+    assert_eq!(
+        app.triggered::<AnyScheduleArrived>()
+            .iter()
+            .map(|(_, any)| (any.entity, any.label.clone()))
+            .collect::<Vec<_>>(),
+        [(entity, Some("hourly".to_string()))]
+    );
+}
+
+#[test]
+fn test_registered_event() {
+    use crate::testing::{cron_app, start, TestAppExt};
+
+    let mut app = cron_app();
+    app.record::<ScheduleRegistered>();
+    app.world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_label("hourly"));
+    // no occurrence left, never armed
+    app.world_mut()
+        .spawn(ScheduleTimer::new("0 0 0 1 1 ? 1999"));
+    app.update();
+    app.update();
+    let registered = app.triggered::<ScheduleRegistered>();
+    assert_eq!(registered.len(), 1);
+    assert_eq!(registered[0].1.label.as_deref(), Some("hourly"));
+    assert_eq!(
+        registered[0].1.first_fire,
+        start() + chrono::Duration::hours(1) - chrono::Duration::seconds(1)
+    );
+}
+
+#[test]
+fn test_change_detection() {
+    use crate::testing::cron_app;
+    use bevy_app::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Changes(usize);
+
+    let mut app = cron_app();
+    app.init_resource::<Changes>().add_systems(
+        Last,
+        |query: Query<(), Changed<ScheduleTimer>>, mut changes: ResMut<Changes>| {
+            changes.0 += query.iter().count();
+        },
+    );
+    app.world_mut().spawn(ScheduleTimer::new("every hour"));
+    // spawned and armed
+    app.update();
+    assert_eq!(app.world().resource::<Changes>().0, 1);
+    // waiting for the next hour
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Changes>().0, 1);
+}
+
+#[test]
+fn test_tick_cron() {
+    use chrono::TimeZone;
+
+    #[derive(Resource, Default)]
+    struct Fired(Vec<DateTime<chrono::Local>>);
+
+    let mut world = World::new();
+    world.init_resource::<Fired>();
+    world.add_observer(
+        |trigger: Trigger<AnyScheduleArrived>, mut fired: ResMut<Fired>| {
+            fired.0.push(trigger.event().scheduled);
+        },
+    );
+    world.spawn(ScheduleTimer::new("every hour").with_alignment(ScheduleAlignment::Schedule));
+    let start = chrono::Local
+        .with_ymd_and_hms(2030, 1, 1, 9, 30, 0)
+        .unwrap();
+    tick_cron(&mut world, start);
+    assert!(world.resource::<Fired>().0.is_empty());
+    for hour in 1..=3 {
+        tick_cron(&mut world, start + chrono::Duration::hours(hour));
+    }
+    let hour = |hour| {
+        chrono::Local
+            .with_ymd_and_hms(2030, 1, 1, hour, 0, 0)
+            .unwrap()
+    };
+    assert_eq!(world.resource::<Fired>().0, [hour(10), hour(11), hour(12)]);
+    assert_eq!(world.resource::<CronFrameStats>().fires_dispatched, 1);
+}
+
+#[test]
+fn test_speed() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+    use bevy_app::App;
+
+    let epoch = CronClock::ticks(1).now();
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>();
+    // the seconds of the clock at each fire
+    let mut fired = vec![];
+    let mut step = |app: &mut App| {
+        app.advance_cron_time(Duration::from_secs(1));
+        if app.triggered::<ScheduleArrived>().len() > fired.len() {
+            let now = app.world().resource::<CronClock>().now();
+            fired.push((now - epoch).num_seconds());
+        }
+        fired.clone()
+    };
+    let timer = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every 10 seconds").with_speed(2.0))
+        .id();
+    app.update();
+    for _ in 1..19 {
+        step(&mut app);
+    }
+    // the time of the timer starts at the clock on the first check, then reads 2k - 1 s at k s
+    assert_eq!(step(&mut app), [6, 11, 16]);
+
+    // back to normal speed, the time already gained is kept
+    app.world_mut()
+        .get_mut::<ScheduleTimer>(timer)
+        .unwrap()
+        .speed = 1.0;
+    for _ in 0..9 {
+        step(&mut app);
+    }
+    // 39 s at 20 s, so 40 s passes at 22 s instead of 41 s
+    assert_eq!(step(&mut app), [6, 11, 16, 22]);
+}
+
+#[test]
+fn test_fire_expiry() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>()
+        .record::<ScheduleFireDropped>();
+    let timer = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every hour").with_fire_expiry(Duration::from_secs(10)))
+        .id();
+    app.update();
+    // one second past the first hour, then 30 seconds past the second one
+    app.advance_cron_time(Duration::from_secs(3600));
+    app.advance_cron_time(Duration::from_secs(3629));
+
+    assert_eq!(app.triggered::<ScheduleArrived>().len(), 1);
+    let dropped = app.triggered::<ScheduleFireDropped>();
+    assert_eq!(dropped.len(), 1);
+    assert_eq!(dropped[0].1.lateness, Duration::from_secs(30));
+    // the dropped timer moves on to its next occurrence
+    let now = app.world().resource::<CronClock>().now();
+    let stale = app.world().get::<ScheduleTimer>(timer).unwrap();
+    assert!(stale.local_schedule.unwrap() > now);
+}
+
+#[test]
+fn test_step_timers() {
+    use crate::testing::{cron_app, TestAppExt, Triggered};
+    use bevy_app::prelude::*;
+
+    let mut app = cron_app();
+    app.record::<ScheduleArrived>();
+    let fired = |app: &App| {
+        app.triggered::<ScheduleArrived>()
+            .iter()
+            .filter_map(|(_, fire)| fire.label.clone())
+            .collect::<Vec<_>>()
+    };
+    app.world_mut()
+        .spawn(ScheduleTimer::every_frames(3).with_label("frames"));
+    let ticks = app
+        .world_mut()
+        .spawn(ScheduleTimer::every_fixed_ticks(2).with_label("ticks"))
+        .id();
+    for _ in 0..7 {
+        app.update();
+    }
+    assert_eq!(fired(&app), ["frames", "frames"]);
+
+    // fixed ticks run independently of the frames, several ticks in a frame fire once
+    app.world_mut()
+        .resource_mut::<Triggered<ScheduleArrived>>()
+        .0
+        .clear();
+    for _ in 0..5 {
+        app.world_mut().run_schedule(FixedUpdate);
+    }
+    app.update();
+    assert_eq!(fired(&app), ["ticks"]);
+    let timer = app.world().get::<ScheduleTimer>(ticks).unwrap();
+    assert_eq!((timer.steps, timer.pending_ticks), (1, 0));
+    assert!(!timer.is_armed());
+}
+
+#[test]
+fn test_resume_policy() {
+    use crate::testing::{cron_app, TestAppExt};
+    use bevy_app::prelude::*;
+    for (policy, arrived, skipped) in [
+        (ResumePolicy::FireMissed, 2, 0),
+        (ResumePolicy::SkipMissed, 1, 1),
+    ] {
+        let mut app = cron_app();
+        app.record::<ScheduleArrived>().record::<ScheduleSkipped>();
+        let timer = app
+            .world_mut()
+            .spawn(ScheduleTimer::new("every 10 seconds"))
+            .id();
+        let step = |app: &mut App, seconds: u64, paused: Option<bool>| {
+            app.world_mut()
+                .resource_mut::<CronClock>()
+                .fast_forward(Duration::from_secs(seconds));
+            let mut timer = app.world_mut().get_mut::<ScheduleTimer>(timer).unwrap();
+            match paused {
+                Some(true) => timer.pause(),
+                Some(false) => timer.resume_with(policy),
+                None => {}
+            }
+            app.update();
+        };
+        step(&mut app, 0, None);
+        // paused over the occurrence at 10 s
+        step(&mut app, 0, Some(true));
+        step(&mut app, 25, Some(false));
+        // paused and resumed before the occurrence at 30 s, which fires either way
+        step(&mut app, 1, Some(true));
+        step(&mut app, 1, Some(false));
+        step(&mut app, 5, None);
+
+        assert_eq!(
+            (
+                app.triggered::<ScheduleArrived>().len(),
+                app.triggered::<ScheduleSkipped>().len()
+            ),
+            (arrived, skipped),
+            "{policy:?}"
+        );
+    }
+}
+
+#[test]
+fn test_next_and_upcoming_runs() {
+    use crate::testing::cron_app;
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    let timer = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every 10 seconds"))
+        .id();
+    let epoch = CronClock::ticks(1).now();
+    let at = |seconds| epoch + chrono::Duration::seconds(seconds);
+    assert_eq!(
+        app.world().get::<ScheduleTimer>(timer).unwrap().next_run(),
+        None
+    );
+    app.update();
+    app.advance_cron_time(Duration::from_secs(12));
+    let timer = app.world().get::<ScheduleTimer>(timer).unwrap();
+    assert_eq!(timer.next_run(), Some(at(20)));
+    assert_eq!(timer.last_run(), Some(at(13)));
+    assert_eq!(timer.upcoming(3), [at(20), at(30), at(40)]);
+    assert!(ScheduleTimer::every_frames(2).upcoming(3).is_empty());
+}
+
+#[test]
+fn test_check_interval() {
+    use crate::testing::cron_app;
+
+    // checked every 250 ms by default
+    let mut app = cron_app();
+    app.init_resource::<Time>();
+    let timer = app
+        .world_mut()
+        .spawn(ScheduleTimer::new("every 10 seconds"))
+        .id();
+    let mut armed = vec![];
+    let mut evaluated = vec![];
+    for _ in 0..4 {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(100));
+        app.update();
+        armed.push(
+            app.world()
+                .get::<ScheduleTimer>(timer)
+                .unwrap()
+                .local_schedule
+                .is_some(),
+        );
+        evaluated.push(app.world().resource::<CronFrameStats>().timers_evaluated);
+    }
+    // checked once 300 ms elapsed, with empty stats on the frames in between
+    assert_eq!(armed, [false, false, true, true]);
+    assert_eq!(evaluated, [0, 0, 1, 0]);
+}
@@ -0,0 +1,269 @@
+use crate::{CronError, CronExpr, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Timezone cron expressions are evaluated in
+///
+/// Parses from `"Local"`, `"UTC"`, an offset such as `"+02:00"` and, with the `timezone` feature,
+/// IANA names such as `"Europe/Berlin"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CronTimezone {
+    /// the system timezone
+    #[default]
+    Local,
+    /// coordinated universal time
+    Utc,
+    /// a fixed offset from UTC, without daylight saving time
+    Fixed(FixedOffset),
+    /// a named timezone, following its daylight saving time rules
+    #[cfg(feature = "timezone")]
+    Named(chrono_tz::Tz),
+}
+
+impl CronTimezone {
+    /// The next occurrence of `expr` strictly after `after`, with the expression evaluated in
+    /// this timezone
+    pub fn next_after(
+        &self,
+        expr: &CronExpr,
+        after: &DateTime<chrono::Local>,
+    ) -> Option<DateTime<chrono::Local>> {
+        match self {
+            CronTimezone::Local => expr.after(after).next(),
+            CronTimezone::Utc => next_in(expr, after, chrono::Utc),
+            CronTimezone::Fixed(offset) => next_in(expr, after, *offset),
+            #[cfg(feature = "timezone")]
+            CronTimezone::Named(tz) => next_in(expr, after, *tz),
+        }
+    }
+
+    /// The timezone of the system, resolved against the timezone database bundled with the
+    /// `bundled_tz` feature.
+    ///
+    /// Unlike [`CronTimezone::Local`], which silently falls back to UTC where chrono cannot read a
+    /// system timezone database, e.g. in browsers or on some Android devices, this follows the
+    /// daylight saving time rules of the zone the platform reports, or fails with
+    /// [`CronError::SystemTimezoneUnavailable`] or [`CronError::UnknownTimezone`].
+    ///
+    /// ```rust
+    /// use bevy_cronjob::prelude::*;
+    ///
+    /// let timezones = CronTimezones::default()
+    ///     .with_default(CronTimezone::system().unwrap_or(CronTimezone::Utc));
+    /// ```
+    #[cfg(feature = "bundled_tz")]
+    pub fn system() -> Result<Self, CronError> {
+        let name = iana_time_zone::get_timezone().map_err(|error| {
+            CronError::SystemTimezoneUnavailable {
+                reason: error.to_string(),
+            }
+        })?;
+        name.parse()
+    }
+
+    /// The wall clock time of `datetime` in this timezone
+    pub fn time_of(&self, datetime: &DateTime<chrono::Local>) -> NaiveTime {
+        match self {
+            CronTimezone::Local => datetime.time(),
+            CronTimezone::Utc => datetime.with_timezone(&chrono::Utc).time(),
+            CronTimezone::Fixed(offset) => datetime.with_timezone(offset).time(),
+            #[cfg(feature = "timezone")]
+            CronTimezone::Named(tz) => datetime.with_timezone(tz).time(),
+        }
+    }
+
+    /// The calendar date of `datetime` in this timezone
+    pub fn date_of(&self, datetime: &DateTime<chrono::Local>) -> NaiveDate {
+        match self {
+            CronTimezone::Local => datetime.date_naive(),
+            CronTimezone::Utc => datetime.with_timezone(&chrono::Utc).date_naive(),
+            CronTimezone::Fixed(offset) => datetime.with_timezone(offset).date_naive(),
+            #[cfg(feature = "timezone")]
+            CronTimezone::Named(tz) => datetime.with_timezone(tz).date_naive(),
+        }
+    }
+}
+
+fn next_in<Z: TimeZone>(
+    expr: &CronExpr,
+    after: &DateTime<chrono::Local>,
+    timezone: Z,
+) -> Option<DateTime<chrono::Local>> {
+    expr.after(&after.with_timezone(&timezone))
+        .next()
+        .map(|datetime| datetime.with_timezone(&chrono::Local))
+}
+
+impl fmt::Display for CronTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CronTimezone::Local => write!(f, "Local"),
+            CronTimezone::Utc => write!(f, "UTC"),
+            CronTimezone::Fixed(offset) => write!(f, "{offset}"),
+            #[cfg(feature = "timezone")]
+            CronTimezone::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+impl FromStr for CronTimezone {
+    type Err = CronError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "Local" | "local" => return Ok(CronTimezone::Local),
+            "UTC" | "utc" | "Z" => return Ok(CronTimezone::Utc),
+            _ => {}
+        }
+        if let Ok(offset) = name.parse::<FixedOffset>() {
+            return Ok(CronTimezone::Fixed(offset));
+        }
+        #[cfg(feature = "timezone")]
+        if let Ok(tz) = name.parse::<chrono_tz::Tz>() {
+            return Ok(CronTimezone::Named(tz));
+        }
+        Err(CronError::UnknownTimezone {
+            name: name.to_string(),
+        })
+    }
+}
+
+impl From<FixedOffset> for CronTimezone {
+    fn from(offset: FixedOffset) -> Self {
+        CronTimezone::Fixed(offset)
+    }
+}
+
+#[cfg(feature = "timezone")]
+impl From<chrono_tz::Tz> for CronTimezone {
+    fn from(tz: chrono_tz::Tz) -> Self {
+        CronTimezone::Named(tz)
+    }
+}
+
+/// Timezones of groups of timers, so shards or regions served by one process each follow their
+/// own local time.
+///
+/// A timer is evaluated in its own timezone (see [`ScheduleTimer::with_timezone`]), else in the
+/// timezone of its first tag or label with a group timezone, else in the default.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// App::new().add_plugins(CronJobPlugin).insert_resource(
+///     CronTimezones::default()
+///         .with_group("eu_shard", "+01:00".parse::<CronTimezone>().unwrap())
+///         .with_group("na_shard", "-05:00".parse::<CronTimezone>().unwrap()),
+/// );
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CronTimezones {
+    /// timezone of timers without an own or group timezone
+    pub default: CronTimezone,
+    groups: HashMap<String, CronTimezone>,
+}
+
+impl CronTimezones {
+    /// Set the timezone of timers without an own or group timezone
+    pub fn with_default(mut self, timezone: impl Into<CronTimezone>) -> Self {
+        self.default = timezone.into();
+        self
+    }
+
+    /// Evaluate timers tagged or labeled `group` in `timezone`
+    pub fn with_group(
+        mut self,
+        group: impl Into<String>,
+        timezone: impl Into<CronTimezone>,
+    ) -> Self {
+        self.set_group(group, timezone);
+        self
+    }
+
+    /// Set or replace the timezone of a group
+    pub fn set_group(&mut self, group: impl Into<String>, timezone: impl Into<CronTimezone>) {
+        self.groups.insert(group.into(), timezone.into());
+    }
+
+    /// The timezone of a group, if one is set
+    pub fn group(&self, group: &str) -> Option<CronTimezone> {
+        self.groups.get(group).copied()
+    }
+
+    /// The timezone `timer` is evaluated in
+    pub fn resolve(&self, timer: &ScheduleTimer) -> CronTimezone {
+        timer
+            .timezone
+            .or_else(|| timer.tags.iter().find_map(|tag| self.group(tag)))
+            .or_else(|| timer.label().and_then(|label| self.group(label)))
+            .unwrap_or(self.default)
+    }
+}
+
+#[test]
+fn test_group_timezone() {
+    use chrono::Timelike;
+
+    let berlin: CronTimezone = "+01:00".parse().unwrap();
+    let new_york: CronTimezone = "-05:00".parse().unwrap();
+    let timezones = CronTimezones::default()
+        .with_default(CronTimezone::Utc)
+        .with_group("eu_shard", berlin)
+        .with_group("na_shard", new_york);
+    let eu = ScheduleTimer::new("0 0 9 * * ? *").with_tag("eu_shard");
+    let na = ScheduleTimer::new("0 0 9 * * ? *").with_label("na_shard");
+    let own = ScheduleTimer::new("0 0 9 * * ? *")
+        .with_tag("eu_shard")
+        .with_timezone(new_york);
+    let other = ScheduleTimer::new("0 0 9 * * ? *");
+    assert_eq!(timezones.resolve(&eu), berlin);
+    assert_eq!(timezones.resolve(&na), new_york);
+    assert_eq!(timezones.resolve(&own), new_york);
+    assert_eq!(timezones.resolve(&other), CronTimezone::Utc);
+
+    let expr = CronExpr::parse("0 0 9 * * ? *").unwrap();
+    let now = chrono::Local::now();
+    let utc_hour = |timezone: CronTimezone| {
+        timezone
+            .next_after(&expr, &now)
+            .unwrap()
+            .with_timezone(&chrono::Utc)
+            .hour()
+    };
+    assert_eq!(utc_hour(berlin), 8);
+    assert_eq!(utc_hour(new_york), 14);
+    assert_eq!(utc_hour(CronTimezone::Utc), 9);
+    assert!("Mars/Olympus".parse::<CronTimezone>().is_err());
+    #[cfg(not(feature = "timezone"))]
+    assert_eq!(
+        "Europe/Berlin"
+            .parse::<CronTimezone>()
+            .unwrap_err()
+            .to_string(),
+        "unknown timezone `Europe/Berlin`, named timezones require the `timezone` feature"
+    );
+}
+
+#[cfg(feature = "timezone")]
+#[test]
+fn test_named_timezone() {
+    let tz: CronTimezone = "Europe/Berlin".parse().unwrap();
+    assert_eq!(tz, CronTimezone::Named(chrono_tz::Europe::Berlin));
+    assert_eq!(tz.to_string(), "Europe/Berlin");
+}
+
+#[cfg(feature = "bundled_tz")]
+#[test]
+fn test_system_timezone() {
+    match CronTimezone::system() {
+        Ok(tz) => assert!(matches!(tz, CronTimezone::Named(_) | CronTimezone::Utc)),
+        Err(error) => assert!(matches!(
+            error,
+            CronError::SystemTimezoneUnavailable { .. } | CronError::UnknownTimezone { .. }
+        )),
+    }
+}
@@ -0,0 +1,133 @@
+//! Restart [`bevy_tweening`] animators when a schedule fires.
+
+use crate::{CronClock, IntoCronExpression, ScheduleArrived, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::EntityCommands;
+use bevy_tweening::{Animator, Tweenable};
+
+type StartTween = Box<dyn Fn(&mut EntityCommands) + Send + Sync>;
+
+/// Starts a fresh [`Animator`] on the entity each time the [`ScheduleTimer`] on the same entity
+/// fires, and optionally another one when a second schedule fires, e.g. to play the tween back.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use bevy_cronjob::tween::ScheduledTween;
+/// use bevy_tweening::{lens::TransformRotateYLens, Tween};
+/// use std::time::Duration;
+///
+/// fn sweep(start: f32, end: f32) -> Tween<Transform> {
+///     Tween::new(
+///         EaseFunction::SineInOut,
+///         Duration::from_secs(3),
+///         TransformRotateYLens { start, end },
+///     )
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // the lighthouse beam sweeps out every 10 seconds and back 5 seconds later
+///     commands.spawn((
+///         Transform::default(),
+///         ScheduleTimer::new("0/10 * * * * ? *"),
+///         ScheduledTween::new(|| sweep(0.0, 3.14))
+///             .reverse_on("5/10 * * * * ? *", || sweep(3.14, 0.0)),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct ScheduledTween {
+    forward: StartTween,
+    reverse: Option<(ScheduleTimer, StartTween)>,
+}
+
+impl ScheduledTween {
+    /// Start the tween built by `tween` whenever the timer on the entity fires
+    pub fn new<T: Component, U: Tweenable<T> + 'static>(
+        tween: impl Fn() -> U + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            forward: start_tween(tween),
+            reverse: None,
+        }
+    }
+
+    /// Also start the tween built by `tween` whenever `expression` passes
+    pub fn reverse_on<T: Component, U: Tweenable<T> + 'static>(
+        mut self,
+        expression: impl IntoCronExpression,
+        tween: impl Fn() -> U + Send + Sync + 'static,
+    ) -> Self {
+        self.reverse = Some((ScheduleTimer::new(expression), start_tween(tween)));
+        self
+    }
+}
+
+fn start_tween<T: Component, U: Tweenable<T> + 'static>(
+    tween: impl Fn() -> U + Send + Sync + 'static,
+) -> StartTween {
+    Box::new(move |entity: &mut EntityCommands| {
+        entity.insert(Animator::new(tween()));
+    })
+}
+
+pub(crate) fn start_scheduled_tween(
+    trigger: Trigger<ScheduleArrived>,
+    query: Query<&ScheduledTween>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    if let Ok(tween) = query.get(entity) {
+        (tween.forward)(&mut commands.entity(entity));
+    }
+}
+
+pub(crate) fn start_reverse_tweens(
+    mut query: Query<(Entity, &mut ScheduledTween)>,
+    clock: Res<CronClock>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    for (entity, mut tween) in query.iter_mut() {
+        let Some((timer, start)) = &mut tween.reverse else {
+            continue;
+        };
+        if timer
+            .poll(now, timer.timezone.unwrap_or_default())
+            .is_some()
+        {
+            start(&mut commands.entity(entity));
+        }
+    }
+}
+
+#[test]
+fn test_scheduled_tween() {
+    use crate::testing::{cron_app, start};
+    use bevy::prelude::*;
+    use bevy_tweening::{lens::TransformScaleLens, Tween};
+    use std::time::Duration;
+
+    let mut app = cron_app();
+    let mut timer = ScheduleTimer::new("every hour");
+    timer.local_schedule = Some(start() - chrono::Duration::seconds(1));
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::default(),
+            timer,
+            ScheduledTween::new(|| {
+                Tween::new(
+                    EaseFunction::Linear,
+                    Duration::from_secs(1),
+                    TransformScaleLens {
+                        start: Vec3::ONE,
+                        end: Vec3::splat(2.0),
+                    },
+                )
+            }),
+        ))
+        .id();
+    app.update();
+    assert!(app.world().get::<Animator<Transform>>(entity).is_some());
+}
@@ -0,0 +1,131 @@
+//! Keep [`bevy_ui`] text showing the countdown of a [`UiCountdown`].
+
+use crate::UiCountdown;
+use bevy_ecs::prelude::*;
+use bevy_time::{Time, Timer, TimerMode};
+use bevy_ui::widget::Text;
+use std::time::Duration;
+
+/// Renders the [`UiCountdown`] on the same entity into its [`Text`], or into
+/// [`value`](Self::value) when the entity has no `Text`, at most once per refresh period.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use bevy_cronjob::ui::CountdownText;
+/// use std::time::Duration;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(ScheduleTimer::new("every hour").with_label("boss"));
+///     commands.spawn((
+///         bevy_ui::widget::Text::default(),
+///         UiCountdown::for_label("boss"),
+///         CountdownText::new(Duration::from_millis(250)).with_template("boss in {}"),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct CountdownText {
+    /// `{}` is replaced by the countdown
+    pub template: String,
+    /// shown while the countdown has no remaining time
+    pub placeholder: String,
+    /// the rendered text, kept even when written into a [`Text`]
+    pub value: String,
+    refresh: Timer,
+    rendered: bool,
+}
+
+impl CountdownText {
+    /// Refresh the text every `refresh`, or every frame with [`Duration::ZERO`]
+    pub fn new(refresh: Duration) -> Self {
+        Self {
+            template: "{}".to_string(),
+            placeholder: String::new(),
+            value: String::new(),
+            refresh: Timer::new(refresh, TimerMode::Repeating),
+            rendered: false,
+        }
+    }
+
+    /// Surround the countdown with text, e.g. `"next event in {}"`
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+        self
+    }
+
+    /// Text shown while the timer has no next fire
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// The refresh period
+    pub fn refresh(&self) -> Duration {
+        self.refresh.duration()
+    }
+
+    fn render(&self, countdown: &UiCountdown) -> String {
+        match countdown.remaining() {
+            Some(_) => self.template.replace("{}", countdown.text()),
+            None => self.placeholder.clone(),
+        }
+    }
+}
+
+pub(crate) fn update_countdown_texts(
+    time: Option<Res<Time>>,
+    mut texts: Query<(&UiCountdown, &mut CountdownText, Option<&mut Text>)>,
+) {
+    let delta = time.map(|time| time.delta()).unwrap_or_default();
+    for (countdown, mut text, target) in texts.iter_mut() {
+        let due = text.refresh.duration().is_zero()
+            || text.refresh.tick(delta).just_finished()
+            || !text.rendered;
+        if !due {
+            continue;
+        }
+        let value = text.render(countdown);
+        text.rendered = true;
+        if text.value != value {
+            text.value = value;
+        }
+        if let Some(mut target) = target {
+            if target.0 != text.value {
+                target.0.clone_from(&text.value);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_countdown_text() {
+    use crate::testing::{cron_app, start};
+    use crate::ScheduleTimer;
+
+    let mut app = cron_app();
+    let mut timer = ScheduleTimer::new("every hour").with_label("boss");
+    timer.local_schedule = Some(start() + chrono::Duration::seconds(90));
+    app.world_mut().spawn(timer);
+    let ui = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            UiCountdown::for_label("boss"),
+            CountdownText::new(Duration::from_secs(60)).with_template("boss in {}"),
+        ))
+        .id();
+    let plain = app
+        .world_mut()
+        .spawn((
+            UiCountdown::for_label("nobody"),
+            CountdownText::new(Duration::ZERO).with_placeholder("--:--"),
+        ))
+        .id();
+    app.update();
+
+    let text = app.world().get::<Text>(ui).unwrap();
+    assert_eq!(text.0, "boss in 01:30");
+    let plain = app.world().get::<CountdownText>(plain).unwrap();
+    assert_eq!(plain.value, "--:--");
+}
@@ -0,0 +1,116 @@
+use crate::{CronClock, ScheduleTimer};
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::warn;
+use chrono::DateTime;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Enables detection of timers that should have fired but were not dispatched in time.
+///
+/// Occurrences can go unserviced because the check system did not run, the timer was skipped or
+/// because of bugs. With this resource present, every occurrence left pending for longer than
+/// `grace` is reported once with a warning and a [`ScheduleOverdue`] event on the timer entity,
+/// so silent schedule failures on servers become visible.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+/// use std::time::Duration;
+///
+/// App::new()
+///     .add_plugins(CronJobPlugin)
+///     .insert_resource(CronWatchdog::new(Duration::from_secs(30)));
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct CronWatchdog {
+    /// how long an occurrence may stay pending before it is reported
+    pub grace: Duration,
+}
+
+impl CronWatchdog {
+    /// Report occurrences pending for longer than `grace`
+    pub fn new(grace: Duration) -> Self {
+        Self { grace }
+    }
+}
+
+impl Default for CronWatchdog {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5))
+    }
+}
+
+/// Triggered on a timer entity when one of its occurrences is overdue, see [`CronWatchdog`]
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleOverdue {
+    /// label of the overdue timer
+    pub label: Option<String>,
+    /// the occurrence that was not dispatched
+    pub scheduled: DateTime<chrono::Local>,
+    /// how long the occurrence has been pending
+    pub overdue_by: Duration,
+}
+
+pub(crate) fn watch_overdue_timers(
+    watchdog: Res<CronWatchdog>,
+    clock: Res<CronClock>,
+    query: Query<(Entity, &ScheduleTimer)>,
+    mut reported: Local<HashMap<Entity, DateTime<chrono::Local>>>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    for (entity, timer) in query.iter() {
+        let Some(scheduled) = timer.local_schedule else {
+            continue;
+        };
+        let Ok(overdue_by) = (now - scheduled).to_std() else {
+            continue;
+        };
+        if overdue_by <= watchdog.grace || reported.get(&entity) == Some(&scheduled) {
+            continue;
+        }
+        warn!(
+            label = timer.label(),
+            ?entity,
+            %scheduled,
+            "cron job is overdue by {overdue_by:?}"
+        );
+        reported.insert(entity, scheduled);
+        commands.trigger_targets(
+            ScheduleOverdue {
+                label: timer.label.clone(),
+                scheduled,
+                overdue_by,
+            },
+            entity,
+        );
+    }
+    reported.retain(|entity, scheduled| {
+        query
+            .get(*entity)
+            .is_ok_and(|(_, timer)| timer.local_schedule == Some(*scheduled))
+    });
+}
+
+#[test]
+fn test_watchdog() {
+    use crate::testing::{start, TestAppExt};
+    use bevy_app::prelude::*;
+
+    let mut app = App::new();
+    // no CronJobPlugin, so nothing dispatches the timer
+    app.insert_resource(CronWatchdog::new(Duration::from_secs(10)))
+        .insert_resource(CronClock::manual(start()))
+        .add_systems(Update, watch_overdue_timers)
+        .record::<ScheduleOverdue>();
+    let mut late = ScheduleTimer::new("every hour");
+    late.local_schedule = Some(start() - chrono::Duration::seconds(60));
+    app.world_mut().spawn(late);
+    let mut within_grace = ScheduleTimer::new("every hour");
+    within_grace.local_schedule = Some(start() - chrono::Duration::seconds(1));
+    app.world_mut().spawn(within_grace);
+
+    app.update();
+    app.update();
+    assert_eq!(app.triggered::<ScheduleOverdue>().len(), 1);
+}
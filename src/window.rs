@@ -0,0 +1,145 @@
+use crate::condition::CronWindow;
+use crate::{CronClock, CronExpr, IntoCronExpression};
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::debug;
+use chrono::DateTime;
+
+/// A component tracking the window between an occurrence of `start` and the following occurrence
+/// of `end`, e.g. at night between `"every day at 10 pm"` and `"every day at 6 am"`.
+///
+/// While the window is open, the entity has the [`InWindow`] marker. Opening and closing the
+/// window trigger [`WindowEntered`] and [`WindowExited`] on the entity. The window is evaluated
+/// against the [`CronClock`] and known from the first check, like
+/// [`in_cron_window`](crate::in_cron_window) for systems.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_cronjob::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Shop;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((Shop, ScheduleWindow::new("every day at 9 am", "every day at 5 pm")));
+/// }
+///
+/// fn serve(shops: Query<&Shop, With<InWindow>>) {
+///     for _shop in shops.iter() {
+///         // only open shops
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleWindow {
+    /// opens the window
+    pub start: CronExpr,
+    /// closes the window
+    pub end: CronExpr,
+    /// the window at the last check
+    state: Option<CronWindow>,
+}
+
+impl ScheduleWindow {
+    /// A window from each occurrence of `start` to the following occurrence of `end`
+    pub fn new(start: impl IntoCronExpression, end: impl IntoCronExpression) -> Self {
+        Self {
+            start: start
+                .into_cron_expr()
+                .expect("Failed to parse cron expression"),
+            end: end
+                .into_cron_expr()
+                .expect("Failed to parse cron expression"),
+            state: None,
+        }
+    }
+
+    /// Whether the window was open at the last check
+    pub fn is_open(&self) -> bool {
+        self.state.is_some_and(|state| state.open)
+    }
+}
+
+/// Marks entities whose [`ScheduleWindow`] is open
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InWindow;
+
+/// Triggered on an entity when its [`ScheduleWindow`] opens
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowEntered {
+    /// when the window closes, `None` when `end` has no occurrence left
+    pub until: Option<DateTime<chrono::Local>>,
+}
+
+/// Triggered on an entity when its [`ScheduleWindow`] closes
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowExited {
+    /// when the window opens again, `None` when `start` has no occurrence left
+    pub next_start: Option<DateTime<chrono::Local>>,
+}
+
+pub(crate) fn update_schedule_windows(
+    mut query: Query<(Entity, &mut ScheduleWindow, Has<InWindow>)>,
+    clock: Res<CronClock>,
+    mut commands: Commands,
+) {
+    let now = clock.now();
+    for (entity, mut window, in_window) in query.iter_mut() {
+        // only take the window mutably when it is evaluated again
+        let current = match window.state {
+            Some(state) if state.is_current(now) => state,
+            _ => {
+                let window = &mut *window;
+                CronWindow::update(&mut window.state, &window.start, &window.end, now)
+            }
+        };
+        match (current.open, in_window) {
+            (true, false) => {
+                debug!(?entity, "cron window entered");
+                commands.entity(entity).insert(InWindow);
+                commands.trigger_targets(
+                    WindowEntered {
+                        until: current.next_end,
+                    },
+                    entity,
+                );
+            }
+            (false, true) => {
+                debug!(?entity, "cron window exited");
+                commands.entity(entity).remove::<InWindow>();
+                commands.trigger_targets(
+                    WindowExited {
+                        next_start: current.next_start,
+                    },
+                    entity,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_schedule_window() {
+    use crate::testing::{cron_app, TestAppExt};
+    use crate::CronAppExt;
+
+    let mut app = cron_app();
+    app.record::<WindowEntered>().record::<WindowExited>();
+
+    // open during the first half of every minute
+    let entity = app
+        .world_mut()
+        .spawn(ScheduleWindow::new("0 * * * * ? *", "30 * * * * ? *"))
+        .id();
+    let mut in_window = vec![];
+    app.update();
+    for _ in 0..4 {
+        in_window.push(app.world().get::<InWindow>(entity).is_some());
+        app.advance_cron_time(std::time::Duration::from_secs(20));
+    }
+    // at 1 s, 21 s, 41 s and 61 s
+    assert_eq!(in_window, [true, true, false, true]);
+    assert_eq!(app.triggered::<WindowEntered>().len(), 2);
+    assert_eq!(app.triggered::<WindowExited>().len(), 1);
+    assert!(app.world().get::<ScheduleWindow>(entity).unwrap().is_open());
+}